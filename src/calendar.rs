@@ -0,0 +1,334 @@
+use crate::product::Category;
+
+/// Day of the week
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the weekday for a zero-based day index (0 = Monday)
+    fn from_index(index: u32) -> Weekday {
+        match index % 7 {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// Returns the customer traffic multiplier for this weekday
+    pub fn traffic_multiplier(&self) -> f64 {
+        match self {
+            Weekday::Friday => 1.1,
+            Weekday::Saturday => 1.3,
+            Weekday::Sunday => 1.2,
+            _ => 1.0,
+        }
+    }
+
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Weekday::Saturday | Weekday::Sunday)
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mon",
+            Weekday::Tuesday => "Tue",
+            Weekday::Wednesday => "Wed",
+            Weekday::Thursday => "Thu",
+            Weekday::Friday => "Fri",
+            Weekday::Saturday => "Sat",
+            Weekday::Sunday => "Sun",
+        }
+    }
+}
+
+/// Season of the year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Autumn,
+}
+
+impl Season {
+    /// Returns the season for a month (1-12)
+    pub fn from_month(month: u32) -> Season {
+        match month {
+            3..=5 => Season::Spring,
+            6..=8 => Season::Summer,
+            9..=11 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    /// Returns the seasonal demand multiplier for a category
+    pub fn category_multiplier(&self, category: Category) -> f64 {
+        match (self, category) {
+            (Season::Autumn, Category::Clothing) => 1.3, // New wardrobes for the cold
+            (Season::Winter, Category::Clothing) => 1.1,
+            (Season::Summer, Category::Clothing) => 0.9,
+            (Season::Summer, Category::Food) => 1.1, // Picnics and barbecues
+            (Season::Spring, Category::Furniture) => 1.2, // Moving season
+            (Season::Summer, Category::Furniture) => 1.1,
+            (Season::Winter, Category::Furniture) => 0.8,
+            _ => 1.0,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Winter => "Winter",
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+        }
+    }
+}
+
+/// A scripted holiday that spikes demand for particular products
+#[derive(Debug, Clone)]
+pub struct Holiday {
+    pub name: &'static str,
+    pub month: u32,
+    pub start_day: u32,
+    /// Number of days the holiday effect lasts
+    pub length: u32,
+    /// Extra customer traffic while the holiday runs
+    pub traffic_multiplier: f64,
+    /// Category-wide demand boosts
+    pub category_boosts: Vec<(Category, f64)>,
+    /// Product-specific demand boosts (product_id, multiplier)
+    pub product_boosts: Vec<(u32, f64)>,
+}
+
+impl Holiday {
+    /// Returns the scripted holiday calendar
+    /// Product IDs: 1=Bread, 2=Milk, 3=Cheese, 4=Apples, 5=Headphones, 6=Phone Charger,
+    ///   8=T-Shirt, 9=Jeans, 16=Chair, 17=Table, 18=Jacket, 20=Smartphone, 21=Laptop
+    pub fn default_holidays() -> Vec<Holiday> {
+        vec![
+            Holiday {
+                name: "New Year",
+                month: 1,
+                start_day: 1,
+                length: 2,
+                traffic_multiplier: 0.8,
+                category_boosts: vec![(Category::Food, 1.3)],
+                product_boosts: vec![(3, 1.5)],
+            },
+            Holiday {
+                name: "Valentine's Day",
+                month: 2,
+                start_day: 12,
+                length: 3,
+                traffic_multiplier: 1.1,
+                category_boosts: vec![],
+                product_boosts: vec![(3, 1.4), (18, 1.5), (5, 1.3)],
+            },
+            Holiday {
+                name: "Back to School",
+                month: 8,
+                start_day: 20,
+                length: 12,
+                traffic_multiplier: 1.15,
+                category_boosts: vec![(Category::Clothing, 1.3)],
+                product_boosts: vec![(21, 1.8), (6, 1.5), (8, 1.4), (9, 1.4)],
+            },
+            Holiday {
+                name: "Harvest Festival",
+                month: 10,
+                start_day: 28,
+                length: 4,
+                traffic_multiplier: 1.1,
+                category_boosts: vec![(Category::Food, 1.3)],
+                product_boosts: vec![(4, 2.0), (1, 1.4)],
+            },
+            Holiday {
+                name: "Black Friday",
+                month: 11,
+                start_day: 27,
+                length: 3,
+                traffic_multiplier: 1.6,
+                category_boosts: vec![(Category::Electronics, 1.8), (Category::Furniture, 1.4)],
+                product_boosts: vec![(21, 1.5), (20, 1.5)],
+            },
+            Holiday {
+                name: "Holiday Season",
+                month: 12,
+                start_day: 15,
+                length: 10,
+                traffic_multiplier: 1.4,
+                category_boosts: vec![(Category::Electronics, 1.5), (Category::Clothing, 1.2)],
+                product_boosts: vec![(20, 1.6), (5, 1.5), (18, 1.4), (2, 1.3)],
+            },
+        ]
+    }
+
+    /// Returns true if the holiday covers the given month and day of month
+    pub fn is_active(&self, month: u32, day_of_month: u32) -> bool {
+        month == self.month
+            && day_of_month >= self.start_day
+            && day_of_month < self.start_day + self.length
+    }
+}
+
+/// The calendar date for a game day (day 1 = Monday, January 1, Year 1)
+#[derive(Debug, Clone)]
+pub struct Calendar {
+    pub year: u32,
+    /// Month of the year (1-12)
+    pub month: u32,
+    /// Day of the month (1-31)
+    pub day_of_month: u32,
+    pub weekday: Weekday,
+    pub season: Season,
+    pub holiday: Option<Holiday>,
+}
+
+impl Calendar {
+    /// Days in each month (no leap years)
+    const MONTH_LENGTHS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    const DAYS_PER_YEAR: u32 = 365;
+
+    /// Derives the calendar date from a game day (1-based)
+    pub fn from_day(day: u32) -> Self {
+        let index = day.saturating_sub(1);
+        let year = index / Self::DAYS_PER_YEAR + 1;
+        let mut day_of_year = index % Self::DAYS_PER_YEAR;
+
+        let mut month = 1;
+        for length in Self::MONTH_LENGTHS {
+            if day_of_year < length {
+                break;
+            }
+            day_of_year -= length;
+            month += 1;
+        }
+        let day_of_month = day_of_year + 1;
+
+        let holiday = Holiday::default_holidays()
+            .into_iter()
+            .find(|h| h.is_active(month, day_of_month));
+
+        Calendar {
+            year,
+            month,
+            day_of_month,
+            weekday: Weekday::from_index(index),
+            season: Season::from_month(month),
+            holiday,
+        }
+    }
+
+    /// Returns the customer traffic multiplier (weekday and holiday)
+    pub fn traffic_multiplier(&self) -> f64 {
+        let holiday = self.holiday.as_ref().map(|h| h.traffic_multiplier).unwrap_or(1.0);
+        self.weekday.traffic_multiplier() * holiday
+    }
+
+    /// Returns the demand multiplier for a category (season, year-end and holiday)
+    pub fn category_multiplier(&self, category: Category) -> f64 {
+        let mut multiplier = self.season.category_multiplier(category);
+
+        // Year-end gift buying
+        if self.month == 12 && category == Category::Electronics {
+            multiplier *= 1.3;
+        }
+
+        if let Some(holiday) = &self.holiday {
+            for (boosted, boost) in &holiday.category_boosts {
+                if *boosted == category {
+                    multiplier *= boost;
+                }
+            }
+        }
+        multiplier
+    }
+
+    /// Returns the holiday demand multiplier for a specific product
+    pub fn product_multiplier(&self, product_id: u32) -> f64 {
+        self.holiday
+            .as_ref()
+            .and_then(|h| h.product_boosts.iter().find(|(id, _)| *id == product_id))
+            .map(|(_, boost)| *boost)
+            .unwrap_or(1.0)
+    }
+
+    /// Returns the short month name
+    pub fn month_name(&self) -> &'static str {
+        const NAMES: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+        NAMES[(self.month - 1) as usize]
+    }
+
+    /// Returns a display string such as "Sat, Mar 14 (Year 1)"
+    pub fn date_string(&self) -> String {
+        format!(
+            "{}, {} {} (Year {})",
+            self.weekday.short_name(),
+            self.month_name(),
+            self.day_of_month,
+            self.year
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_day() {
+        let calendar = Calendar::from_day(1);
+        assert_eq!(calendar.year, 1);
+        assert_eq!(calendar.month, 1);
+        assert_eq!(calendar.day_of_month, 1);
+        assert_eq!(calendar.weekday, Weekday::Monday);
+        assert_eq!(calendar.season, Season::Winter);
+        assert_eq!(calendar.holiday.as_ref().map(|h| h.name), Some("New Year"));
+    }
+
+    #[test]
+    fn test_month_and_year_rollover() {
+        let feb_first = Calendar::from_day(32);
+        assert_eq!((feb_first.month, feb_first.day_of_month), (2, 1));
+
+        let dec_31 = Calendar::from_day(365);
+        assert_eq!((dec_31.month, dec_31.day_of_month, dec_31.year), (12, 31, 1));
+
+        let next_year = Calendar::from_day(366);
+        assert_eq!((next_year.month, next_year.day_of_month, next_year.year), (1, 1, 2));
+    }
+
+    #[test]
+    fn test_weekend_boost() {
+        let saturday = Calendar::from_day(6);
+        assert_eq!(saturday.weekday, Weekday::Saturday);
+        assert!(saturday.traffic_multiplier() > Calendar::from_day(3).traffic_multiplier());
+    }
+
+    #[test]
+    fn test_seasonal_demand() {
+        // Mid-October: autumn clothing boost
+        let october = Calendar::from_day(288);
+        assert_eq!(october.season, Season::Autumn);
+        assert!(october.category_multiplier(Category::Clothing) > 1.0);
+
+        // December: year-end electronics boost
+        let december = Calendar::from_day(340);
+        assert!(december.category_multiplier(Category::Electronics) > 1.0);
+    }
+}
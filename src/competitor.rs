@@ -1,3 +1,5 @@
+use crate::economy::EconomicState;
+
 /// Pricing strategy for AI competitors
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PricingStrategy {
@@ -127,6 +129,11 @@ impl Competitor {
         None
     }
 
+    /// Returns true if the competitor has the cash and is due to open a store soon
+    pub fn is_planning_expansion(&self) -> bool {
+        self.cash > 15000.0 && self.days_since_expansion > 10
+    }
+
     /// React to player opening a new store
     pub fn react_to_player_expansion(&mut self) -> Option<String> {
         // Competitors may respond to player expansion
@@ -142,24 +149,89 @@ impl Competitor {
 #[derive(Debug)]
 pub struct CompetitiveMarket {
     pub competitors: Vec<Competitor>,
-    /// Total market size (base customers across all businesses)
+    /// Total market size (customers shopping across all businesses today)
     pub total_market_size: u32,
+    /// Market size in normal times with the starting number of stores
+    pub base_market_size: u32,
+    /// Stores in the market when it was created (player's first store included)
+    pub baseline_stores: u32,
     /// Player's calculated market share (0.0 to 1.0)
     pub player_market_share: f64,
 }
 
 impl CompetitiveMarket {
+    /// Extra customers drawn into the market by each store beyond the baseline
+    pub const STORE_DRAW: f64 = 0.04;
+    /// Share of the gap to the target size closed each day
+    pub const DAILY_ADJUSTMENT: f64 = 0.02;
+    /// Smallest the market can shrink to, relative to its base size
+    pub const MIN_SIZE_FACTOR: f64 = 0.5;
+
     /// Creates a new competitive market
     pub fn new() -> Self {
+        Self::with_competitors(Competitor::default_competitors(), 500) // Base market of 500 potential customers
+    }
+
+    /// Creates a market with the given competitors and number of potential customers
+    pub fn with_competitors(competitors: Vec<Competitor>, total_market_size: u32) -> Self {
+        let baseline_stores = competitors.iter().map(|c| c.store_count).sum::<u32>() + 1;
         CompetitiveMarket {
-            competitors: Competitor::default_competitors(),
-            total_market_size: 500, // Base market of 500 potential customers
+            competitors,
+            total_market_size,
+            base_market_size: total_market_size,
+            baseline_stores,
             player_market_share: 0.15, // Player starts with 15% share
         }
     }
 
+    /// Returns the size the market is heading toward given the economy and store count
+    pub fn target_market_size(&self, economic_state: EconomicState, player_store_count: u32) -> f64 {
+        let total_stores = self.total_competitor_stores() + player_store_count;
+        let store_factor = 1.0 + Self::STORE_DRAW * (total_stores as f64 - self.baseline_stores as f64);
+        let factor = (store_factor * economic_state.market_size_multiplier()).max(Self::MIN_SIZE_FACTOR);
+        self.base_market_size as f64 * factor
+    }
+
+    /// Moves the market size part of the way toward its target
+    /// Returns the change in customers
+    pub fn update_market_size(&mut self, economic_state: EconomicState, player_store_count: u32) -> i32 {
+        let current = self.total_market_size as f64;
+        let target = self.target_market_size(economic_state, player_store_count);
+        let new_size = (current + (target - current) * Self::DAILY_ADJUSTMENT).round() as u32;
+        // Always move at least one customer toward the target so small gaps close
+        let new_size = if new_size == self.total_market_size && (target - current).abs() >= 1.0 {
+            if target > current { new_size + 1 } else { new_size - 1 }
+        } else {
+            new_size
+        };
+        let change = new_size as i32 - self.total_market_size as i32;
+        self.total_market_size = new_size;
+        change
+    }
+
+    /// Returns the market size relative to normal times
+    pub fn size_factor(&self) -> f64 {
+        self.total_market_size as f64 / self.base_market_size as f64
+    }
+
+    /// Returns the most customers the player's stores can share today
+    pub fn player_customer_pool(&self) -> u32 {
+        (self.total_market_size as f64 * self.player_market_share) as u32
+    }
+
+    /// Returns the customers left for competitors
+    pub fn competitor_customers(&self) -> u32 {
+        self.total_market_size - self.player_customer_pool()
+    }
+
     /// Calculates market shares based on all participants
-    pub fn calculate_market_shares(&mut self, player_store_count: u32, player_avg_markup: f64) {
+    /// `player_marketing_multiplier` is the brand awareness boost from advertising (1.0 = none)
+    pub fn calculate_market_shares(
+        &mut self,
+        player_store_count: u32,
+        player_avg_markup: f64,
+        player_marketing_multiplier: f64,
+    ) {
         // Player's market power
         let player_price_factor = if player_avg_markup > 60.0 {
             0.7 // High prices reduce attraction
@@ -168,7 +240,7 @@ impl CompetitiveMarket {
         } else {
             1.0
         };
-        let player_power = player_store_count as f64 * player_price_factor;
+        let player_power = player_store_count as f64 * player_price_factor * player_marketing_multiplier;
 
         // Total competitor power
         let competitor_power: f64 = self.competitors.iter().map(|c| c.market_power()).sum();
@@ -199,6 +271,8 @@ impl CompetitiveMarket {
     /// Returns notable events
     pub fn advance_day(&mut self, economic_multiplier: f64) -> Vec<String> {
         let player_share = self.player_market_share;
+        // A bigger customer base means more revenue for everyone
+        let economic_multiplier = economic_multiplier * self.size_factor();
         let mut events = Vec::new();
 
         for competitor in &mut self.competitors {
@@ -210,6 +284,15 @@ impl CompetitiveMarket {
         events
     }
 
+    /// Returns the price multiplier of the cheapest competitor (1.0 if there are none)
+    pub fn lowest_price_multiplier(&self) -> f64 {
+        self.competitors
+            .iter()
+            .map(|c| c.strategy.price_multiplier())
+            .fold(None, |lowest: Option<f64>, m| Some(lowest.map_or(m, |l| l.min(m))))
+            .unwrap_or(1.0)
+    }
+
     /// Gets total competitor store count
     pub fn total_competitor_stores(&self) -> u32 {
         self.competitors.iter().map(|c| c.store_count).sum()
@@ -239,3 +322,40 @@ impl Default for CompetitiveMarket {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_market_grows_in_good_times() {
+        let mut market = CompetitiveMarket::new();
+        for _ in 0..200 {
+            market.update_market_size(EconomicState::Prosperity, 1);
+        }
+        assert!(market.total_market_size > market.base_market_size);
+
+        for _ in 0..400 {
+            market.update_market_size(EconomicState::Collapse, 1);
+        }
+        assert!(market.total_market_size < market.base_market_size);
+    }
+
+    #[test]
+    fn test_more_stores_draw_more_customers() {
+        let market = CompetitiveMarket::new();
+        let normal = market.target_market_size(EconomicState::Standard, 1);
+        assert!((normal - market.base_market_size as f64).abs() < 1e-9);
+        assert!(market.target_market_size(EconomicState::Standard, 5) > normal);
+    }
+
+    #[test]
+    fn test_customer_pool_splits_market() {
+        let mut market = CompetitiveMarket::new();
+        market.calculate_market_shares(3, 40.0, 1.0);
+        assert_eq!(
+            market.player_customer_pool() + market.competitor_customers(),
+            market.total_market_size
+        );
+    }
+}
@@ -0,0 +1,189 @@
+/// How a factory splits its finished goods between connected stores
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum DistributionPolicy {
+    /// Everything goes to the first connected store
+    #[default]
+    PrimaryOnly,
+    /// Units are dealt out one at a time, picking up where the last delivery stopped
+    RoundRobin,
+    /// Split in proportion to each store's demand yesterday
+    SalesRate,
+    /// Top each store up to this many units per product; the rest stays at the factory
+    FillToTarget(u32),
+    /// Fixed percentages per store (store_id, percent); any unassigned share stays at the factory
+    Manual(Vec<(u32, f64)>),
+}
+
+impl DistributionPolicy {
+    /// Returns a short description for menus
+    pub fn name(&self) -> String {
+        match self {
+            DistributionPolicy::PrimaryOnly => "Primary store only".to_string(),
+            DistributionPolicy::RoundRobin => "Round-robin".to_string(),
+            DistributionPolicy::SalesRate => "By sales rate".to_string(),
+            DistributionPolicy::FillToTarget(target) => format!("Fill each store to {}", target),
+            DistributionPolicy::Manual(_) => "Manual percentages".to_string(),
+        }
+    }
+
+    /// Checks the policy makes sense for the given connected stores
+    pub fn validate(&self, connected_stores: &[u32]) -> Result<(), String> {
+        match self {
+            DistributionPolicy::FillToTarget(0) => Err("Target must be at least 1".to_string()),
+            DistributionPolicy::Manual(shares) => {
+                if shares.iter().any(|(id, _)| !connected_stores.contains(id)) {
+                    return Err("Every store in the split must be connected to the factory".to_string());
+                }
+                if shares.iter().any(|(_, pct)| *pct < 0.0) {
+                    return Err("Percentages can't be negative".to_string());
+                }
+                let total: f64 = shares.iter().map(|(_, pct)| pct).sum();
+                if total > 100.0 + 1e-9 {
+                    return Err(format!("Percentages add up to {:.0}%, more than 100%", total));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// What a connected store looks like to the distribution policy for one product
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StoreDemand {
+    pub store_id: u32,
+    /// Units already on the shelf
+    pub on_hand: u32,
+    /// Units customers wanted yesterday
+    pub demand: u32,
+}
+
+/// Splits units between stores by weight, handing leftover units to the largest remainders
+fn split_by_weight(quantity: u32, weights: &[(u32, f64)]) -> Vec<(u32, u32)> {
+    let total: f64 = weights.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 {
+        return Vec::new();
+    }
+    let exact: Vec<f64> = weights
+        .iter()
+        .map(|(_, w)| quantity as f64 * w / total)
+        .collect();
+    let mut shares: Vec<u32> = exact.iter().map(|e| e.floor() as u32).collect();
+    let mut leftover = quantity - shares.iter().sum::<u32>();
+    let mut by_remainder: Vec<usize> = (0..weights.len()).collect();
+    by_remainder.sort_by(|&a, &b| (exact[b] - exact[b].floor()).total_cmp(&(exact[a] - exact[a].floor())));
+    for idx in by_remainder {
+        if leftover == 0 {
+            break;
+        }
+        shares[idx] += 1;
+        leftover -= 1;
+    }
+    weights
+        .iter()
+        .zip(shares)
+        .map(|((store_id, _), qty)| (*store_id, qty))
+        .collect()
+}
+
+/// Decides how many units of a product each store gets
+/// `cursor` is the round-robin position and is advanced past the units dealt out
+/// Returns (store_id, quantity) for stores receiving at least one unit
+pub fn allocate(
+    policy: &DistributionPolicy,
+    quantity: u32,
+    stores: &[StoreDemand],
+    cursor: &mut usize,
+) -> Vec<(u32, u32)> {
+    if stores.is_empty() || quantity == 0 {
+        return Vec::new();
+    }
+
+    let allocation = match policy {
+        DistributionPolicy::PrimaryOnly => vec![(stores[0].store_id, quantity)],
+        DistributionPolicy::RoundRobin => {
+            let mut counts = vec![0; stores.len()];
+            for _ in 0..quantity {
+                counts[*cursor % stores.len()] += 1;
+                *cursor = (*cursor + 1) % stores.len();
+            }
+            stores.iter().map(|s| s.store_id).zip(counts).collect()
+        }
+        DistributionPolicy::SalesRate => {
+            let weights: Vec<(u32, f64)> = stores.iter().map(|s| (s.store_id, s.demand as f64)).collect();
+            if weights.iter().all(|(_, w)| *w == 0.0) {
+                // No sales history yet: split evenly
+                let even: Vec<(u32, f64)> = stores.iter().map(|s| (s.store_id, 1.0)).collect();
+                split_by_weight(quantity, &even)
+            } else {
+                split_by_weight(quantity, &weights)
+            }
+        }
+        DistributionPolicy::FillToTarget(target) => {
+            let mut remaining = quantity;
+            let mut allocation = Vec::new();
+            // Emptiest shelves first
+            let mut order: Vec<&StoreDemand> = stores.iter().collect();
+            order.sort_by_key(|s| s.on_hand);
+            for store in order {
+                let qty = target.saturating_sub(store.on_hand).min(remaining);
+                remaining -= qty;
+                allocation.push((store.store_id, qty));
+            }
+            allocation
+        }
+        DistributionPolicy::Manual(shares) => {
+            // Shares for stores that aren't in the list stay at the factory
+            let shares: Vec<(u32, f64)> = shares
+                .iter()
+                .filter(|(id, _)| stores.iter().any(|s| s.store_id == *id))
+                .copied()
+                .collect();
+            let total: f64 = shares.iter().map(|(_, pct)| pct).sum();
+            let assigned = (quantity as f64 * total.min(100.0) / 100.0).round() as u32;
+            split_by_weight(assigned, &shares)
+        }
+    };
+
+    allocation.into_iter().filter(|(_, qty)| *qty > 0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stores() -> Vec<StoreDemand> {
+        vec![
+            StoreDemand { store_id: 1, on_hand: 10, demand: 30 },
+            StoreDemand { store_id: 2, on_hand: 0, demand: 10 },
+        ]
+    }
+
+    #[test]
+    fn test_round_robin_continues_from_cursor() {
+        let mut cursor = 0;
+        let first = allocate(&DistributionPolicy::RoundRobin, 3, &stores(), &mut cursor);
+        assert_eq!(first, vec![(1, 2), (2, 1)]);
+        let second = allocate(&DistributionPolicy::RoundRobin, 1, &stores(), &mut cursor);
+        assert_eq!(second, vec![(2, 1)]);
+    }
+
+    #[test]
+    fn test_sales_rate_and_fill_to_target() {
+        let mut cursor = 0;
+        let by_sales = allocate(&DistributionPolicy::SalesRate, 8, &stores(), &mut cursor);
+        assert_eq!(by_sales, vec![(1, 6), (2, 2)]);
+
+        let filled = allocate(&DistributionPolicy::FillToTarget(12), 20, &stores(), &mut cursor);
+        assert_eq!(filled, vec![(2, 12), (1, 2)]);
+    }
+
+    #[test]
+    fn test_manual_keeps_unassigned_share() {
+        let mut cursor = 0;
+        let policy = DistributionPolicy::Manual(vec![(1, 50.0), (2, 25.0)]);
+        assert!(policy.validate(&[1, 2]).is_ok());
+        assert!(policy.validate(&[1]).is_err());
+        assert_eq!(allocate(&policy, 8, &stores(), &mut cursor), vec![(1, 4), (2, 2)]);
+    }
+}
@@ -1,6 +1,50 @@
-use crate::product::{Category, Product};
+use crate::calendar::Calendar;
+use crate::event::MacroEvent;
+use crate::product::{Category, Product, ProductRelationship};
+use crate::promotion::Promotion;
 use std::collections::HashMap;
 
+/// Price points tried when searching for the most profitable price
+const FORECAST_STEPS: usize = 200;
+
+/// What a price forecast assumes about the store
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ForecastScenario {
+    /// Cost of each unit sold (wholesale or production cost)
+    pub unit_cost: f64,
+    /// Shoppers per day in the store (before the product's price-dependent interest)
+    pub customer_count: f64,
+    /// Units on hand (sales can't exceed this)
+    pub stock: u32,
+    /// Days the stock should last
+    pub days: u32,
+    /// Highest legal shelf price (None if the product isn't capped)
+    pub price_cap: Option<f64>,
+    /// What shoppers will pay for the stock's quality tier, relative to standard goods
+    pub quality_value: f64,
+}
+
+/// Expected results of selling a product at one price
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SalesForecast {
+    pub price: f64,
+    /// Units customers would buy per day
+    pub daily_units: f64,
+    /// Units sold over the scenario's days (limited by stock)
+    pub units: f64,
+    pub revenue: f64,
+    pub profit: f64,
+}
+
+/// Returns `steps` evenly spaced prices from `low` to `high` inclusive
+pub fn price_range(low: f64, high: f64, steps: usize) -> Vec<f64> {
+    if steps < 2 || high <= low {
+        return vec![low];
+    }
+    let step = (high - low) / (steps - 1) as f64;
+    (0..steps).map(|i| low + step * i as f64).collect()
+}
+
 /// Represents the current state of the economy
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EconomicState {
@@ -49,6 +93,31 @@ impl EconomicState {
         }
     }
 
+    /// Returns the annual inflation rate (prices deflate in a collapse)
+    pub fn inflation_rate(&self) -> f64 {
+        match self {
+            EconomicState::Collapse => -0.04,   // -4%/year
+            EconomicState::Recession => 0.0,    // Flat
+            EconomicState::Standard => 0.03,    // 3%/year
+            EconomicState::Growth => 0.05,      // 5%/year
+            EconomicState::Booming => 0.08,     // 8%/year
+            EconomicState::Prosperity => 0.12,  // 12%/year
+        }
+    }
+
+    /// Returns the long-run size of the customer base relative to normal times
+    /// (people move in during good times and leave or stay home in bad ones)
+    pub fn market_size_multiplier(&self) -> f64 {
+        match self {
+            EconomicState::Collapse => 0.75,
+            EconomicState::Recession => 0.9,
+            EconomicState::Standard => 1.0,
+            EconomicState::Growth => 1.08,
+            EconomicState::Booming => 1.15,
+            EconomicState::Prosperity => 1.25,
+        }
+    }
+
     /// Returns the next state if transitioning up (toward prosperity)
     pub fn transition_up(&self) -> Option<EconomicState> {
         match self {
@@ -122,9 +191,28 @@ pub struct Market {
     pub economic_state: EconomicState,
     /// Economic trend (-1.0 to 1.0, affects transition probability)
     pub economic_trend: f64,
+    /// Calendar date for the day being simulated (season, weekday, holidays)
+    pub calendar: Calendar,
+    /// Price-level index (1.0 at game start), drifts with the economic state
+    pub price_level: f64,
+    /// Price level at the last wage review
+    pub wage_index: f64,
+    /// Macro events currently running
+    pub events: Vec<MacroEvent>,
+    /// Macro events scheduled to start in the coming days
+    pub upcoming_events: Vec<MacroEvent>,
+    /// Substitute and complement relationships between products
+    pub relationships: Vec<ProductRelationship>,
 }
 
 impl Market {
+    /// Days over which the annual inflation rate compounds
+    const DAYS_PER_YEAR: f64 = 365.0;
+    /// Days between cost-of-living wage reviews
+    pub const WAGE_REVIEW_DAYS: u32 = 90;
+    /// Days ahead the leading indicator looks at the economic trend
+    pub const INDICATOR_LEAD_DAYS: u32 = 7;
+
     /// Creates a new market with products
     pub fn new(products: &[Product]) -> Self {
         let mut wholesale_prices = HashMap::new();
@@ -147,14 +235,51 @@ impl Market {
             day_seed: 12345,
             economic_state: EconomicState::Standard,
             economic_trend: 0.0,
+            calendar: Calendar::from_day(1),
+            price_level: 1.0,
+            wage_index: 1.0,
+            events: Vec::new(),
+            upcoming_events: Vec::new(),
+            relationships: ProductRelationship::default_relationships(),
         }
     }
 
-    /// Gets the wholesale price for a product, adjusted by economic state
+    /// Gets the wholesale price for a product, adjusted by economic state and inflation
     pub fn get_wholesale_price(&self, product_id: u32) -> Option<f64> {
         self.wholesale_prices
             .get(&product_id)
-            .map(|&base_price| base_price * self.economic_state.price_multiplier())
+            .map(|&base_price| {
+                base_price
+                    * self.economic_state.price_multiplier()
+                    * self.price_level
+                    * self.event_price_multiplier(product_id)
+            })
+    }
+
+    /// Returns the price customers consider normal for a product at today's price level
+    pub fn reference_price(&self, product: &Product) -> f64 {
+        product.base_price * self.price_level
+    }
+
+    /// Returns the demand multiplier from related products on the same shelf
+    /// `own_ratio` is this product's shelf price over its normal price; `shelf_ratios` maps
+    /// every other in-stock product to the same ratio
+    pub fn relationship_multiplier(&self, product_id: u32, own_ratio: f64, shelf_ratios: &HashMap<u32, f64>) -> f64 {
+        let multiplier: f64 = self
+            .relationships
+            .iter()
+            .filter_map(|rel| {
+                let partner = rel.partner_of(product_id)?;
+                let partner_ratio = shelf_ratios.get(&partner)?;
+                Some(rel.demand_effect(own_ratio, *partner_ratio))
+            })
+            .product();
+        multiplier.clamp(0.3, 2.0)
+    }
+
+    /// Returns the current annual inflation rate
+    pub fn inflation_rate(&self) -> f64 {
+        self.economic_state.inflation_rate()
     }
 
     /// Gets the base wholesale price without economic adjustment
@@ -165,7 +290,81 @@ impl Market {
     /// Updates market conditions for a new day and returns any economic change
     pub fn advance_day(&mut self, day: u32) -> Option<String> {
         self.day_seed = day as u64 * 31337 + 42;
-        self.update_economy(day)
+        self.calendar = Calendar::from_day(day);
+        let change = self.update_economy(day);
+        self.price_level *= 1.0 + self.inflation_rate() / Self::DAYS_PER_YEAR;
+        change
+    }
+
+    /// Counts down running macro events, starts scheduled ones whose day has come,
+    /// and possibly schedules a new one (announced a few days ahead)
+    /// Returns (started, ended) events
+    pub fn advance_events(&mut self) -> (Vec<MacroEvent>, Vec<MacroEvent>) {
+        let mut ended = Vec::new();
+        let mut still_running = Vec::new();
+        for mut event in self.events.drain(..) {
+            if event.tick() {
+                ended.push(event);
+            } else {
+                still_running.push(event);
+            }
+        }
+        self.events = still_running;
+
+        let mut started = Vec::new();
+        let mut still_upcoming = Vec::new();
+        for mut event in self.upcoming_events.drain(..) {
+            event.starts_in = event.starts_in.saturating_sub(1);
+            if event.is_running() {
+                started.push(event.clone());
+                self.events.push(event);
+            } else {
+                still_upcoming.push(event);
+            }
+        }
+        self.upcoming_events = still_upcoming;
+
+        let scheduled = self.events.len() + self.upcoming_events.len();
+        if scheduled < MacroEvent::MAX_ACTIVE && self.salted_random(1) < MacroEvent::DAILY_CHANCE {
+            let library = MacroEvent::library();
+            let pick = (self.salted_random(2) * library.len() as f64) as usize;
+            let mut event = library[pick.min(library.len() - 1)].clone();
+            let already_known = self
+                .events
+                .iter()
+                .chain(self.upcoming_events.iter())
+                .any(|e| e.name == event.name);
+            if !already_known {
+                let lead_range = MacroEvent::MAX_LEAD_DAYS - MacroEvent::MIN_LEAD_DAYS + 1;
+                event.starts_in =
+                    MacroEvent::MIN_LEAD_DAYS + (self.salted_random(3) * lead_range as f64) as u32;
+                self.upcoming_events.push(event);
+            }
+        }
+        (started, ended)
+    }
+
+    /// Returns the combined wholesale price shock from running events
+    pub fn event_price_multiplier(&self, product_id: u32) -> f64 {
+        self.events.iter().map(|e| e.price_multiplier(product_id)).product()
+    }
+
+    /// Returns the combined demand shift from running events
+    pub fn event_demand_multiplier(&self, category: Category) -> f64 {
+        self.events.iter().map(|e| e.demand_multiplier(category)).product()
+    }
+
+    /// Returns the base interest rate: economic state plus any event adjustments (min 1%)
+    pub fn base_interest_rate(&self) -> f64 {
+        let change: f64 = self.events.iter().map(|e| e.interest_rate_change).sum();
+        (self.economic_state.interest_rate() + change).max(0.01)
+    }
+
+    /// Closes a wage review: returns the raise factor owed since the last review
+    pub fn review_wages(&mut self) -> f64 {
+        let factor = self.price_level / self.wage_index;
+        self.wage_index = self.price_level;
+        factor
     }
 
     /// Updates the economic state based on trend and random chance
@@ -174,7 +373,7 @@ impl Market {
         let old_state = self.economic_state;
 
         // Update trend (slow sine wave over ~50 days)
-        self.economic_trend = (day as f64 * 0.125).sin();
+        self.economic_trend = Self::trend_at(day);
 
         // Base transition chances
         let mut up_chance = 0.04;   // 4% base chance to improve
@@ -206,10 +405,10 @@ impl Market {
             if let Some(new_state) = self.economic_state.transition_up() {
                 self.economic_state = new_state;
             }
-        } else if roll < up_chance + down_chance {
-            if let Some(new_state) = self.economic_state.transition_down() {
-                self.economic_state = new_state;
-            }
+        } else if roll < up_chance + down_chance
+            && let Some(new_state) = self.economic_state.transition_down()
+        {
+            self.economic_state = new_state;
         }
 
         // Return message if state changed
@@ -229,13 +428,35 @@ impl Market {
         }
     }
 
+    /// Returns the economic trend for a day (slow sine wave over ~50 days)
+    fn trend_at(day: u32) -> f64 {
+        (day as f64 * 0.125).sin()
+    }
+
+    /// Returns a noisy reading of where the economic trend is heading:
+    /// the trend a week out, give or take up to 0.35
+    pub fn leading_indicator(&self, day: u32) -> f64 {
+        let future_trend = Self::trend_at(day + Self::INDICATOR_LEAD_DAYS);
+        let noise = (self.salted_random(4) - 0.5) * 0.7;
+        (future_trend + noise).clamp(-1.0, 1.0)
+    }
+
     /// Returns a random value between 0.0 and 1.0 based on current day seed
     fn get_random_value(&self) -> f64 {
         let x = self.day_seed.wrapping_mul(48271).wrapping_add(1);
         (x % 10000) as f64 / 10000.0
     }
 
+    /// Returns an independent random value between 0.0 and 1.0 for the day (salt picks the stream)
+    pub fn salted_random(&self, salt: u64) -> f64 {
+        let x = (self.day_seed ^ salt.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((x >> 33) % 10000) as f64 / 10000.0
+    }
+
     /// Calculates expected sales based on price vs base price and demand
+    /// Promotions covering the product lower the price customers see and draw extra demand
     /// Returns the number of units that would sell
     pub fn calculate_sales(
         &self,
@@ -243,34 +464,131 @@ impl Market {
         retail_price: f64,
         available_quantity: u32,
         customer_count: u32,
+        promotions: &[Promotion],
     ) -> u32 {
-        let base_price = product.base_price;
-        let category_multiplier = self
-            .category_demand
-            .get(&product.category)
-            .copied()
-            .unwrap_or(1.0);
+        // Can't sell more than we have
+        self.calculate_demand(product, retail_price, customer_count, promotions)
+            .min(available_quantity)
+    }
+
+    /// Calculates unconstrained demand: how many units customers would buy
+    /// if the shelf never ran out
+    pub fn calculate_demand(
+        &self,
+        product: &Product,
+        retail_price: f64,
+        customer_count: u32,
+        promotions: &[Promotion],
+    ) -> u32 {
+        let expected_sales = self.expected_demand(product, retail_price, customer_count as f64, promotions) as u32;
+
+        // Add some variance using simple pseudo-random
+        let variance = self.get_daily_variance();
+        ((expected_sales as f64) * variance) as u32
+    }
+
+    /// Calculates average demand before daily variance (fractional units)
+    pub fn expected_demand(
+        &self,
+        product: &Product,
+        retail_price: f64,
+        customer_count: f64,
+        promotions: &[Promotion],
+    ) -> f64 {
+        // Willingness to pay rises with the general price level
+        let base_price = self.reference_price(product);
+
+        // Best applicable promotion (if any)
+        let promotion = Promotion::best_for(product, promotions);
+        let retail_price = retail_price * promotion.map(|p| p.price_multiplier()).unwrap_or(1.0);
 
         // Price elasticity: higher price = fewer sales
         // Formula: sales_factor = 1 - (price - base_price) / base_price * 0.5
         let price_ratio = (retail_price - base_price) / base_price;
         let price_factor = (1.0 - price_ratio * 0.5).clamp(0.0, 2.0);
 
-        // Apply economic state sales multiplier
-        let economic_multiplier = self.economic_state.sales_multiplier();
+        customer_count * self.base_demand(product, promotions) * price_factor
+    }
 
-        // Base demand per customer (small fraction of customers buy each product)
-        let base_demand = 0.1 * category_multiplier * economic_multiplier;
+    /// Forecasts units, revenue and profit at each price over the scenario's horizon
+    /// `interest` gives the share of shoppers drawn to the product at the price they'd pay
+    /// (substitutes and complements on the shelf make it depend on price)
+    pub fn forecast(
+        &self,
+        product: &Product,
+        promotions: &[Promotion],
+        scenario: &ForecastScenario,
+        prices: &[f64],
+        interest: impl Fn(f64) -> f64,
+    ) -> Vec<SalesForecast> {
+        prices
+            .iter()
+            .map(|&price| {
+                let price_paid = price
+                    * Promotion::best_for(product, promotions)
+                        .map(|p| p.price_multiplier())
+                        .unwrap_or(1.0);
+                let customers = scenario.customer_count * interest(price_paid);
+                // Customers judge the price against what the goods are worth
+                let daily_units = self.expected_demand(product, price / scenario.quality_value, customers, promotions);
+                let units = (daily_units * scenario.days as f64).min(scenario.stock as f64);
+                let revenue = units * price_paid;
+                SalesForecast {
+                    price,
+                    daily_units,
+                    units,
+                    revenue,
+                    profit: revenue - units * scenario.unit_cost,
+                }
+            })
+            .collect()
+    }
 
-        // Calculate expected sales
-        let expected_sales = (customer_count as f64 * base_demand * price_factor) as u32;
+    /// Finds the price with the highest expected profit, searching from the unit cost
+    /// up to the price where demand disappears
+    pub fn optimal_price(
+        &self,
+        product: &Product,
+        promotions: &[Promotion],
+        scenario: &ForecastScenario,
+        interest: impl Fn(f64) -> f64,
+    ) -> Option<SalesForecast> {
+        // Demand reaches zero at three times the goods' worth (before promotions)
+        let promo_multiplier = Promotion::best_for(product, promotions)
+            .map(|p| p.price_multiplier())
+            .unwrap_or(1.0);
+        let mut ceiling = 3.0 * self.reference_price(product) * scenario.quality_value / promo_multiplier;
+        if let Some(cap) = scenario.price_cap {
+            ceiling = ceiling.min(cap);
+        }
+        let prices = price_range(scenario.unit_cost.min(ceiling), ceiling, FORECAST_STEPS);
+        self.forecast(product, promotions, scenario, &prices, interest)
+            .into_iter()
+            .max_by(|a, b| a.profit.total_cmp(&b.profit))
+    }
 
-        // Add some variance using simple pseudo-random
-        let variance = self.get_daily_variance();
-        let adjusted_sales = ((expected_sales as f64) * variance) as u32;
+    /// Returns the share of customers who want a product at its normal price
+    /// (category, season, holiday, event, economy and promotion effects)
+    pub fn base_demand(&self, product: &Product, promotions: &[Promotion]) -> f64 {
+        let promotion_multiplier = Promotion::best_for(product, promotions)
+            .map(|p| p.demand_multiplier())
+            .unwrap_or(1.0);
+        let category_multiplier = self
+            .category_demand
+            .get(&product.category)
+            .copied()
+            .unwrap_or(1.0)
+            * self.event_demand_multiplier(product.category);
 
-        // Can't sell more than we have
-        adjusted_sales.min(available_quantity)
+        // Seasonal and holiday demand
+        let calendar_multiplier = self.calendar.category_multiplier(product.category)
+            * self.calendar.product_multiplier(product.id);
+
+        // Apply economic state sales multiplier
+        let economic_multiplier = self.economic_state.sales_multiplier();
+
+        // Base demand per customer (small fraction of customers buy each product)
+        0.1 * category_multiplier * calendar_multiplier * economic_multiplier * promotion_multiplier
     }
 
     /// Returns a daily variance multiplier (0.8 to 1.2)
@@ -297,6 +615,135 @@ impl Market {
 
     /// Gets the interest rate for a specific loan type based on current economy
     pub fn get_loan_rate(&self, loan_type: &crate::loan::LoanType) -> f64 {
-        self.economic_state.interest_rate() + loan_type.rate_modifier()
+        self.base_interest_rate() + loan_type.rate_modifier()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_level_drifts_with_economy() {
+        let products = Product::default_products();
+        let mut market = Market::new(&products);
+        for day in 1..=100 {
+            market.advance_day(day);
+        }
+        assert!(market.price_level != 1.0);
+    }
+
+    #[test]
+    fn test_price_level_scales_prices() {
+        let products = Product::default_products();
+        let mut market = Market::new(&products);
+        let bread = &products[0];
+        let before = market.get_wholesale_price(bread.id).unwrap();
+
+        market.price_level = 1.1;
+        assert!((market.get_wholesale_price(bread.id).unwrap() - before * 1.1).abs() < 1e-9);
+        assert!((market.reference_price(bread) - bread.base_price * 1.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wage_review_catches_up_with_prices() {
+        let products = Product::default_products();
+        let mut market = Market::new(&products);
+        market.price_level = 1.06;
+        assert!((market.review_wages() - 1.06).abs() < 1e-9);
+        // Nothing owed until prices move again
+        assert!((market.review_wages() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_macro_events_break_out() {
+        let products = Product::default_products();
+        let mut market = Market::new(&products);
+        let mut started = 0;
+        for day in 1..=365 {
+            market.advance_day(day);
+            started += market.advance_events().0.len();
+            assert!(market.events.len() + market.upcoming_events.len() <= MacroEvent::MAX_ACTIVE);
+            assert!(market.upcoming_events.iter().all(|e| !e.is_running()));
+        }
+        assert!(started > 0);
+    }
+
+    #[test]
+    fn test_macro_events_stack_with_economy() {
+        let products = Product::default_products();
+        let mut market = Market::new(&products);
+        let steel_before = market.get_wholesale_price(12).unwrap();
+        let rate_before = market.base_interest_rate();
+
+        for name in ["Steel Shortage", "Housing Boom"] {
+            let event = MacroEvent::library().into_iter().find(|e| e.name == name).unwrap();
+            market.events.push(event);
+        }
+        assert!((market.get_wholesale_price(12).unwrap() - steel_before * 1.8).abs() < 1e-9);
+        assert!((market.base_interest_rate() - (rate_before + 0.02)).abs() < 1e-9);
+        assert!(market.event_demand_multiplier(Category::Furniture) > 1.0);
+    }
+
+    #[test]
+    fn test_leading_indicator_is_noisy_but_informative() {
+        let products = Product::default_products();
+        let mut market = Market::new(&products);
+        let mut agree = 0;
+        for day in 1..=365 {
+            market.advance_day(day);
+            let reading = market.leading_indicator(day);
+            let future = Market::trend_at(day + Market::INDICATOR_LEAD_DAYS);
+            if reading.signum() == future.signum() {
+                agree += 1;
+            }
+        }
+        assert!(agree > 250, "indicator agreed on only {} days", agree);
+        assert!(agree < 365, "indicator should not be perfect");
+    }
+
+    #[test]
+    fn test_forecast_trades_units_for_margin() {
+        let products = Product::default_products();
+        let market = Market::new(&products);
+        let bread = &products[0];
+        let scenario = ForecastScenario {
+            unit_cost: market.get_wholesale_price(bread.id).unwrap(),
+            customer_count: 200.0,
+            stock: 1000,
+            days: 7,
+            price_cap: None,
+            quality_value: 1.0,
+        };
+        let forecast = market.forecast(bread, &[], &scenario, &[bread.base_price, bread.base_price * 2.0], |_| 1.0);
+        assert!(forecast[0].units > forecast[1].units);
+        assert!((forecast[0].revenue - forecast[0].units * forecast[0].price).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_optimal_price_rises_when_stock_is_short() {
+        let products = Product::default_products();
+        let market = Market::new(&products);
+        let bread = &products[0];
+        let plenty = ForecastScenario {
+            unit_cost: market.get_wholesale_price(bread.id).unwrap(),
+            customer_count: 200.0,
+            stock: 1000,
+            days: 7,
+            price_cap: None,
+            quality_value: 1.0,
+        };
+        let scarce = ForecastScenario { stock: 5, ..plenty };
+
+        let best_plenty = market.optimal_price(bread, &[], &plenty, |_| 1.0).unwrap();
+        let best_scarce = market.optimal_price(bread, &[], &scarce, |_| 1.0).unwrap();
+        assert!(best_plenty.price > plenty.unit_cost);
+        assert!(best_scarce.price > best_plenty.price);
+
+        // A legal cap below the best price limits the search
+        let cap = (plenty.unit_cost + best_scarce.price) / 2.0;
+        let capped = ForecastScenario { price_cap: Some(cap), ..scarce };
+        let best_capped = market.optimal_price(bread, &[], &capped, |_| 1.0).unwrap();
+        assert!(best_capped.price <= cap + 1e-9);
     }
 }
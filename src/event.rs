@@ -0,0 +1,172 @@
+use crate::product::Category;
+
+/// A timed macroeconomic shock that stacks on top of the economic state
+#[derive(Debug, Clone)]
+pub struct MacroEvent {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Early warning that circulates in the news before the event starts
+    pub rumor: &'static str,
+    /// How many days the event lasts once it starts
+    pub duration: u32,
+    /// Days until a scheduled event starts (0 once running)
+    pub starts_in: u32,
+    /// Days left before the event ends
+    pub days_remaining: u32,
+    /// Wholesale price shocks (product_id, multiplier)
+    pub price_shocks: Vec<(u32, f64)>,
+    /// Consumer demand shifts (category, multiplier)
+    pub demand_shifts: Vec<(Category, f64)>,
+    /// Change to the base interest rate (e.g., 0.02 for +2 points)
+    pub interest_rate_change: f64,
+}
+
+impl MacroEvent {
+    /// Daily chance that a new event breaks out
+    pub const DAILY_CHANCE: f64 = 0.03;
+    /// Maximum events running at the same time
+    pub const MAX_ACTIVE: usize = 2;
+    /// Fewest days of warning before a scheduled event starts
+    pub const MIN_LEAD_DAYS: u32 = 3;
+    /// Most days of warning before a scheduled event starts
+    pub const MAX_LEAD_DAYS: u32 = 7;
+
+    /// Returns the library of events that can break out
+    /// Product IDs: 2=Milk, 3=Cheese, 5=Headphones, 6=Phone Charger, 11=Lumber, 12=Steel,
+    ///   15=Electronic Components, 17=Steel Table, 19=Blender, 20=Smartphone, 21=Laptop
+    pub fn library() -> Vec<MacroEvent> {
+        vec![
+            MacroEvent {
+                name: "Steel Shortage",
+                description: "Mills cut output, steel prices soar",
+                rumor: "Steel mills report furnace outages",
+                duration: 12,
+                starts_in: 0,
+                days_remaining: 12,
+                price_shocks: vec![(12, 1.8), (17, 1.3)],
+                demand_shifts: vec![],
+                interest_rate_change: 0.0,
+            },
+            MacroEvent {
+                name: "Chip Crisis",
+                description: "Semiconductor plants stall, electronics get scarce",
+                rumor: "Chip foundries warn of production delays",
+                duration: 20,
+                starts_in: 0,
+                days_remaining: 20,
+                price_shocks: vec![(15, 2.0), (20, 1.3), (21, 1.3), (5, 1.15), (6, 1.1), (19, 1.1)],
+                demand_shifts: vec![(Category::Electronics, 0.85)],
+                interest_rate_change: 0.0,
+            },
+            MacroEvent {
+                name: "Food Recall",
+                description: "Contamination scare keeps shoppers away from groceries",
+                rumor: "Health inspectors investigate a major food supplier",
+                duration: 7,
+                starts_in: 0,
+                days_remaining: 7,
+                price_shocks: vec![(2, 0.9), (3, 0.85)],
+                demand_shifts: vec![(Category::Food, 0.6)],
+                interest_rate_change: 0.0,
+            },
+            MacroEvent {
+                name: "Housing Boom",
+                description: "Home sales surge, everyone needs furniture",
+                rumor: "Mortgage applications climb sharply",
+                duration: 25,
+                starts_in: 0,
+                days_remaining: 25,
+                price_shocks: vec![(11, 1.3)],
+                demand_shifts: vec![(Category::Furniture, 1.5)],
+                interest_rate_change: 0.02,
+            },
+            MacroEvent {
+                name: "Pandemic",
+                description: "People stay home, stockpile food and skip the mall",
+                rumor: "Doctors track an unusual respiratory illness",
+                duration: 30,
+                starts_in: 0,
+                days_remaining: 30,
+                price_shocks: vec![(13, 1.2)],
+                demand_shifts: vec![(Category::Food, 1.2), (Category::Clothing, 0.7), (Category::Furniture, 0.8)],
+                interest_rate_change: -0.02,
+            },
+            MacroEvent {
+                name: "Tech Boom",
+                description: "A new gadget craze sweeps the country",
+                rumor: "Tech insiders hype an upcoming product launch",
+                duration: 20,
+                starts_in: 0,
+                days_remaining: 20,
+                price_shocks: vec![(15, 1.2)],
+                demand_shifts: vec![(Category::Electronics, 1.4)],
+                interest_rate_change: 0.01,
+            },
+        ]
+    }
+
+    /// Returns true once the event has started
+    pub fn is_running(&self) -> bool {
+        self.starts_in == 0
+    }
+
+    /// Returns the wholesale price multiplier for a product
+    pub fn price_multiplier(&self, product_id: u32) -> f64 {
+        self.price_shocks
+            .iter()
+            .find(|(id, _)| *id == product_id)
+            .map(|(_, multiplier)| *multiplier)
+            .unwrap_or(1.0)
+    }
+
+    /// Returns the demand multiplier for a category
+    pub fn demand_multiplier(&self, category: Category) -> f64 {
+        self.demand_shifts
+            .iter()
+            .find(|(c, _)| *c == category)
+            .map(|(_, multiplier)| *multiplier)
+            .unwrap_or(1.0)
+    }
+
+    /// Counts down one day, returns true if the event has ended
+    pub fn tick(&mut self) -> bool {
+        self.days_remaining = self.days_remaining.saturating_sub(1);
+        self.days_remaining == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str) -> MacroEvent {
+        MacroEvent::library().into_iter().find(|e| e.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_library_events_start_at_full_duration() {
+        for event in MacroEvent::library() {
+            assert_eq!(event.days_remaining, event.duration);
+        }
+    }
+
+    #[test]
+    fn test_event_effects() {
+        let steel = event("Steel Shortage");
+        assert_eq!(steel.price_multiplier(12), 1.8);
+        assert_eq!(steel.price_multiplier(1), 1.0);
+
+        let recall = event("Food Recall");
+        assert!(recall.demand_multiplier(Category::Food) < 1.0);
+        assert_eq!(recall.demand_multiplier(Category::Clothing), 1.0);
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut recall = event("Food Recall");
+        for _ in 0..6 {
+            assert!(!recall.tick());
+        }
+        assert!(recall.tick());
+    }
+}
@@ -1,4 +1,7 @@
 use std::collections::HashMap;
+use crate::distribution::DistributionPolicy;
+use crate::procurement::ProcurementPolicy;
+use crate::quality::{self, DefectHandling, MaterialGrade, QualityTier};
 use crate::recipe::Recipe;
 
 /// Represents a production job in progress
@@ -9,6 +12,16 @@ pub struct ProductionJob {
     pub days_remaining: u32,
     pub output_product_id: u32,
     pub output_quantity: u32,
+    /// Output goes to component storage instead of finished goods
+    pub intermediate: bool,
+    /// Materials consumed plus labor and overhead absorbed so far
+    pub cost: f64,
+    /// Average quality bonus of the raw materials consumed (from their grade)
+    pub material_bonus: f64,
+    /// Average quality score of the components consumed
+    pub component_quality: f64,
+    /// Share of the ingredient units that were components (0-1)
+    pub component_share: f64,
 }
 
 impl ProductionJob {
@@ -19,6 +32,11 @@ impl ProductionJob {
             days_remaining: recipe.production_days,
             output_product_id: recipe.output_product_id,
             output_quantity: recipe.output_quantity,
+            intermediate: recipe.intermediate,
+            cost: 0.0,
+            material_bonus: 0.0,
+            component_quality: QualityTier::DEFAULT_SCORE,
+            component_share: 0.0,
         }
     }
 }
@@ -28,13 +46,23 @@ impl ProductionJob {
 pub struct FactoryWorker {
     pub name: String,
     pub salary: f64,
+    /// Experience on the line (0-1), improves product quality
+    pub skill: f64,
 }
 
 impl FactoryWorker {
+    /// Daily salary at the starting price level
+    pub const BASE_SALARY: f64 = 75.0;
+    /// Skill of a newly hired worker
+    pub const STARTING_SKILL: f64 = 0.5;
+    /// Skill gained per day worked
+    pub const DAILY_SKILL_GAIN: f64 = 0.002;
+
     pub fn new(name: &str) -> Self {
         FactoryWorker {
             name: name.to_string(),
-            salary: 75.0, // $75/day
+            salary: Self::BASE_SALARY, // $75/day
+            skill: Self::STARTING_SKILL,
         }
     }
 }
@@ -45,6 +73,66 @@ pub struct ProductionResult {
     pub recipe_name: String,
     pub product_id: u32,
     pub quantity: u32,
+    /// Production cost per good unit (materials, labor and overhead)
+    pub unit_cost: f64,
+    /// Quality score of the batch (0-1)
+    pub quality: f64,
+    /// Units that came out defective (not included in quantity)
+    pub defective: u32,
+    /// True if the output is a component
+    pub intermediate: bool,
+}
+
+impl ProductionResult {
+    /// Returns the quality tier of the good units
+    pub fn tier(&self) -> QualityTier {
+        QualityTier::from_score(self.quality)
+    }
+}
+
+/// How much a standing production order keeps making
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrderTarget {
+    /// Keep this many units in storage (counting jobs in progress)
+    StockLevel(u32),
+    /// Start this many jobs every day
+    DailyCount(u32),
+}
+
+/// A recipe the factory keeps producing on its own
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandingOrder {
+    pub recipe_id: u32,
+    pub target: OrderTarget,
+}
+
+impl StandingOrder {
+    pub fn new(recipe_id: u32, target: OrderTarget) -> Self {
+        StandingOrder { recipe_id, target }
+    }
+}
+
+/// Why a standing order couldn't start all the jobs it wanted
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlockReason {
+    /// Every production slot is busy
+    NoSlots,
+    /// Short of ingredients: (product_id, quantity missing for one batch)
+    MissingIngredients(Vec<(u32, u32)>),
+    /// The recipe no longer exists
+    UnknownRecipe,
+}
+
+/// What a standing order did on a given day
+#[derive(Debug, Clone)]
+pub struct OrderLogEntry {
+    pub day: u32,
+    pub recipe_id: u32,
+    pub recipe_name: String,
+    /// Jobs started
+    pub started: u32,
+    /// Set if fewer jobs were started than the order wanted
+    pub blocked: Option<BlockReason>,
 }
 
 /// Represents a manufacturing factory
@@ -52,30 +140,84 @@ pub struct ProductionResult {
 pub struct Factory {
     pub id: u32,
     pub name: String,
+    /// Region (city) the factory operates in
+    pub region_id: u32,
     pub raw_materials: HashMap<u32, u32>,  // product_id -> quantity
     pub finished_goods: HashMap<u32, u32>, // product_id -> quantity
+    /// Intermediate goods made here (or transferred in) for use in other recipes
+    pub components: HashMap<u32, u32>,     // product_id -> quantity
+    /// Average cost per unit of everything in storage
+    pub unit_costs: HashMap<u32, f64>,     // product_id -> cost
+    /// Average quality score of goods made here and still in storage
+    pub quality: HashMap<u32, f64>,        // product_id -> score
+    /// Average quality bonus of raw materials in storage, from the grade they were bought at
+    pub material_bonus: HashMap<u32, f64>, // product_id -> bonus
+    /// Machinery level (1-3), improves quality
+    pub equipment_level: u32,
+    /// Grade of raw materials bought for this factory
+    pub material_grade: MaterialGrade,
+    /// What happens to defective units
+    pub defect_handling: DefectHandling,
+    /// Expected defects not yet realised (a unit fails each time this reaches 1)
+    pub defect_backlog: f64,
     pub production_queue: Vec<ProductionJob>,
     pub workers: Vec<FactoryWorker>,
     pub daily_rent: f64,
+    /// Days until the lease is renewed and rent is repriced
+    pub lease_days_remaining: u32,
     /// Store IDs this factory is connected to (supply chain)
     pub connected_stores: Vec<u32>,
     /// If true, auto-transfer finished goods to connected stores
     pub auto_transfer: bool,
+    /// How auto-transferred goods are split between connected stores
+    pub distribution: DistributionPolicy,
+    /// Next store in line under round-robin distribution
+    pub distribution_cursor: usize,
+    /// Recipes restarted automatically each day, in priority order
+    pub standing_orders: Vec<StandingOrder>,
+    /// Recent standing order activity, oldest first
+    pub order_log: Vec<OrderLogEntry>,
+    /// Rules for buying raw materials automatically
+    pub procurement: ProcurementPolicy,
 }
 
 impl Factory {
-    /// Creates a new factory
-    pub fn new(id: u32, name: &str) -> Self {
+    /// Daily rent at the starting price level
+    pub const BASE_RENT: f64 = 150.0;
+    /// Length of a lease before rent is repriced
+    pub const LEASE_TERM_DAYS: u32 = 180;
+    /// Maximum standing orders per factory
+    pub const MAX_STANDING_ORDERS: usize = 5;
+    /// Standing order log entries kept per factory
+    pub const ORDER_LOG_LENGTH: usize = 30;
+
+    /// Creates a new factory in a region
+    pub fn new(id: u32, name: &str, region_id: u32) -> Self {
         Factory {
             id,
             name: name.to_string(),
+            region_id,
             raw_materials: HashMap::new(),
             finished_goods: HashMap::new(),
+            components: HashMap::new(),
+            unit_costs: HashMap::new(),
+            quality: HashMap::new(),
+            material_bonus: HashMap::new(),
+            equipment_level: 1,
+            material_grade: MaterialGrade::Standard,
+            defect_handling: DefectHandling::SellAsSeconds,
+            defect_backlog: 0.0,
             production_queue: Vec::new(),
             workers: Vec::new(),
-            daily_rent: 150.0, // $150/day
+            daily_rent: Self::BASE_RENT, // $150/day
+            lease_days_remaining: Self::LEASE_TERM_DAYS,
             connected_stores: Vec::new(),
             auto_transfer: false,
+            distribution: DistributionPolicy::default(),
+            distribution_cursor: 0,
+            standing_orders: Vec::new(),
+            order_log: Vec::new(),
+            procurement: ProcurementPolicy::default(),
         }
     }
 
@@ -94,11 +236,41 @@ impl Factory {
         self.production_slots().saturating_sub(self.active_jobs())
     }
 
-    /// Adds raw materials to the factory storage
-    pub fn add_raw_material(&mut self, product_id: u32, quantity: u32) {
+    /// Adds raw materials bought at the given unit cost and grade to the factory storage
+    pub fn add_raw_material(&mut self, product_id: u32, quantity: u32, unit_cost: f64, grade: MaterialGrade) {
+        self.merge_unit_cost(product_id, quantity, unit_cost);
+        let on_hand = self.get_raw_material(product_id);
+        let average = (self.material_bonus_of(product_id) * on_hand as f64
+            + grade.quality_bonus() * quantity as f64)
+            / (on_hand + quantity).max(1) as f64;
+        self.material_bonus.insert(product_id, average);
         *self.raw_materials.entry(product_id).or_insert(0) += quantity;
     }
 
+    /// Returns the average quality bonus of a raw material in storage
+    pub fn material_bonus_of(&self, product_id: u32) -> f64 {
+        *self.material_bonus.get(&product_id).unwrap_or(&0.0)
+    }
+
+    /// Returns the average cost per unit of a product in storage (0 if unknown)
+    pub fn unit_cost(&self, product_id: u32) -> f64 {
+        *self.unit_costs.get(&product_id).unwrap_or(&0.0)
+    }
+
+    /// Folds incoming units into the average unit cost (call before adding the units)
+    fn merge_unit_cost(&mut self, product_id: u32, quantity: u32, unit_cost: f64) {
+        let on_hand = self.get_raw_material(product_id)
+            + self.get_component(product_id)
+            + self.get_finished_good(product_id);
+        let total = on_hand + quantity;
+        if total == 0 {
+            return;
+        }
+        let average = (self.unit_cost(product_id) * on_hand as f64 + unit_cost * quantity as f64)
+            / total as f64;
+        self.unit_costs.insert(product_id, average);
+    }
+
     /// Gets the quantity of a raw material in storage
     pub fn get_raw_material(&self, product_id: u32) -> u32 {
         *self.raw_materials.get(&product_id).unwrap_or(&0)
@@ -109,10 +281,75 @@ impl Factory {
         *self.finished_goods.get(&product_id).unwrap_or(&0)
     }
 
-    /// Checks if the factory has enough raw materials to produce a recipe
+    /// Gets the quantity of a component in storage
+    pub fn get_component(&self, product_id: u32) -> u32 {
+        *self.components.get(&product_id).unwrap_or(&0)
+    }
+
+    /// Adds components made at the given unit cost and quality to the factory storage
+    pub fn add_components(&mut self, product_id: u32, quantity: u32, unit_cost: f64, quality: f64) {
+        self.merge_unit_cost(product_id, quantity, unit_cost);
+        self.merge_quality(product_id, quantity, quality);
+        *self.components.entry(product_id).or_insert(0) += quantity;
+    }
+
+    /// Removes components from storage (for transfer to another factory)
+    pub fn take_components(&mut self, product_id: u32, quantity: u32) -> Result<u32, String> {
+        let available = self.get_component(product_id);
+        if available == 0 {
+            return Err("No components of this type".to_string());
+        }
+
+        let actual_quantity = quantity.min(available);
+        if let Some(qty) = self.components.get_mut(&product_id) {
+            *qty -= actual_quantity;
+        }
+
+        Ok(actual_quantity)
+    }
+
+    /// Gets how much of an ingredient is on hand (raw materials plus components)
+    pub fn get_ingredient(&self, product_id: u32) -> u32 {
+        self.get_raw_material(product_id) + self.get_component(product_id)
+    }
+
+    /// Consumes one batch of a recipe's ingredients, from raw materials first, then components
+    /// Returns a job carrying the cost and quality of the ingredients used
+    fn consume_ingredients(&mut self, recipe: &Recipe) -> ProductionJob {
+        let cost = recipe.material_cost(|id| self.unit_cost(id));
+        let (mut raw_units, mut bonus_total) = (0, 0.0);
+        let (mut component_units, mut quality_total) = (0, 0.0);
+        for ing in &recipe.ingredients {
+            let raw = ing.quantity.min(self.get_raw_material(ing.product_id));
+            let from_components = (ing.quantity - raw).min(self.get_component(ing.product_id));
+            raw_units += raw;
+            bonus_total += self.material_bonus_of(ing.product_id) * raw as f64;
+            component_units += from_components;
+            quality_total += self.quality_of(ing.product_id) * from_components as f64;
+            if let Some(qty) = self.raw_materials.get_mut(&ing.product_id) {
+                *qty -= raw;
+            }
+            if let Some(qty) = self.components.get_mut(&ing.product_id) {
+                *qty -= from_components;
+            }
+        }
+
+        let mut job = ProductionJob::new(recipe);
+        job.cost = cost;
+        if raw_units > 0 {
+            job.material_bonus = bonus_total / raw_units as f64;
+        }
+        if component_units > 0 {
+            job.component_quality = quality_total / component_units as f64;
+            job.component_share = component_units as f64 / (raw_units + component_units) as f64;
+        }
+        job
+    }
+
+    /// Checks if the factory has enough ingredients to produce a recipe
     pub fn has_ingredients(&self, recipe: &Recipe) -> bool {
         recipe.ingredients.iter().all(|ing| {
-            self.get_raw_material(ing.product_id) >= ing.quantity
+            self.get_ingredient(ing.product_id) >= ing.quantity
         })
     }
 
@@ -122,7 +359,7 @@ impl Factory {
             .ingredients
             .iter()
             .filter_map(|ing| {
-                let have = self.get_raw_material(ing.product_id);
+                let have = self.get_ingredient(ing.product_id);
                 if have < ing.quantity {
                     Some((ing.product_id, ing.quantity - have))
                 } else {
@@ -144,34 +381,102 @@ impl Factory {
             return Err("Insufficient raw materials".to_string());
         }
 
-        // Consume raw materials
-        for ing in &recipe.ingredients {
-            if let Some(qty) = self.raw_materials.get_mut(&ing.product_id) {
-                *qty -= ing.quantity;
-            }
-        }
-
         // Add job to queue
-        self.production_queue.push(ProductionJob::new(recipe));
+        let job = self.consume_ingredients(recipe);
+        self.production_queue.push(job);
 
         Ok(())
     }
 
+    /// Returns the average skill on the line (the owner's if there are no workers)
+    pub fn average_skill(&self) -> f64 {
+        if self.workers.is_empty() {
+            return quality::OWNER_SKILL;
+        }
+        self.workers.iter().map(|w| w.skill).sum::<f64>() / self.workers.len() as f64
+    }
+
+    /// Returns the quality score of the factory's output today, from materials at its current grade
+    pub fn quality_score(&self) -> f64 {
+        quality::quality_score(self.average_skill(), self.equipment_level, self.material_grade)
+    }
+
+    /// Returns the quality score a job comes out at: the line's work with the job's own
+    /// raw materials, blended with its components by their share of the ingredients
+    pub fn job_quality(&self, job: &ProductionJob) -> f64 {
+        let line = quality::line_quality(self.average_skill(), self.equipment_level, job.material_bonus);
+        line * (1.0 - job.component_share) + job.component_quality * job.component_share
+    }
+
+    /// Returns the average quality score of a product in storage
+    pub fn quality_of(&self, product_id: u32) -> f64 {
+        *self.quality.get(&product_id).unwrap_or(&QualityTier::DEFAULT_SCORE)
+    }
+
+    /// Folds incoming units into the average quality (call before adding the units)
+    fn merge_quality(&mut self, product_id: u32, quantity: u32, score: f64) {
+        let on_hand = self.get_component(product_id) + self.get_finished_good(product_id);
+        let total = on_hand + quantity;
+        if total == 0 {
+            return;
+        }
+        let average = (self.quality_of(product_id) * on_hand as f64 + score * quantity as f64)
+            / total as f64;
+        self.quality.insert(product_id, average);
+    }
+
+    /// Raises the equipment level by one
+    pub fn upgrade_equipment(&mut self) -> Result<u32, String> {
+        if self.equipment_level >= quality::MAX_EQUIPMENT_LEVEL {
+            return Err("Equipment is already at the highest level".to_string());
+        }
+        self.equipment_level += 1;
+        Ok(self.equipment_level)
+    }
+
+    /// Returns one production slot's share of a day's labor and overhead
+    pub fn slot_cost(&self) -> f64 {
+        self.daily_expenses() / self.production_slots() as f64
+    }
+
     /// Advances all production jobs by one day, returns completed products
+    /// Each job absorbs a slot's share of the day's rent and wages, and finished
+    /// batches lose their defective units (their cost falls on the good units)
+    /// Quality comes from the materials the job started with, not the current grade.
     pub fn advance_production(&mut self) -> Vec<ProductionResult> {
         let mut completed = Vec::new();
         let mut still_in_progress = Vec::new();
+        let slot_cost = self.slot_cost();
 
-        for mut job in self.production_queue.drain(..) {
+        let jobs: Vec<ProductionJob> = self.production_queue.drain(..).collect();
+        for mut job in jobs {
             job.days_remaining -= 1;
+            job.cost += slot_cost;
             if job.days_remaining == 0 {
-                // Job complete - add to finished goods
-                *self.finished_goods.entry(job.output_product_id).or_insert(0) +=
-                    job.output_quantity;
+                let quality = self.job_quality(&job);
+                self.defect_backlog += quality::defect_rate(quality) * job.output_quantity as f64;
+                let defective = (self.defect_backlog.floor() as u32).min(job.output_quantity);
+                self.defect_backlog -= defective as f64;
+                let good = job.output_quantity - defective;
+
+                // Job complete - add to finished goods (or components for other recipes)
+                let unit_cost = job.cost / good.max(1) as f64;
+                self.merge_unit_cost(job.output_product_id, good, unit_cost);
+                self.merge_quality(job.output_product_id, good, quality);
+                let storage = if job.intermediate {
+                    &mut self.components
+                } else {
+                    &mut self.finished_goods
+                };
+                *storage.entry(job.output_product_id).or_insert(0) += good;
                 completed.push(ProductionResult {
                     recipe_name: job.recipe_name,
                     product_id: job.output_product_id,
-                    quantity: job.output_quantity,
+                    quantity: good,
+                    unit_cost,
+                    quality,
+                    defective,
+                    intermediate: job.intermediate,
                 });
             } else {
                 still_in_progress.push(job);
@@ -179,9 +484,129 @@ impl Factory {
         }
 
         self.production_queue = still_in_progress;
+
+        // Workers learn on the job
+        if self.active_jobs() > 0 || !completed.is_empty() {
+            for worker in &mut self.workers {
+                worker.skill = (worker.skill + FactoryWorker::DAILY_SKILL_GAIN).min(1.0);
+            }
+        }
         completed
     }
 
+    /// Adds a standing order (replacing any existing order for the same recipe)
+    pub fn add_standing_order(&mut self, order: StandingOrder) -> Result<(), String> {
+        match order.target {
+            OrderTarget::StockLevel(0) | OrderTarget::DailyCount(0) => {
+                return Err("Target must be at least 1".to_string());
+            }
+            _ => {}
+        }
+        if let Some(existing) = self
+            .standing_orders
+            .iter_mut()
+            .find(|o| o.recipe_id == order.recipe_id)
+        {
+            *existing = order;
+            return Ok(());
+        }
+        if self.standing_orders.len() >= Self::MAX_STANDING_ORDERS {
+            return Err(format!(
+                "Maximum of {} standing orders per factory",
+                Self::MAX_STANDING_ORDERS
+            ));
+        }
+        self.standing_orders.push(order);
+        Ok(())
+    }
+
+    /// Cancels a standing order by index
+    pub fn cancel_standing_order(&mut self, index: usize) -> Result<StandingOrder, String> {
+        if index >= self.standing_orders.len() {
+            return Err("Invalid standing order index".to_string());
+        }
+        Ok(self.standing_orders.remove(index))
+    }
+
+    /// Returns units of a recipe's output in storage plus those still being made
+    pub fn projected_stock(&self, recipe: &Recipe) -> u32 {
+        let stored = if recipe.intermediate {
+            self.get_component(recipe.output_product_id)
+        } else {
+            self.get_finished_good(recipe.output_product_id)
+        };
+        let in_progress: u32 = self
+            .production_queue
+            .iter()
+            .filter(|job| job.recipe_id == recipe.id)
+            .map(|job| job.output_quantity)
+            .sum();
+        stored + in_progress
+    }
+
+    /// Returns how many jobs a standing order wants started today
+    fn jobs_wanted(&self, order: &StandingOrder, recipe: &Recipe) -> u32 {
+        match order.target {
+            OrderTarget::DailyCount(count) => count,
+            OrderTarget::StockLevel(level) => level
+                .saturating_sub(self.projected_stock(recipe))
+                .div_ceil(recipe.output_quantity.max(1)),
+        }
+    }
+
+    /// Starts jobs for every standing order that has room and materials,
+    /// logging what was started or blocked
+    pub fn run_standing_orders(&mut self, recipes: &[Recipe], day: u32) -> Vec<OrderLogEntry> {
+        let mut entries = Vec::new();
+
+        for order in self.standing_orders.clone() {
+            let recipe = match recipes.iter().find(|r| r.id == order.recipe_id) {
+                Some(r) => r,
+                None => {
+                    entries.push(OrderLogEntry {
+                        day,
+                        recipe_id: order.recipe_id,
+                        recipe_name: "Unknown".to_string(),
+                        started: 0,
+                        blocked: Some(BlockReason::UnknownRecipe),
+                    });
+                    continue;
+                }
+            };
+
+            let wanted = self.jobs_wanted(&order, recipe);
+            if wanted == 0 {
+                continue;
+            }
+
+            let mut started = 0;
+            while started < wanted && self.start_production(recipe).is_ok() {
+                started += 1;
+            }
+
+            let blocked = if started == wanted {
+                None
+            } else if self.available_slots() == 0 {
+                Some(BlockReason::NoSlots)
+            } else {
+                Some(BlockReason::MissingIngredients(self.missing_ingredients(recipe)))
+            };
+
+            entries.push(OrderLogEntry {
+                day,
+                recipe_id: recipe.id,
+                recipe_name: recipe.name.clone(),
+                started,
+                blocked,
+            });
+        }
+
+        self.order_log.extend(entries.iter().cloned());
+        let overflow = self.order_log.len().saturating_sub(Self::ORDER_LOG_LENGTH);
+        self.order_log.drain(..overflow);
+        entries
+    }
+
     /// Removes finished goods from factory storage (for transfer to store)
     pub fn take_finished_goods(&mut self, product_id: u32, quantity: u32) -> Result<u32, String> {
         let available = self.get_finished_good(product_id);
@@ -203,15 +628,54 @@ impl Factory {
         self.daily_rent + salaries
     }
 
-    /// Hires a new worker (max 3 workers per factory)
-    pub fn hire_worker(&mut self, name: &str) -> Result<(), String> {
+    /// Hires a new worker at the given daily salary (max 3 workers per factory)
+    pub fn hire_worker(&mut self, name: &str, salary: f64) -> Result<(), String> {
         if self.workers.len() >= 3 {
             return Err("Maximum of 3 workers per factory".to_string());
         }
-        self.workers.push(FactoryWorker::new(name));
+        self.workers.push(FactoryWorker {
+            salary,
+            ..FactoryWorker::new(name)
+        });
         Ok(())
     }
 
+    /// Raises (or cuts) every worker's salary by a cost-of-living factor
+    pub fn apply_wage_review(&mut self, factor: f64) {
+        for worker in &mut self.workers {
+            worker.salary *= factor;
+        }
+    }
+
+    /// Raises any worker paid below the minimum wage up to it
+    /// Returns the number of workers given a raise
+    pub fn apply_minimum_wage(&mut self, minimum_wage: f64) -> u32 {
+        let mut raised = 0;
+        for worker in self.workers.iter_mut().filter(|w| w.salary < minimum_wage) {
+            worker.salary = minimum_wage;
+            raised += 1;
+        }
+        raised
+    }
+
+    /// Signs a new lease with rent at the given price level
+    pub fn sign_lease(&mut self, price_level: f64) {
+        self.daily_rent = Self::BASE_RENT * price_level;
+        self.lease_days_remaining = Self::LEASE_TERM_DAYS;
+    }
+
+    /// Counts down the lease; on expiry renews it at the current price level
+    /// Returns (old_rent, new_rent) if the lease was renewed
+    pub fn advance_lease(&mut self, price_level: f64) -> Option<(f64, f64)> {
+        self.lease_days_remaining = self.lease_days_remaining.saturating_sub(1);
+        if self.lease_days_remaining > 0 {
+            return None;
+        }
+        let old_rent = self.daily_rent;
+        self.sign_lease(price_level);
+        Some((old_rent, self.daily_rent))
+    }
+
     /// Fires a worker by index
     pub fn fire_worker(&mut self, index: usize) -> Result<FactoryWorker, String> {
         if index >= self.workers.len() {
@@ -230,6 +694,11 @@ impl Factory {
         self.finished_goods.values().sum()
     }
 
+    /// Returns total component count
+    pub fn total_components(&self) -> u32 {
+        self.components.values().sum()
+    }
+
     /// Calculates how many times a recipe can be produced given available slots and materials
     pub fn max_producible(&self, recipe: &Recipe) -> u32 {
         // Limited by available slots
@@ -243,7 +712,7 @@ impl Factory {
             .ingredients
             .iter()
             .map(|ing| {
-                let have = self.get_raw_material(ing.product_id);
+                let have = self.get_ingredient(ing.product_id);
                 have / ing.quantity
             })
             .min()
@@ -272,14 +741,9 @@ impl Factory {
 
         // Start each job
         for _ in 0..actual_quantity {
-            // Consume raw materials
-            for ing in &recipe.ingredients {
-                if let Some(qty) = self.raw_materials.get_mut(&ing.product_id) {
-                    *qty -= ing.quantity;
-                }
-            }
             // Add job to queue
-            self.production_queue.push(ProductionJob::new(recipe));
+            let job = self.consume_ingredients(recipe);
+            self.production_queue.push(job);
         }
 
         Ok(actual_quantity)
@@ -294,9 +758,20 @@ impl Factory {
         }
     }
 
+    /// Sets how finished goods are split between connected stores
+    pub fn set_distribution(&mut self, policy: DistributionPolicy) -> Result<(), String> {
+        policy.validate(&self.connected_stores)?;
+        self.distribution = policy;
+        self.distribution_cursor = 0;
+        Ok(())
+    }
+
     /// Disconnects this factory from a store
     pub fn disconnect_store(&mut self, store_id: u32) {
         self.connected_stores.retain(|&id| id != store_id);
+        if let DistributionPolicy::Manual(shares) = &mut self.distribution {
+            shares.retain(|(id, _)| *id != store_id);
+        }
     }
 
     /// Checks if this factory is connected to a specific store
@@ -319,3 +794,190 @@ impl Factory {
         self.connected_stores.first().copied()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standing_order_tops_up_stock() {
+        let recipes = Recipe::default_recipes();
+        let board = Recipe::producing(&recipes, 22).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(15, 4, 1.0, MaterialGrade::Standard);
+        factory.add_raw_material(14, 1, 1.0, MaterialGrade::Standard);
+        factory
+            .add_standing_order(StandingOrder::new(board.id, OrderTarget::StockLevel(2)))
+            .unwrap();
+
+        // Enough electronics for two boards but plastic for only one
+        let entries = factory.run_standing_orders(&recipes, 1);
+        assert_eq!(entries[0].started, 1);
+        assert_eq!(entries[0].blocked, Some(BlockReason::MissingIngredients(vec![(14, 1)])));
+
+        // The job in progress counts towards the target
+        factory.add_raw_material(14, 1, 1.0, MaterialGrade::Standard);
+        factory
+            .add_standing_order(StandingOrder::new(board.id, OrderTarget::StockLevel(1)))
+            .unwrap();
+        assert_eq!(factory.standing_orders.len(), 1);
+        assert!(factory.run_standing_orders(&recipes, 2).is_empty());
+        assert_eq!(factory.order_log.len(), 1);
+    }
+
+    #[test]
+    fn test_daily_count_retries_when_slots_free_up() {
+        let recipes = Recipe::default_recipes();
+        let chair = Recipe::producing(&recipes, 16).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(11, 20, 1.0, MaterialGrade::Standard);
+        factory
+            .add_standing_order(StandingOrder::new(chair.id, OrderTarget::DailyCount(3)))
+            .unwrap();
+
+        // Two slots for three jobs a day
+        let first = factory.run_standing_orders(&recipes, 1);
+        assert_eq!(first[0].started, 2);
+        assert_eq!(first[0].blocked, Some(BlockReason::NoSlots));
+        // Nothing frees up until production moves on
+        assert_eq!(factory.run_standing_orders(&recipes, 1)[0].started, 0);
+
+        factory.advance_production();
+        let second = factory.run_standing_orders(&recipes, 2);
+        assert_eq!(second[0].started, 2);
+        assert_eq!(factory.order_log.len(), 3);
+        assert_eq!(factory.order_log.last().unwrap().day, 2);
+    }
+
+    #[test]
+    fn test_blocked_orders_are_logged_and_retried() {
+        let recipes = Recipe::default_recipes();
+        let chair = Recipe::producing(&recipes, 16).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory
+            .add_standing_order(StandingOrder::new(chair.id, OrderTarget::DailyCount(1)))
+            .unwrap();
+        factory
+            .add_standing_order(StandingOrder::new(999, OrderTarget::DailyCount(1)))
+            .unwrap();
+
+        let entries = factory.run_standing_orders(&recipes, 1);
+        assert_eq!(entries[0].started, 0);
+        assert_eq!(entries[0].blocked, Some(BlockReason::MissingIngredients(vec![(11, 2)])));
+        assert_eq!(entries[1].blocked, Some(BlockReason::UnknownRecipe));
+
+        // Lumber arrives: the chair order goes through the next day
+        factory.add_raw_material(11, 2, 1.0, MaterialGrade::Standard);
+        let entries = factory.run_standing_orders(&recipes, 2);
+        assert_eq!(entries[0].started, 1);
+        assert_eq!(entries[0].blocked, None);
+
+        // The log keeps only the most recent entries
+        for day in 3..=40 {
+            factory.run_standing_orders(&recipes, day);
+        }
+        assert_eq!(factory.order_log.len(), Factory::ORDER_LOG_LENGTH);
+        assert_eq!(factory.order_log.last().unwrap().day, 40);
+    }
+
+    #[test]
+    fn test_unit_cost_includes_materials_and_overhead() {
+        let recipes = Recipe::default_recipes();
+        let board = Recipe::producing(&recipes, 22).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(15, 2, 10.0, MaterialGrade::Standard);
+        factory.add_raw_material(14, 1, 4.0, MaterialGrade::Standard);
+
+        factory.start_production(board).unwrap();
+        let done = factory.advance_production();
+        // $24 of materials plus one slot-day of rent
+        let expected = 24.0 + Factory::BASE_RENT / 2.0;
+        assert!((done[0].unit_cost - expected).abs() < 1e-9);
+        assert!((factory.unit_cost(22) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_defects_are_removed_from_output() {
+        let recipes = Recipe::default_recipes();
+        let chair = Recipe::producing(&recipes, 16).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(11, 40, 1.0, MaterialGrade::Standard);
+        let rate = quality::defect_rate(factory.quality_score());
+
+        let mut good = 0;
+        let mut defective = 0;
+        for _ in 0..10 {
+            factory.start_production_batch(chair, 2).unwrap();
+            for result in factory.advance_production() {
+                good += result.quantity;
+                defective += result.defective;
+            }
+        }
+        assert_eq!(good + defective, 20);
+        assert_eq!(defective, (20.0 * rate).floor() as u32);
+        assert_eq!(factory.get_finished_good(16), good);
+    }
+
+    #[test]
+    fn test_grade_change_mid_job_keeps_the_job_quality() {
+        let recipes = Recipe::default_recipes();
+        let table = Recipe::producing(&recipes, 17).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.material_grade = MaterialGrade::Premium;
+        factory.add_raw_material(12, 2, 1.0, MaterialGrade::Premium);
+        factory.add_raw_material(11, 1, 1.0, MaterialGrade::Premium);
+        let premium = factory.quality_score();
+
+        factory.start_production(table).unwrap();
+        factory.material_grade = MaterialGrade::Standard;
+        assert!(factory.advance_production().is_empty());
+        let done = factory.advance_production();
+        assert!((done[0].quality - premium).abs() < 1e-9);
+        assert!((factory.quality_of(17) - premium).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_component_quality_carries_into_assembly() {
+        let recipes = Recipe::default_recipes();
+        let board = Recipe::producing(&recipes, 22).unwrap();
+        let phone = Recipe::producing(&recipes, 20).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(15, 2, 1.0, MaterialGrade::Premium);
+        factory.add_raw_material(14, 1, 1.0, MaterialGrade::Premium);
+        factory.add_raw_material(14, 1, 1.0, MaterialGrade::Standard);
+
+        factory.start_production(board).unwrap();
+        let board_quality = factory.advance_production()[0].quality;
+        let standard = factory.quality_score();
+        assert!(board_quality > standard);
+
+        // Half the phone's inputs are the premium board
+        factory.start_production(phone).unwrap();
+        factory.advance_production();
+        let done = factory.advance_production();
+        assert!(done[0].quality > standard);
+        assert!(done[0].quality < board_quality);
+    }
+
+    #[test]
+    fn test_components_feed_later_stages() {
+        let recipes = Recipe::default_recipes();
+        let board = Recipe::producing(&recipes, 22).unwrap();
+        let phone = Recipe::producing(&recipes, 20).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(15, 2, 1.0, MaterialGrade::Standard);
+        factory.add_raw_material(14, 2, 1.0, MaterialGrade::Standard);
+
+        assert!(!factory.has_ingredients(phone));
+        factory.start_production(board).unwrap();
+        factory.advance_production();
+        assert_eq!(factory.get_component(22), 1);
+        assert_eq!(factory.get_finished_good(22), 0);
+
+        factory.start_production(phone).unwrap();
+        assert_eq!(factory.get_component(22), 0);
+        factory.advance_production();
+        factory.advance_production();
+        assert_eq!(factory.get_finished_good(20), 1);
+    }
+}
@@ -1,25 +1,62 @@
 use std::collections::HashMap;
-use crate::competitor::CompetitiveMarket;
-use crate::economy::{EconomicState, Market};
-use crate::factory::ProductionResult;
+use crate::calendar::Calendar;
+use crate::distribution::{allocate, DistributionPolicy, StoreDemand};
+use crate::economy::{price_range, EconomicState, ForecastScenario, Market, SalesForecast};
+use crate::event::MacroEvent;
+use crate::factory::{OrderLogEntry, OrderTarget, ProductionResult, StandingOrder};
 use crate::loan::{Loan, LoanType};
+use crate::logistics::{Cargo, Logistics, Truck, TruckOwnership};
+use crate::manager::{ManagerMandate, ManagerReport, StoreManager};
+use crate::marketing::{CampaignChannel, CampaignTarget, MarketingCampaign};
+use crate::news::{Headline, NewsCategory, NewsFeed};
+use crate::planning::{ProductionPlan, ProductionTarget, Shortfall};
 use crate::player::Player;
+use crate::procurement::{ProcurementReport, ProcurementRule};
 use crate::product::Product;
+use crate::promotion::{LoyaltyProgram, Promotion, PromotionKind};
+use crate::quality::{self, DefectHandling, MaterialGrade, QualityTier};
 use crate::recipe::Recipe;
+use crate::regulation::Regulations;
+use crate::region::Region;
+use crate::reputation::ServiceReport;
+use crate::shopper::{simulate_shoppers, DemandModel, ShelfOffer, ShoppingDay};
+use crate::store::{DemandRecord, Employee};
 use crate::stock::StockMarket;
+use crate::tax::{TaxFiling, TaxLedger, TaxPolicy};
+use crate::transfer::{InternalTransfer, TransferLedger};
+use crate::trade::{Destination, ForeignTrade, ImportQuote, ShipmentKind};
 
 /// Represents the complete game state
 pub struct GameState {
     pub day: u32,
     pub player: Player,
     pub market: Market,
-    pub competitive_market: CompetitiveMarket,
+    /// Cities the player can operate in, each with its own competitors
+    pub regions: Vec<Region>,
     pub stock_market: StockMarket,
     pub products: Vec<Product>,
     pub recipes: Vec<Recipe>,
     pub current_store: usize,
     pub current_factory: Option<usize>,
     pub is_bankrupt: bool,
+    /// Daily news archive
+    pub news: NewsFeed,
+    /// How customer demand is simulated
+    pub demand_model: DemandModel,
+    /// Seed for the agent-based shopper simulation
+    pub simulation_seed: u64,
+    /// Tax rates, deductions and filings
+    pub taxes: TaxLedger,
+    /// Minimum wage, price controls and business licences
+    pub regulations: Regulations,
+    /// Exchange rates, overseas suppliers and buyers, shipments at sea
+    pub trade: ForeignTrade,
+    /// Transfer pricing settings and factory-to-store shipment records
+    pub transfers: TransferLedger,
+    /// Trucks and goods on the road between factories and stores
+    pub logistics: Logistics,
+    /// Shipping paid since the last day's report (trips and couriers)
+    pub shipping_costs: f64,
 }
 
 /// Result of simulating a day's sales
@@ -36,6 +73,8 @@ pub struct DayResult {
     // Economic state fields
     pub economic_state: EconomicState,
     pub economic_change: Option<String>,       // "Economy improved to Growth!"
+    pub date: String,                          // "Sat, Jan 6 (Year 1)"
+    pub holiday: Option<String>,               // Holiday in effect, if any
     // Loan fields
     pub loan_interest_accrued: f64,            // Total interest accrued today
     pub loan_payments: Vec<(u32, f64)>,        // (loan_id, amount_paid) - auto-payments
@@ -44,12 +83,66 @@ pub struct DayResult {
     pub term_loan_penalties: f64,              // Penalties for defaulted term loans
     // Supply chain auto-transfers: (factory_name, store_name, product_name, quantity)
     pub auto_transfers: Vec<(String, String, String, u32)>,
+    // Finished goods the distribution policy left at the factory: (factory_name, product_name, quantity)
+    pub distribution_held: Vec<(String, String, u32)>,
+    // Deliveries that reached stores: (factory_name, store_name, units)
+    pub deliveries_arrived: Vec<(String, String, u32)>,
+    pub shipping_costs: f64,                   // Trips, couriers and truck leases since yesterday
+    // Standing production orders: (factory_name, what was started or blocked)
+    pub standing_orders: Vec<(String, OrderLogEntry)>,
+    // Raw materials bought automatically by factory procurement rules
+    pub procurement_reports: Vec<ProcurementReport>,
+    // Defective units: (factory_name, product_name, quantity, sold_as_seconds)
+    pub defects: Vec<(String, String, u32, bool)>,
+    pub seconds_revenue: f64,                  // Paid by outlets for defective goods
     // Competitor events
     pub competitor_events: Vec<String>,
     pub player_market_share: f64,
+    pub regional_shares: Vec<(String, f64, u32)>, // (region name, player share, market size)
     // Stock market events
     pub stock_changes: Vec<(String, f64, f64)>,  // (symbol, old_price, new_price)
     pub dividends_earned: f64,
+    // Store manager reports
+    pub manager_reports: Vec<ManagerReport>,
+    // Marketing
+    pub active_campaigns: usize,
+    pub campaigns_ended: Vec<String>,
+    // Promotions: (store_name, promotion, units_sold, discount_given)
+    pub promotion_sales: Vec<(String, String, u32, f64)>,
+    pub promotion_discounts: f64,
+    pub promotions_ended: Vec<(String, String)>, // (store_name, promotion)
+    pub loyalty_rewards: f64,
+    // Reputation: (store_name, old_score, new_score)
+    pub reputation_changes: Vec<(String, f64, f64)>,
+    // Unmet demand: (store_name, product_name, units_lost, revenue_lost)
+    pub lost_sales: Vec<(String, String, u32, f64)>,
+    pub total_lost_revenue: f64,
+    // Inflation
+    pub price_level: f64,
+    pub inflation_rate: f64,                      // Annual rate
+    pub lease_renewals: Vec<(String, f64, f64)>,  // (location_name, old_rent, new_rent)
+    pub wage_review: Option<f64>,                 // Cost-of-living factor applied to all wages
+    // Macro events
+    pub events_started: Vec<(String, String, u32)>, // (name, description, days)
+    pub events_ended: Vec<String>,
+    pub active_events: Vec<(String, u32)>,          // (name, days_remaining)
+    // News: (section, headline)
+    pub headlines: Vec<(String, String)>,
+    // Agent-based shopper results per store (empty in formula mode)
+    pub shopper_stats: Vec<(String, ShoppingDay)>,
+    // Taxes
+    pub sales_tax: f64,
+    pub payroll_tax: f64,
+    pub depreciation: f64,                  // Deductible wear on stores and factories
+    pub tax_filing: Option<TaxFiling>,      // Quarterly corporate tax return, if filed today
+    // Government regulation
+    pub regulation_events: Vec<String>,     // Rule changes, licence renewals and lapses
+    pub fines: Vec<(String, f64)>,          // (reason, amount)
+    // Foreign trade
+    pub exchange_rates: Vec<(String, f64, f64)>,             // (currency code, rate, daily change)
+    pub imports_arrived: Vec<(String, String, u32, String)>, // (supplier, product, quantity, destination)
+    pub imports_undeliverable: Vec<(String, String, u32)>,   // (supplier, product, quantity) - nowhere to deliver
+    pub exports_paid: Vec<(String, String, u32, f64)>,       // (buyer, product, quantity, dollars received)
 }
 
 impl GameState {
@@ -59,23 +152,78 @@ impl GameState {
         let market = Market::new(&products);
         let player = Player::new(1000.0, "My First Store");
         let recipes = Recipe::default_recipes();
-        let competitive_market = CompetitiveMarket::new();
+        let regions = Region::default_regions();
         let stock_market = StockMarket::new();
+        let trade = ForeignTrade::new(&products);
 
         GameState {
             day: 1,
             player,
             market,
-            competitive_market,
+            regions,
             stock_market,
             products,
             recipes,
             current_store: 0,
             current_factory: None,
             is_bankrupt: false,
+            news: NewsFeed::new(),
+            demand_model: DemandModel::Formula,
+            simulation_seed: 20_240_601,
+            taxes: TaxLedger::new(TaxPolicy::default()),
+            regulations: Regulations::new(Region::HOME_ID),
+            trade,
+            transfers: TransferLedger::default(),
+            logistics: Logistics::with_routes(&Region::default_routes()),
+            shipping_costs: 0.0,
         }
     }
 
+    /// Returns the calendar date for the current (not yet simulated) day
+    pub fn calendar(&self) -> Calendar {
+        Calendar::from_day(self.day)
+    }
+
+    /// Returns the going daily wage for a base salary as of the last wage review
+    /// (never below the minimum wage); inflation since then is paid at the next review
+    pub fn going_wage(&self, base_salary: f64) -> f64 {
+        (base_salary * self.market.wage_index).max(self.regulations.minimum_wage)
+    }
+
+    /// Returns the highest legal retail price for a product, if price controls cap it
+    pub fn price_cap(&self, product: &Product) -> Option<f64> {
+        self.regulations
+            .price_cap(product.category, self.market.reference_price(product))
+    }
+
+    /// Returns a shelf price lowered to the product's legal cap, if it has one
+    pub fn capped_price(&self, product_id: u32, price: f64) -> f64 {
+        match self.get_product(product_id).and_then(|p| self.price_cap(p)) {
+            Some(cap) => price.min(cap),
+            None => price,
+        }
+    }
+
+    /// Returns the cost of a business licence in a region
+    pub fn licence_fee(&self, region_id: u32) -> Option<f64> {
+        self.region(region_id)
+            .map(|r| Regulations::licence_fee(r.rent_multiplier, self.market.price_level))
+    }
+
+    /// Buys (or renews) a business licence for a region
+    pub fn buy_business_licence(&mut self, region_id: u32) -> Result<f64, String> {
+        let fee = self.licence_fee(region_id).ok_or("Invalid region")?;
+        if !self.player.spend(fee) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                fee, self.player.cash
+            ));
+        }
+        self.regulations.grant_licence(region_id);
+        self.taxes.record_expense(fee);
+        Ok(fee)
+    }
+
     /// Gets the current store reference
     pub fn current_store(&self) -> &crate::store::Store {
         self.player.store_at(self.current_store)
@@ -95,26 +243,332 @@ impl GameState {
         Ok(())
     }
 
-    /// Buys a new store
+    /// Base cost of opening a store (scaled by regional property costs)
+    pub const NEW_STORE_COST: f64 = 5000.0;
+    /// Base cost of opening a factory (scaled by regional property costs)
+    pub const NEW_FACTORY_COST: f64 = 10000.0;
+
+    /// Buys a new store in a region
     /// Returns competitor reactions to the expansion
-    pub fn buy_new_store(&mut self, name: &str) -> Result<Vec<String>, String> {
-        const NEW_STORE_COST: f64 = 5000.0;
+    pub fn buy_new_store(&mut self, name: &str, region_id: u32) -> Result<Vec<String>, String> {
+        let region_idx = self.region_index(region_id).ok_or("Invalid region")?;
+        if !self.regulations.has_licence(region_id) {
+            return Err(format!(
+                "You need a business licence to open a store in {}",
+                self.regions[region_idx].name
+            ));
+        }
+        let rent_multiplier = self.regions[region_idx].rent_multiplier;
+        let cost = Self::NEW_STORE_COST * rent_multiplier;
 
-        if self.player.cash < NEW_STORE_COST {
+        if self.player.cash < cost {
             return Err(format!(
                 "Not enough cash! Need ${:.2}, have ${:.2}",
-                NEW_STORE_COST, self.player.cash
+                cost, self.player.cash
             ));
         }
 
-        self.player.spend(NEW_STORE_COST);
-        self.player.add_store(name);
+        self.player.spend(cost);
+        self.player.add_store(name, region_id);
+        self.taxes.add_asset(name, cost);
+        let price_level = self.market.price_level;
+        if let Some(store) = self.player.stores.last_mut() {
+            store.sign_lease(price_level * rent_multiplier);
+        }
 
-        // Notify competitors and get their reactions
-        let reactions = self.competitive_market.notify_player_expansion();
+        // Notify local competitors and get their reactions
+        let reactions = self.regions[region_idx].market.notify_player_expansion();
         Ok(reactions)
     }
 
+    // ==================== REGION METHODS ====================
+
+    /// Returns the index of a region by ID
+    pub fn region_index(&self, region_id: u32) -> Option<usize> {
+        self.regions.iter().position(|r| r.id == region_id)
+    }
+
+    /// Gets a region by ID
+    pub fn region(&self, region_id: u32) -> Option<&Region> {
+        self.regions.iter().find(|r| r.id == region_id)
+    }
+
+    /// Gets the region a store is in
+    fn store_region(&self, store_idx: usize) -> &Region {
+        let region_id = self.player.stores[store_idx].region_id;
+        self.region(region_id).unwrap_or(&self.regions[0])
+    }
+
+    /// Returns the region of the current store
+    pub fn current_region(&self) -> &Region {
+        self.store_region(self.current_store)
+    }
+
+    /// Gets the wholesale price of a product in a region
+    pub fn regional_wholesale_price(&self, region_id: u32, product_id: u32) -> Option<f64> {
+        let multiplier = self.region(region_id).map(|r| r.price_multiplier).unwrap_or(1.0);
+        self.market
+            .get_wholesale_price(product_id)
+            .map(|price| price * multiplier)
+    }
+
+    /// Gets the wholesale price of a product for the current store's region
+    pub fn store_wholesale_price(&self, product_id: u32) -> Option<f64> {
+        self.regional_wholesale_price(self.current_store().region_id, product_id)
+    }
+
+    /// Gets the wholesale price of a product for the current factory
+    /// (its region's price at the factory's material grade)
+    pub fn factory_wholesale_price(&self, product_id: u32) -> Option<f64> {
+        match self.current_factory {
+            Some(factory_idx) => self.factory_material_price(factory_idx, product_id),
+            None => self.regional_wholesale_price(Region::HOME_ID, product_id),
+        }
+    }
+
+    /// Gets the price a factory pays for a raw material at its material grade
+    fn factory_material_price(&self, factory_idx: usize, product_id: u32) -> Option<f64> {
+        let factory = self.player.factories.get(factory_idx)?;
+        self.regional_wholesale_price(factory.region_id, product_id)
+            .map(|price| price * factory.material_grade.price_multiplier())
+    }
+
+    /// Returns the player's market share across regions where they have stores,
+    /// weighted by market size (home region share if there are no stores)
+    pub fn overall_market_share(&self) -> f64 {
+        let mut weighted = 0.0;
+        let mut customers = 0.0;
+        for region in &self.regions {
+            if self.player.stores.iter().any(|s| s.region_id == region.id) {
+                let size = region.market.total_market_size as f64;
+                weighted += region.market.player_market_share * size;
+                customers += size;
+            }
+        }
+        if customers > 0.0 {
+            weighted / customers
+        } else {
+            self.regions[0].market.player_market_share
+        }
+    }
+
+    // ==================== STORE MANAGER METHODS ====================
+
+    /// Hires a manager for the current store
+    /// Returns the name of the manager that was replaced, if any
+    pub fn hire_store_manager(&mut self, name: &str, mandate: ManagerMandate) -> Result<Option<String>, String> {
+        if name.trim().is_empty() {
+            return Err("Manager name cannot be empty".to_string());
+        }
+        let wage_index = self.market.wage_index;
+        let replaced = self.current_store_mut().hire_manager(name, mandate, wage_index);
+        Ok(replaced.map(|m| m.name))
+    }
+
+    /// Fires the manager of the current store
+    pub fn fire_store_manager(&mut self) -> Result<StoreManager, String> {
+        self.current_store_mut().fire_manager()
+    }
+
+    /// Updates the mandate of the current store's manager
+    pub fn set_manager_mandate(&mut self, mandate: ManagerMandate) -> Result<(), String> {
+        let manager = self
+            .current_store_mut()
+            .manager
+            .as_mut()
+            .ok_or("This store has no manager")?;
+        manager.mandate = mandate;
+        Ok(())
+    }
+
+    /// Lets the manager of a store make their daily staffing, pricing and restock decisions
+    fn run_store_manager(&mut self, store_idx: usize) -> Option<ManagerReport> {
+        let store = &self.player.stores[store_idx];
+        let manager = store.manager.clone()?;
+        let mut report = ManagerReport::new(&store.name, &manager.name);
+
+        // 1. Staffing
+        let change = manager.staffing_change(store.employees.len());
+        if change > 0 {
+            let salary = self.going_wage(Employee::BASE_SALARY);
+            for _ in 0..change {
+                let store = &mut self.player.stores[store_idx];
+                let name = format!("Clerk {}", store.employees.len() + 1);
+                if store.hire_employee(&name, salary).is_ok() {
+                    report.hired.push(name);
+                }
+            }
+        } else if change < 0 {
+            for _ in 0..(-change) {
+                let store = &mut self.player.stores[store_idx];
+                let last = store.employees.len().saturating_sub(1);
+                if let Ok(fired) = store.fire_employee(last) {
+                    report.fired.push(fired.name);
+                }
+            }
+        }
+
+        let customer_count = self.store_customer_count(store_idx);
+        let region_id = self.player.stores[store_idx].region_id;
+
+        // Clone inventory keys to avoid borrow issues
+        let mut product_ids: Vec<u32> = self.player.stores[store_idx]
+            .inventory
+            .keys()
+            .copied()
+            .collect();
+        product_ids.sort();
+
+        // 2. Pricing and restocking
+        let mut budget = manager.mandate.restock_budget;
+        for product_id in product_ids {
+            let product = match self.get_product(product_id) {
+                Some(p) => p.clone(),
+                None => continue,
+            };
+            let wholesale = match self.regional_wholesale_price(region_id, product_id) {
+                Some(price) => price,
+                None => continue,
+            };
+
+            // Managers charge for the stock's quality and keep prices within any legal cap
+            let quality_value = self.player.stores[store_idx].quality_tier(product_id).price_multiplier();
+            let target_price = self.capped_price(product_id, manager.mandate.target_price(wholesale) * quality_value);
+            let store = &mut self.player.stores[store_idx];
+            let old_price = store.get_price(product_id).unwrap_or(target_price);
+            if (old_price - target_price).abs() >= 0.01 {
+                store.set_price(product_id, target_price);
+                report.price_changes.push((product.name.clone(), old_price, target_price));
+            }
+
+            let on_hand = store.get_quantity(product_id);
+            let promotions = store.active_promotions();
+            let expected =
+                self.market
+                    .calculate_demand(&product, target_price / quality_value, customer_count, &promotions);
+            let wanted = StoreManager::restock_quantity(expected, on_hand);
+            if wanted == 0 {
+                continue;
+            }
+
+            let spendable = budget.min(self.player.cash.max(0.0));
+            let affordable = (spendable / wholesale) as u32;
+            let quantity = wanted.min(affordable);
+            if quantity == 0 {
+                report.skipped.push(product.name.clone());
+                continue;
+            }
+
+            let cost = wholesale * quantity as f64;
+            if self.player.spend(cost) {
+                self.taxes.record_purchase(cost);
+                budget -= cost;
+                self.player.stores[store_idx].add_inventory(product_id, quantity, target_price);
+                report.restocked.push((product.name.clone(), quantity, cost));
+            }
+        }
+
+        Some(report)
+    }
+
+    // ==================== MARKETING METHODS ====================
+
+    /// Launches an advertising campaign, paying the full cost upfront
+    /// Returns the campaign ID
+    pub fn launch_campaign(
+        &mut self,
+        channel: CampaignChannel,
+        target: CampaignTarget,
+        days: u32,
+    ) -> Result<u32, String> {
+        if !(MarketingCampaign::MIN_DAYS..=MarketingCampaign::MAX_DAYS).contains(&days) {
+            return Err(format!(
+                "Campaigns must run {}-{} days",
+                MarketingCampaign::MIN_DAYS,
+                MarketingCampaign::MAX_DAYS
+            ));
+        }
+
+        match target {
+            CampaignTarget::Store(store_id) if self.get_store_index_by_id(store_id).is_none() => {
+                return Err("Invalid store".to_string());
+            }
+            CampaignTarget::Category(category) if !category.is_retail() => {
+                return Err("Raw materials cannot be advertised".to_string());
+            }
+            _ => {}
+        }
+
+        let cost = MarketingCampaign::cost(channel, target, days);
+        if !self.player.spend(cost) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                cost, self.player.cash
+            ));
+        }
+        self.taxes.record_expense(cost);
+
+        let campaign = MarketingCampaign::new(0, channel, target, days);
+        Ok(self.player.add_campaign(campaign))
+    }
+
+    /// Returns a short description of a campaign (e.g. "Radio: Food")
+    pub fn describe_campaign(&self, campaign: &MarketingCampaign) -> String {
+        let target = match campaign.target {
+            CampaignTarget::Store(store_id) => self
+                .get_store_name_by_id(store_id)
+                .unwrap_or("Closed store")
+                .to_string(),
+            CampaignTarget::Category(category) => category.name().to_string(),
+            CampaignTarget::Brand => "Whole brand".to_string(),
+        };
+        format!("{}: {}", campaign.channel.name(), target)
+    }
+
+    // ==================== PROMOTION METHODS ====================
+
+    /// Starts a promotion in the current store
+    pub fn start_promotion(&mut self, kind: PromotionKind, days: u32) -> Result<(), String> {
+        match &kind {
+            PromotionKind::BuyOneGetOne { product_id } if self.get_product(*product_id).is_none() => {
+                return Err("Product not found".to_string());
+            }
+            PromotionKind::Bundle { product_ids, .. }
+                if product_ids.iter().any(|id| self.get_product(*id).is_none()) =>
+            {
+                return Err("Product not found".to_string());
+            }
+            _ => {}
+        }
+        self.current_store_mut().add_promotion(kind, days)
+    }
+
+    /// Enrolls the current store in a loyalty program
+    pub fn enroll_loyalty_program(&mut self) -> Result<f64, String> {
+        if self.current_store().loyalty_program.is_some() {
+            return Err("This store already has a loyalty program".to_string());
+        }
+        let cost = LoyaltyProgram::ENROLLMENT_COST;
+        if !self.player.spend(cost) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                cost, self.player.cash
+            ));
+        }
+        self.current_store_mut().enroll_loyalty_program()?;
+        self.taxes.record_expense(cost);
+        Ok(cost)
+    }
+
+    /// Returns a short description of a promotion (e.g. "Bread+Milk bundle 10% off")
+    pub fn describe_promotion(&self, promotion: &Promotion) -> String {
+        promotion.describe(|id| {
+            self.get_product(id)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| "Unknown".to_string())
+        })
+    }
+
     // ==================== FACTORY METHODS ====================
 
     /// Gets the current factory reference (if any)
@@ -137,19 +591,25 @@ impl GameState {
         Ok(())
     }
 
-    /// Buys a new factory
-    pub fn buy_new_factory(&mut self, name: &str) -> Result<(), String> {
-        const NEW_FACTORY_COST: f64 = 10000.0;
+    /// Buys a new factory in a region
+    pub fn buy_new_factory(&mut self, name: &str, region_id: u32) -> Result<(), String> {
+        let rent_multiplier = self.region(region_id).ok_or("Invalid region")?.rent_multiplier;
+        let cost = Self::NEW_FACTORY_COST * rent_multiplier;
 
-        if self.player.cash < NEW_FACTORY_COST {
+        if self.player.cash < cost {
             return Err(format!(
                 "Not enough cash! Need ${:.2}, have ${:.2}",
-                NEW_FACTORY_COST, self.player.cash
+                cost, self.player.cash
             ));
         }
 
-        self.player.spend(NEW_FACTORY_COST);
-        self.player.add_factory(name);
+        self.player.spend(cost);
+        self.player.add_factory(name, region_id);
+        self.taxes.add_asset(name, cost);
+        let price_level = self.market.price_level;
+        if let Some(factory) = self.player.factories.last_mut() {
+            factory.sign_lease(price_level * rent_multiplier);
+        }
 
         // Auto-select the new factory if it's the first one
         if self.current_factory.is_none() {
@@ -181,8 +641,7 @@ impl GameState {
         }
 
         let wholesale_price = self
-            .market
-            .get_wholesale_price(product_id)
+            .factory_wholesale_price(product_id)
             .ok_or("Wholesale price not found")?;
 
         let total_cost = wholesale_price * quantity as f64;
@@ -194,7 +653,9 @@ impl GameState {
             ));
         }
 
-        self.player.factories[factory_idx].add_raw_material(product_id, quantity);
+        let factory = &mut self.player.factories[factory_idx];
+        factory.add_raw_material(product_id, quantity, wholesale_price, factory.material_grade);
+        self.taxes.record_purchase(total_cost);
 
         Ok(total_cost)
     }
@@ -260,23 +721,257 @@ impl GameState {
             ));
         }
 
-        // Get product info for retail price
-        let product = self
-            .get_product(product_id)
-            .ok_or("Product not found")?;
+        if self.get_product(product_id).is_none() {
+            return Err("Product not found".to_string());
+        }
+
+        self.ship_to_store(factory_idx, store_idx, product_id, quantity)
+    }
+
+    /// Returns the days goods take from a factory to a store, from the route table
+    pub fn transit_days(&self, factory_idx: usize, store_idx: usize) -> u32 {
+        self.logistics.transit_days(
+            self.player.factories[factory_idx].region_id,
+            self.player.stores[store_idx].region_id,
+        )
+    }
 
-        let retail_price = Market::suggest_retail_price(product.base_price, 50.0);
+    /// Ships finished goods from a factory to a store at the factory's production cost,
+    /// pricing new store lines at the transfer markup and recording the internal transfer
+    /// The goods go on the road and reach the store after the route's transit days
+    fn ship_to_store(
+        &mut self,
+        factory_idx: usize,
+        store_idx: usize,
+        product_id: u32,
+        quantity: u32,
+    ) -> Result<u32, String> {
+        let transfer_price = self.player.factories[factory_idx].unit_cost(product_id);
+        let quality = self.player.factories[factory_idx].quality_of(product_id);
+        let base_retail = if transfer_price > 0.0 {
+            self.transfers.retail_price(transfer_price)
+        } else {
+            // No cost on record: fall back to the catalogue price
+            let base_price = self.get_product(product_id).map(|p| p.base_price).unwrap_or(0.0);
+            Market::suggest_retail_price(base_price, self.transfers.retail_markup)
+        };
+        // Better goods start on the shelf at a higher price
+        let retail_price = base_retail * QualityTier::from_score(quality).price_multiplier();
 
         // Take from factory
         let actual_quantity = self.player.factories[factory_idx]
             .take_finished_goods(product_id, quantity)?;
 
-        // Add to store
-        self.player.stores[store_idx].add_inventory(product_id, actual_quantity, retail_price);
+        // Put on a truck (or courier) to the store
+        let transit_days = self.transit_days(factory_idx, store_idx);
+        let shipping = self.logistics.dispatch(
+            self.player.factories[factory_idx].id,
+            self.player.stores[store_idx].id,
+            Cargo::new(product_id, actual_quantity, retail_price, quality),
+            transit_days,
+            self.day,
+        );
+        self.player.cash -= shipping;
+        self.shipping_costs += shipping;
+        self.taxes.record_expense(shipping);
+
+        self.transfers.record(InternalTransfer {
+            day: self.day,
+            factory_id: self.player.factories[factory_idx].id,
+            store_id: self.player.stores[store_idx].id,
+            product_id,
+            quantity: actual_quantity,
+            transfer_price,
+        });
 
         Ok(actual_quantity)
     }
 
+    /// Sets the markup over transfer price used for new store lines
+    pub fn set_transfer_markup(&mut self, markup: f64) -> Result<(), String> {
+        if markup < 0.0 {
+            return Err("Markup can't be negative".to_string());
+        }
+        self.transfers.retail_markup = markup;
+        Ok(())
+    }
+
+    /// Moves components from the current factory to another factory
+    /// (so one plant can make parts and another assemble them)
+    pub fn transfer_components(
+        &mut self,
+        product_id: u32,
+        quantity: u32,
+        to_factory_idx: usize,
+    ) -> Result<u32, String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        if to_factory_idx >= self.player.factories.len() {
+            return Err("Invalid factory index".to_string());
+        }
+        if to_factory_idx == factory_idx {
+            return Err("Components are already at this factory".to_string());
+        }
+
+        let unit_cost = self.player.factories[factory_idx].unit_cost(product_id);
+        let quality = self.player.factories[factory_idx].quality_of(product_id);
+        let actual_quantity = self.player.factories[factory_idx]
+            .take_components(product_id, quantity)?;
+        self.player.factories[to_factory_idx].add_components(product_id, actual_quantity, unit_cost, quality);
+
+        Ok(actual_quantity)
+    }
+
+    /// Sets up a standing order at the current factory
+    pub fn add_standing_order(&mut self, recipe_id: u32, target: OrderTarget) -> Result<(), String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        if self.get_recipe(recipe_id).is_none() {
+            return Err("Recipe not found".to_string());
+        }
+
+        self.player.factories[factory_idx].add_standing_order(StandingOrder::new(recipe_id, target))
+    }
+
+    /// Cancels a standing order at the current factory
+    pub fn cancel_standing_order(&mut self, index: usize) -> Result<StandingOrder, String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        self.player.factories[factory_idx].cancel_standing_order(index)
+    }
+
+    /// Adds a raw material procurement rule at the current factory
+    pub fn add_procurement_rule(&mut self, rule: ProcurementRule) -> Result<(), String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        if let ProcurementRule::KeepStocked { product_id, .. } = rule {
+            let product = self.get_product(product_id).ok_or("Product not found")?;
+            if !product.product_type.is_raw_material() {
+                return Err("This product is not a raw material".to_string());
+            }
+        }
+
+        self.player.factories[factory_idx].procurement.add_rule(rule)
+    }
+
+    /// Removes a procurement rule at the current factory
+    pub fn remove_procurement_rule(&mut self, index: usize) -> Result<ProcurementRule, String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        self.player.factories[factory_idx].procurement.remove_rule(index)
+    }
+
+    /// Sets the daily raw material spending limit at the current factory
+    pub fn set_procurement_budget(&mut self, budget: f64) -> Result<(), String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        self.player.factories[factory_idx].procurement.daily_budget = budget.max(0.0);
+        Ok(())
+    }
+
+    /// Plans the materials, jobs and factory time needed to make the targets
+    /// within `deadline` days, without buying or starting anything
+    pub fn plan_production(&self, targets: &[ProductionTarget], deadline: u32) -> ProductionPlan {
+        let mut plan = crate::planning::plan_production(
+            targets,
+            deadline,
+            &self.player.factories,
+            &self.recipes,
+            |factory_idx, product_id| self.factory_material_price(factory_idx, product_id),
+        );
+        let cost = plan.purchase_cost();
+        if cost > self.player.cash {
+            plan.shortfalls.push(Shortfall::Cash(cost - self.player.cash));
+        }
+        plan
+    }
+
+    /// Buys every material a plan still needs, delivered to the factories that use them
+    /// Returns the amount spent
+    pub fn buy_planned_materials(&mut self, plan: &ProductionPlan) -> Result<f64, String> {
+        let cost = plan.purchase_cost();
+        if !self.player.spend(cost) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                cost, self.player.cash
+            ));
+        }
+
+        for requirement in plan.requirements.iter().filter(|r| r.to_buy > 0) {
+            if let Some(factory) = self.player.factories.get_mut(requirement.factory_idx) {
+                let grade = factory.material_grade;
+                factory.add_raw_material(requirement.product_id, requirement.to_buy, requirement.unit_cost, grade);
+            }
+        }
+        self.taxes.record_purchase(cost);
+
+        Ok(cost)
+    }
+
+    /// Buys the raw materials a factory's procurement rules call for, within its daily budget
+    /// Returns None if the factory has no rules or nothing needed buying
+    fn run_procurement(&mut self, factory_idx: usize) -> Option<ProcurementReport> {
+        let factory = &self.player.factories[factory_idx];
+        if factory.procurement.is_empty() {
+            return None;
+        }
+
+        let mut report = ProcurementReport::new(&factory.name);
+        let targets = factory.procurement.targets(factory, &self.recipes);
+        let mut budget = factory.procurement.daily_budget;
+
+        for (product_id, target) in targets {
+            let product = match self.get_product(product_id) {
+                Some(p) if p.product_type.is_raw_material() => p.clone(),
+                // Components are made in-house, not bought
+                _ => continue,
+            };
+            let wanted = target.saturating_sub(self.player.factories[factory_idx].get_raw_material(product_id));
+            if wanted == 0 {
+                continue;
+            }
+            let wholesale = match self.factory_material_price(factory_idx, product_id) {
+                Some(price) => price,
+                None => continue,
+            };
+
+            let spendable = budget.min(self.player.cash.max(0.0));
+            let affordable = (spendable / wholesale) as u32;
+            let quantity = wanted.min(affordable);
+            if quantity == 0 {
+                report.skipped.push(product.name.clone());
+                continue;
+            }
+
+            let cost = wholesale * quantity as f64;
+            if self.player.spend(cost) {
+                self.taxes.record_purchase(cost);
+                budget -= cost;
+                let factory = &mut self.player.factories[factory_idx];
+                factory.add_raw_material(product_id, quantity, wholesale, factory.material_grade);
+                report.purchased.push((product.name.clone(), quantity, cost));
+            }
+        }
+
+        if report.is_empty() {
+            None
+        } else {
+            Some(report)
+        }
+    }
+
     // ==================== SUPPLY CHAIN METHODS ====================
 
     /// Connects the current factory to a store
@@ -309,6 +1004,91 @@ impl GameState {
         Ok(())
     }
 
+    /// Sets the distribution policy for the current factory
+    pub fn set_distribution_policy(&mut self, policy: DistributionPolicy) -> Result<(), String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        self.player.factories[factory_idx].set_distribution(policy)
+    }
+
+    /// Sends a factory's finished goods to its connected stores under its distribution policy,
+    /// recording each transfer (factory_name, store_name, product_name, quantity) and
+    /// the units of today's output left at the factory (factory_name, product_name, quantity)
+    /// Goods held back on earlier days aren't reported again.
+    fn distribute_finished_goods(
+        &mut self,
+        factory_idx: usize,
+        produced: &HashMap<u32, u32>,
+        transfers: &mut Vec<(String, String, String, u32)>,
+        held: &mut Vec<(String, String, u32)>,
+    ) {
+        let factory_name = self.player.factories[factory_idx].name.clone();
+        let store_indices: Vec<usize> = self.player.factories[factory_idx]
+            .connected_stores
+            .iter()
+            .filter_map(|id| self.get_store_index_by_id(*id))
+            .collect();
+        if store_indices.is_empty() {
+            return;
+        }
+
+        let mut product_ids: Vec<u32> = self.player.factories[factory_idx]
+            .finished_goods
+            .keys()
+            .copied()
+            .collect();
+        product_ids.sort();
+
+        for product_id in product_ids {
+            let quantity = self.player.factories[factory_idx].get_finished_good(product_id);
+            if quantity == 0 {
+                continue;
+            }
+            let product_name = match self.get_product(product_id) {
+                Some(product) => product.name.clone(),
+                None => continue,
+            };
+
+            let demand: Vec<StoreDemand> = store_indices
+                .iter()
+                .map(|&idx| {
+                    let store = &self.player.stores[idx];
+                    StoreDemand {
+                        store_id: store.id,
+                        on_hand: store.get_quantity(product_id)
+                            + self.logistics.in_transit_to(store.id, product_id),
+                        demand: store.last_demand(product_id),
+                    }
+                })
+                .collect();
+            let factory = &mut self.player.factories[factory_idx];
+            let allocation = allocate(&factory.distribution, quantity, &demand, &mut factory.distribution_cursor);
+
+            let mut sent = 0;
+            for (store_id, qty) in allocation {
+                let store_idx = match self.get_store_index_by_id(store_id) {
+                    Some(idx) => idx,
+                    None => continue,
+                };
+                if let Ok(transferred) = self.ship_to_store(factory_idx, store_idx, product_id, qty) {
+                    sent += transferred;
+                    transfers.push((
+                        factory_name.clone(),
+                        self.player.stores[store_idx].name.clone(),
+                        product_name.clone(),
+                        transferred,
+                    ));
+                }
+            }
+            let newly_held = (quantity - sent).min(produced.get(&product_id).copied().unwrap_or(0));
+            if newly_held > 0 {
+                held.push((factory_name.clone(), product_name, newly_held));
+            }
+        }
+    }
+
     /// Toggles auto-transfer for the current factory
     pub fn toggle_factory_auto_transfer(&mut self) -> Result<bool, String> {
         let factory_idx = self
@@ -349,8 +1129,7 @@ impl GameState {
         }
 
         let wholesale_price = self
-            .market
-            .get_wholesale_price(product_id)
+            .store_wholesale_price(product_id)
             .ok_or("Wholesale price not found")?;
 
         let total_cost = wholesale_price * quantity as f64;
@@ -361,26 +1140,138 @@ impl GameState {
                 total_cost, self.player.cash
             ));
         }
-
-        // Add to inventory with default markup of 50%
-        let suggested_retail = Market::suggest_retail_price(wholesale_price, 50.0);
-        self.current_store_mut()
-            .add_inventory(product_id, quantity, suggested_retail);
-
-        Ok(total_cost)
+
+        self.taxes.record_purchase(total_cost);
+
+        // Add to inventory with default markup of 50% (or the legal cap)
+        let suggested_retail = self.capped_price(product_id, Market::suggest_retail_price(wholesale_price, 50.0));
+        self.current_store_mut()
+            .add_inventory(product_id, quantity, suggested_retail);
+
+        Ok(total_cost)
+    }
+
+    /// Lowers every shelf price above its legal cap to the cap, so new price controls
+    /// don't fine prices set before they existed
+    /// Returns the number of prices lowered
+    fn clamp_prices_to_caps(&mut self) -> u32 {
+        let mut lowered = 0;
+        for store_idx in 0..self.player.stores.len() {
+            let product_ids: Vec<u32> = self.player.stores[store_idx].inventory.keys().copied().collect();
+            for product_id in product_ids {
+                let cap = match self.get_product(product_id).and_then(|p| self.price_cap(p)) {
+                    Some(cap) => cap,
+                    None => continue,
+                };
+                let store = &mut self.player.stores[store_idx];
+                if store.get_price(product_id).is_some_and(|price| price > cap) {
+                    store.set_price(product_id, cap);
+                    lowered += 1;
+                }
+            }
+        }
+        lowered
+    }
+
+    /// Sets the retail price for a product in the current store
+    pub fn set_retail_price(&mut self, product_id: u32, price: f64) -> Result<(), String> {
+        if price <= 0.0 {
+            return Err("Price must be positive".to_string());
+        }
+        if let Some(cap) = self.get_product(product_id).and_then(|p| self.price_cap(p))
+            && price > cap
+        {
+            return Err(format!("Price controls: this product is capped at ${:.2}", cap));
+        }
+
+        if self.current_store_mut().set_price(product_id, price) {
+            Ok(())
+        } else {
+            Err("Product not in inventory".to_string())
+        }
+    }
+
+    // ==================== QUALITY METHODS ====================
+
+    /// Upgrades the current factory's equipment by one level
+    /// Returns the new level
+    pub fn upgrade_factory_equipment(&mut self) -> Result<u32, String> {
+        let factory_idx = self
+            .current_factory
+            .ok_or("No factory selected")?;
+
+        let factory = &self.player.factories[factory_idx];
+        if factory.equipment_level >= quality::MAX_EQUIPMENT_LEVEL {
+            return Err("Equipment is already at the highest level".to_string());
+        }
+        let cost = quality::EQUIPMENT_UPGRADE_COST * (factory.equipment_level + 1) as f64;
+        if !self.player.spend(cost) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                cost, self.player.cash
+            ));
+        }
+
+        let factory = &mut self.player.factories[factory_idx];
+        let level = factory.upgrade_equipment()?;
+        let name = format!("{} equipment (level {})", factory.name, level);
+        self.taxes.add_asset(&name, cost);
+        Ok(level)
+    }
+
+    /// Sets the grade of raw materials the current factory buys
+    pub fn set_material_grade(&mut self, grade: MaterialGrade) -> Result<(), String> {
+        let factory = self
+            .current_factory_mut()
+            .ok_or("No factory selected")?;
+        factory.material_grade = grade;
+        Ok(())
+    }
+
+    /// Sets what the current factory does with defective units
+    pub fn set_defect_handling(&mut self, handling: DefectHandling) -> Result<(), String> {
+        let factory = self
+            .current_factory_mut()
+            .ok_or("No factory selected")?;
+        factory.defect_handling = handling;
+        Ok(())
+    }
+
+    // ==================== LOGISTICS METHODS ====================
+
+    /// Buys a truck outright
+    pub fn buy_truck(&mut self) -> Result<u32, String> {
+        if !self.player.spend(Truck::PURCHASE_PRICE) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                Truck::PURCHASE_PRICE, self.player.cash
+            ));
+        }
+        let id = self.logistics.add_truck(TruckOwnership::Owned);
+        self.taxes.add_asset(&format!("Truck #{}", id), Truck::PURCHASE_PRICE);
+        Ok(id)
     }
 
-    /// Sets the retail price for a product in the current store
-    pub fn set_retail_price(&mut self, product_id: u32, price: f64) -> Result<(), String> {
-        if price <= 0.0 {
-            return Err("Price must be positive".to_string());
-        }
+    /// Leases a truck (paid daily)
+    pub fn lease_truck(&mut self) -> u32 {
+        self.logistics.add_truck(TruckOwnership::Leased)
+    }
 
-        if self.current_store_mut().set_price(product_id, price) {
-            Ok(())
-        } else {
-            Err("Product not in inventory".to_string())
-        }
+    /// Returns a leased truck or sells an owned one for half its price,
+    /// taking it off the tax books
+    /// Returns the sale proceeds (0 for a leased truck)
+    pub fn retire_truck(&mut self, truck_id: u32) -> Result<f64, String> {
+        let truck = self.logistics.remove_truck(truck_id)?;
+        let proceeds = match truck.ownership {
+            TruckOwnership::Owned => {
+                let proceeds = Truck::PURCHASE_PRICE * 0.5;
+                self.taxes.dispose_asset(&truck.name, proceeds);
+                proceeds
+            }
+            TruckOwnership::Leased => 0.0,
+        };
+        self.player.earn(proceeds);
+        Ok(proceeds)
     }
 
     // ==================== LOAN METHODS ====================
@@ -470,23 +1361,409 @@ impl GameState {
         self.market.get_loan_rate(loan_type)
     }
 
+    /// Returns each in-stock product's effective shelf price over its normal price
+    /// (used to work out substitute and complement effects)
+    fn shelf_price_ratios(&self, store_idx: usize, promotions: &[Promotion]) -> HashMap<u32, f64> {
+        self.player.stores[store_idx]
+            .inventory
+            .values()
+            .filter(|item| item.quantity > 0)
+            .filter_map(|item| {
+                let product = self.get_product(item.product_id)?;
+                let price_paid = item.retail_price
+                    * Promotion::best_for(product, promotions)
+                        .map(|p| p.price_multiplier())
+                        .unwrap_or(1.0);
+                Some((item.product_id, price_paid / self.market.reference_price(product)))
+            })
+            .collect()
+    }
+
+    /// Returns how strongly shoppers are drawn to a product beyond its base demand:
+    /// category campaigns draw extra shoppers to that aisle, and substitutes and
+    /// complements on the shelf shift their choice
+    fn product_interest(&self, product: &Product, price_paid: f64, shelf_ratios: &HashMap<u32, f64>) -> f64 {
+        let category_multiplier = self.player.category_marketing_multiplier(product.category);
+        let relationship_multiplier = self.market.relationship_multiplier(
+            product.id,
+            price_paid / self.market.reference_price(product),
+            shelf_ratios,
+        );
+        category_multiplier * relationship_multiplier
+    }
+
+    // ==================== PRICE FORECASTING ====================
+
+    /// Builds a forecast scenario for a product in the current store: today's shoppers,
+    /// its regional wholesale cost and the stock on hand
+    fn forecast_scenario(&self, product: &Product, days: u32) -> Result<ForecastScenario, String> {
+        let store_idx = self.current_store;
+        let store = &self.player.stores[store_idx];
+        store.get_price(product.id).ok_or("Product not in inventory")?;
+        let unit_cost = self
+            .store_wholesale_price(product.id)
+            .ok_or("Wholesale price not found")?;
+
+        Ok(ForecastScenario {
+            unit_cost,
+            customer_count: self.store_customer_count(store_idx) as f64,
+            stock: store.get_quantity(product.id),
+            days: days.max(1),
+            price_cap: self.price_cap(product),
+            quality_value: store.quality_tier(product.id).price_multiplier(),
+        })
+    }
+
+    /// Forecasts sales of a product in the current store over `days` at each price
+    pub fn forecast_prices(&self, product_id: u32, days: u32, prices: &[f64]) -> Result<Vec<SalesForecast>, String> {
+        let product = self.get_product(product_id).ok_or("Product not found")?;
+        let scenario = self.forecast_scenario(product, days)?;
+        let promotions = self.current_store().active_promotions();
+        let shelf_ratios = self.shelf_price_ratios(self.current_store, &promotions);
+        Ok(self.market.forecast(product, &promotions, &scenario, prices, |price_paid| {
+            self.product_interest(product, price_paid, &shelf_ratios)
+        }))
+    }
+
+    /// Returns forecasts across a spread of markups from cost to triple the wholesale price
+    /// (or the legal price cap, if lower)
+    pub fn forecast_markups(&self, product_id: u32, days: u32) -> Result<Vec<SalesForecast>, String> {
+        let wholesale = self
+            .store_wholesale_price(product_id)
+            .ok_or("Wholesale price not found")?;
+        let high = self.capped_price(product_id, wholesale * 3.0);
+        self.forecast_prices(product_id, days, &price_range(wholesale.min(high), high, 9))
+    }
+
+    /// Suggests the retail price that maximizes expected profit from the stock on hand
+    /// over the next `days`
+    pub fn suggest_optimal_price(&self, product_id: u32, days: u32) -> Result<SalesForecast, String> {
+        let product = self.get_product(product_id).ok_or("Product not found")?;
+        let scenario = self.forecast_scenario(product, days)?;
+        if scenario.stock == 0 {
+            return Err("No stock to forecast".to_string());
+        }
+        let promotions = self.current_store().active_promotions();
+        let shelf_ratios = self.shelf_price_ratios(self.current_store, &promotions);
+        self.market
+            .optimal_price(product, &promotions, &scenario, |price_paid| {
+                self.product_interest(product, price_paid, &shelf_ratios)
+            })
+            .ok_or_else(|| "No profitable price found".to_string())
+    }
+
+    // ==================== FOREIGN TRADE ====================
+
+    /// Prices an import order at today's exchange rate
+    pub fn quote_import(&self, supplier_id: u32, product_id: u32, quantity: u32) -> Result<ImportQuote, String> {
+        self.trade.quote_import(supplier_id, product_id, quantity)
+    }
+
+    /// Orders goods from an overseas supplier, paying goods and tariff up front
+    /// Raw materials ship to the current factory, retail goods to the current store
+    pub fn place_import_order(&mut self, supplier_id: u32, product_id: u32, quantity: u32) -> Result<ImportQuote, String> {
+        if quantity == 0 {
+            return Err("Quantity must be greater than 0".to_string());
+        }
+        let product = self.get_product(product_id).ok_or("Product not found")?;
+        let destination = if product.product_type.is_raw_material() {
+            let factory = self
+                .current_factory()
+                .ok_or("Raw materials must be shipped to a factory. Buy or select a factory first!")?;
+            Destination::Factory(factory.id)
+        } else {
+            Destination::Store(self.current_store().id)
+        };
+
+        let quote = self.trade.quote_import(supplier_id, product_id, quantity)?;
+        if !self.player.spend(quote.total()) {
+            return Err(format!(
+                "Not enough cash! Need ${:.2}, have ${:.2}",
+                quote.total(),
+                self.player.cash
+            ));
+        }
+        self.taxes.record_purchase(quote.total());
+
+        let supplier_name = self.trade.supplier(supplier_id).map(|s| s.name.clone()).unwrap_or_default();
+        let unit_cost = quote.total() / quantity as f64;
+        self.trade.ship(
+            &supplier_name,
+            product_id,
+            quantity,
+            quote.shipping_days,
+            ShipmentKind::Import { destination, unit_cost },
+        );
+        Ok(quote)
+    }
+
+    /// Ships finished goods from the current factory to an overseas buyer
+    /// The buyer pays in its currency when the goods arrive
+    /// Returns the dollar value at today's exchange rate
+    pub fn export_goods(&mut self, buyer_id: u32, product_id: u32, quantity: u32) -> Result<f64, String> {
+        if quantity == 0 {
+            return Err("Quantity must be greater than 0".to_string());
+        }
+        let factory_idx = self.current_factory.ok_or("No factory selected")?;
+        let (payment, value) = self.trade.quote_export(buyer_id, product_id, quantity)?;
+        let buyer = self.trade.buyer(buyer_id).ok_or("Buyer not found")?;
+        let (buyer_name, currency, shipping_days) = (buyer.name.clone(), buyer.currency, buyer.shipping_days);
+
+        let available = self.player.factories[factory_idx].get_finished_good(product_id);
+        if available < quantity {
+            return Err(format!("Only {} in stock at the factory", available));
+        }
+        self.player.factories[factory_idx].take_finished_goods(product_id, quantity)?;
+
+        self.trade.ship(
+            &buyer_name,
+            product_id,
+            quantity,
+            shipping_days,
+            ShipmentKind::Export { currency, payment },
+        );
+        Ok(value)
+    }
+
+    // ==================== TAX METHODS ====================
+
+    /// Sets the sales, payroll and corporate tax rates (each a fraction from 0 up to 1)
+    /// New rates apply from the next day; this quarter's books are kept
+    pub fn set_tax_policy(&mut self, policy: TaxPolicy) -> Result<(), String> {
+        for (name, rate) in [
+            ("Sales", policy.sales_tax_rate),
+            ("Payroll", policy.payroll_tax_rate),
+            ("Corporate", policy.corporate_tax_rate),
+        ] {
+            if !(0.0..1.0).contains(&rate) {
+                return Err(format!("{} tax rate must be between 0% and 100%", name));
+            }
+        }
+        self.taxes.policy = policy;
+        Ok(())
+    }
+
+    // ==================== CUSTOMER SIMULATION ====================
+
+    /// Switches between formula-based and agent-based demand
+    pub fn set_demand_model(&mut self, model: DemandModel) {
+        self.demand_model = model;
+    }
+
+    /// Runs today's individual shoppers through a store
+    /// Products the store doesn't carry are still on shopping lists (priced at the default markup)
+    fn simulate_store_shoppers(
+        &self,
+        store_idx: usize,
+        customer_count: u32,
+        promotions: &[Promotion],
+        shelf_ratios: &HashMap<u32, f64>,
+    ) -> ShoppingDay {
+        let store = &self.player.stores[store_idx];
+        let shelf: Vec<ShelfOffer> = self
+            .products
+            .iter()
+            .filter(|p| p.product_type.can_sell_retail())
+            .map(|product| {
+                let (price, available) = match store.inventory.get(&product.id) {
+                    Some(item) => {
+                        let price = item.retail_price
+                            * Promotion::best_for(product, promotions)
+                                .map(|p| p.price_multiplier())
+                                .unwrap_or(1.0);
+                        (price, item.quantity)
+                    }
+                    None => {
+                        let wholesale = self
+                            .regional_wholesale_price(store.region_id, product.id)
+                            .unwrap_or(product.base_price);
+                        (Market::suggest_retail_price(wholesale, 50.0), 0)
+                    }
+                };
+                // Shoppers will pay more for better goods
+                let quality_value = store
+                    .inventory
+                    .get(&product.id)
+                    .map(|item| item.tier().price_multiplier())
+                    .unwrap_or(1.0);
+                ShelfOffer {
+                    product_id: product.id,
+                    category: product.category,
+                    price,
+                    reference_price: self.market.reference_price(product) * quality_value,
+                    available,
+                    want_chance: self.market.base_demand(product, promotions)
+                        * self.product_interest(product, price, shelf_ratios),
+                }
+            })
+            .collect();
+
+        // Same seed, same day, same store: same shoppers
+        let seed = self
+            .simulation_seed
+            .wrapping_add(self.day as u64 * 1_000_003)
+            .wrapping_add(store.id as u64 * 7_919);
+        let region = self.store_region(store_idx);
+        let budget_scale = self.market.price_level * self.market.economic_state.sales_multiplier();
+        // Local competitors sell at a typical 50% markup, adjusted by their strategy
+        let rival_price_ratio = 1.5
+            * self.market.economic_state.price_multiplier()
+            * region.price_multiplier
+            * region.market.lowest_price_multiplier();
+
+        simulate_shoppers(seed, customer_count, &shelf, budget_scale, rival_price_ratio)
+    }
+
+    // ==================== NEWS ====================
+
+    /// Writes today's headlines from the leading indicator, macro event rumors and competitor moves
+    /// Rumors don't always make the paper, and now and then a false alarm does
+    fn publish_news(&mut self, events_started: &[MacroEvent], competitor_events: &[String]) -> Vec<(String, String)> {
+        const RUMOR_CHANCE: f64 = 0.75;
+        const FALSE_RUMOR_CHANCE: f64 = 0.04;
+        const EXPANSION_LEAK_CHANCE: f64 = 0.5;
+
+        let day = self.day;
+        let mut stories: Vec<(NewsCategory, String)> = Vec::new();
+
+        // Economy: a noisy leading indicator of the trend
+        let reading = self.market.leading_indicator(day);
+        let pick = self.market.salted_random(5);
+        stories.push((NewsCategory::Economy, Headline::economy(reading, pick).to_string()));
+
+        // Macro events: breaking news and rumors of what's coming
+        for event in events_started {
+            stories.push((NewsCategory::Events, format!("{}: {}", event.name, event.description)));
+        }
+        for (i, event) in self.market.upcoming_events.iter().enumerate() {
+            if self.market.salted_random(10 + i as u64) < RUMOR_CHANCE {
+                stories.push((NewsCategory::Events, format!("Rumor: {}", event.rumor)));
+            }
+        }
+        if self.market.salted_random(6) < FALSE_RUMOR_CHANCE {
+            let library = MacroEvent::library();
+            let pick = (self.market.salted_random(7) * library.len() as f64) as usize;
+            let decoy = &library[pick.min(library.len() - 1)];
+            let known = self
+                .market
+                .events
+                .iter()
+                .chain(self.market.upcoming_events.iter())
+                .any(|e| e.name == decoy.name);
+            if !known {
+                stories.push((NewsCategory::Events, format!("Rumor: {}", decoy.rumor)));
+            }
+        }
+
+        // Business: competitor moves and leaks about their plans
+        for event in competitor_events {
+            stories.push((NewsCategory::Business, event.clone()));
+        }
+        let competitors = self
+            .regions
+            .iter()
+            .flat_map(|region| region.market.competitors.iter().map(move |c| (region, c)));
+        for (i, (region, competitor)) in competitors.enumerate() {
+            if competitor.is_planning_expansion() && self.market.salted_random(20 + i as u64) < EXPANSION_LEAK_CHANCE {
+                stories.push((
+                    NewsCategory::Business,
+                    format!("{} said to be scouting new locations in {}", competitor.name, region.name),
+                ));
+            }
+        }
+
+        for (category, text) in &stories {
+            self.news.publish(day, *category, text);
+        }
+        stories
+            .into_iter()
+            .map(|(category, text)| (category.name().to_string(), text))
+            .collect()
+    }
+
     /// Advances to the next day and simulates sales for ALL stores
     pub fn advance_day(&mut self) -> DayResult {
         // Update economy and get any change message
         let economic_change = self.market.advance_day(self.day);
         let economic_state = self.market.economic_state;
 
-        // Calculate player's average markup for market share calculation
-        let player_avg_markup = self.calculate_average_markup();
-        let player_store_count = self.player.stores.len() as u32;
+        // Macro events run alongside the economic state
+        let (started, ended) = self.market.advance_events();
+        let events_started: Vec<(String, String, u32)> = started
+            .iter()
+            .map(|e| (e.name.to_string(), e.description.to_string(), e.duration))
+            .collect();
+        let events_ended: Vec<String> = ended.iter().map(|e| e.name.to_string()).collect();
+        let active_events: Vec<(String, u32)> = self
+            .market
+            .events
+            .iter()
+            .map(|e| (e.name.to_string(), e.days_remaining))
+            .collect();
+
+        // Update market shares region by region, based on player and local competitor positions
+        let player_marketing_multiplier = self.player.brand_marketing_multiplier();
+        let mut competitor_events = Vec::new();
+        for region_idx in 0..self.regions.len() {
+            let region_id = self.regions[region_idx].id;
+            let player_avg_markup = self.calculate_average_markup(region_id);
+            let player_store_count =
+                self.player.stores.iter().filter(|s| s.region_id == region_id).count() as u32;
+
+            let region = &mut self.regions[region_idx];
+            region.market.update_market_size(economic_state, player_store_count);
+            region.market.calculate_market_shares(
+                player_store_count,
+                player_avg_markup,
+                player_marketing_multiplier,
+            );
+
+            // Process local competitor actions
+            let local_economy = economic_state.sales_multiplier() * region.sales_multiplier;
+            for event in region.market.advance_day(local_economy) {
+                competitor_events.push(format!("[{}] {}", region.name, event));
+            }
+        }
+        let player_market_share = self.overall_market_share();
+        let regional_shares: Vec<(String, f64, u32)> = self
+            .regions
+            .iter()
+            .map(|r| (r.name.clone(), r.market.player_market_share, r.market.total_market_size))
+            .collect();
+
+        // Today's paper
+        let headlines = self.publish_news(&started, &competitor_events);
+
+        // Deliveries reach the stores before the doors open
+        let mut deliveries_arrived = Vec::new();
+        for delivery in self.logistics.advance() {
+            let factory_name = self
+                .player
+                .factories
+                .iter()
+                .find(|f| f.id == delivery.factory_id)
+                .map(|f| f.name.clone())
+                .unwrap_or_default();
+            let prices: Vec<f64> = delivery
+                .cargo
+                .iter()
+                .map(|cargo| self.capped_price(cargo.product_id, cargo.retail_price))
+                .collect();
+            if let Some(store) = self.player.stores.iter_mut().find(|s| s.id == delivery.store_id) {
+                for (cargo, price) in delivery.cargo.iter().zip(prices) {
+                    store.add_inventory_with_quality(cargo.product_id, cargo.quantity, price, cargo.quality);
+                }
+                deliveries_arrived.push((factory_name, store.name.clone(), delivery.units()));
+            }
+        }
 
-        // Update market shares based on player and competitor positions
-        self.competitive_market.calculate_market_shares(player_store_count, player_avg_markup);
-        let player_market_share = self.competitive_market.player_market_share;
-        let customer_multiplier = self.competitive_market.player_customer_multiplier();
+        // Store managers make their decisions before the doors open
+        let manager_reports: Vec<ManagerReport> = (0..self.player.stores.len())
+            .filter_map(|store_idx| self.run_store_manager(store_idx))
+            .collect();
 
-        // Process competitor actions
-        let competitor_events = self.competitive_market.advance_day(economic_state.sales_multiplier());
+        // Caps follow the price level, so yesterday's legal price can be over today's cap
+        self.clamp_prices_to_caps();
 
         let mut total_revenue = 0.0;
         let mut total_items_sold = 0;
@@ -495,6 +1772,15 @@ impl GameState {
         let mut expenses_by_store = Vec::new();
         let mut expenses_by_factory = Vec::new();
         let mut production_completed = Vec::new();
+        let mut promotion_sales = Vec::new();
+        let mut promotion_discounts = 0.0;
+        let mut promotions_ended = Vec::new();
+        let mut loyalty_rewards = 0.0;
+        let mut reputation_changes = Vec::new();
+        let mut lost_sales = Vec::new();
+        let mut total_lost_revenue = 0.0;
+        let mut shopper_stats = Vec::new();
+        let mut fines: Vec<(String, f64)> = Vec::new();
 
         // Loan-related tracking
         let mut loan_interest_accrued = 0.0;
@@ -509,15 +1795,36 @@ impl GameState {
             // Calculate expenses for this store
             let store = &self.player.stores[store_idx];
             let rent = store.daily_rent;
-            let salaries: f64 = store.employees.iter().map(|e| e.salary).sum();
+            let salaries = store.total_salaries();
             let store_name = store.name.clone();
             let store_expenses = rent + salaries;
             total_expenses += store_expenses;
-            expenses_by_store.push((store_name, rent, salaries));
-
-            // Get customer count with employee bonus and market share multiplier
-            let base_customers = self.player.stores[store_idx].effective_customers();
-            let customer_count = (base_customers as f64 * customer_multiplier) as u32;
+            expenses_by_store.push((store_name.clone(), rent, salaries));
+
+            // Get customer count with employee bonus, market share and advertising multipliers
+            let customer_count = self.store_customer_count(store_idx);
+            let promotions = self.player.stores[store_idx].active_promotions();
+            let mut store_revenue = 0.0;
+            let mut service = ServiceReport {
+                products_carried: self.player.stores[store_idx].inventory.len() as u32,
+                customers: customer_count,
+                staff: self.player.stores[store_idx].employees.len() as u32 + 1,
+                ..Default::default()
+            };
+            let mut price_ratio_total = 0.0;
+            let mut priced_products = 0;
+            let mut demand_log = HashMap::new();
+            // Units sold under each bundle: (promotion_idx, product_id) -> (units, shelf price, sales entry)
+            let mut bundle_units: HashMap<(usize, u32), (u32, f64, usize)> = HashMap::new();
+            let shelf_ratios = self.shelf_price_ratios(store_idx, &promotions);
+            let shopping_day = match self.demand_model {
+                DemandModel::Agents => {
+                    let day = self.simulate_store_shoppers(store_idx, customer_count, &promotions, &shelf_ratios);
+                    shopper_stats.push((store_name.clone(), day.clone()));
+                    Some(day)
+                }
+                DemandModel::Formula => None,
+            };
 
             // Clone inventory keys to avoid borrow issues
             let product_ids: Vec<u32> = self.player.stores[store_idx]
@@ -534,34 +1841,197 @@ impl GameState {
                     if let Some(item) = store.inventory.get(&product_id) {
                         let retail_price = item.retail_price;
                         let available = item.quantity;
+                        let tier = item.tier();
+                        // Customers judge the price against what the goods are worth
+                        let quality_value = tier.price_multiplier();
+
+                        if available == 0 {
+                            service.products_stocked_out += 1;
+                        }
+
+                        let price_paid = retail_price
+                            * Promotion::best_for(&product, &promotions)
+                                .map(|p| p.price_multiplier())
+                                .unwrap_or(1.0);
+
+                        // Record what customers wanted, whether or not we had it
+                        let (demand, sales) = match &shopping_day {
+                            Some(day) => {
+                                let record = day.records.get(&product_id).copied().unwrap_or_default();
+                                (record.demand, record.sold.min(available))
+                            }
+                            None => {
+                                let interest = self.product_interest(&product, price_paid, &shelf_ratios);
+                                let product_customers = (customer_count as f64 * interest) as u32;
+                                let demand = self.market.calculate_demand(
+                                    &product,
+                                    retail_price / quality_value,
+                                    product_customers,
+                                    &promotions,
+                                );
+                                (demand, demand.min(available))
+                            }
+                        };
+                        demand_log.insert(product_id, DemandRecord::new(demand, sales));
+                        if demand > sales {
+                            let lost = demand - sales;
+                            let lost_revenue = lost as f64 * price_paid;
+                            total_lost_revenue += lost_revenue;
+                            lost_sales.push((store_name.clone(), product.name.clone(), lost, lost_revenue));
+                        }
 
                         if available > 0 {
-                            let sales = self.market.calculate_sales(
-                                &product,
-                                retail_price,
-                                available,
-                                customer_count,
-                            );
-
-                            if sales > 0 {
-                                if let Some(revenue) =
+                            // Track what customers saw on the shelf
+                            price_ratio_total +=
+                                price_paid / (self.market.reference_price(&product) * quality_value);
+                            priced_products += 1;
+                            if sales >= available {
+                                service.products_stocked_out += 1;
+                            }
+
+                            if sales > 0
+                                && let Some(shelf_revenue) =
                                     self.player.stores[store_idx].sell(product_id, sales)
+                            {
+                                // Customers pay the promotional price; bundle discounts wait
+                                // until we know how many complete sets were bought
+                                let mut revenue = shelf_revenue;
+                                match Promotion::best_index(&product, &promotions) {
+                                    Some(idx) if promotions[idx].needs_co_purchase() => {
+                                        let unit_price = shelf_revenue / sales as f64;
+                                        bundle_units.insert((idx, product_id), (sales, unit_price, sales_by_product.len()));
+                                    }
+                                    Some(idx) => {
+                                        revenue = shelf_revenue * promotions[idx].price_multiplier();
+                                        let discount = shelf_revenue - revenue;
+                                        promotion_discounts += discount;
+                                        promotion_sales.push((
+                                            store_name.clone(),
+                                            self.describe_promotion(&promotions[idx]),
+                                            sales,
+                                            discount,
+                                        ));
+                                    }
+                                    None => {}
+                                }
+
+                                // Inspectors fine sales above a legal price cap
+                                if let Some(cap) = self.price_cap(&product)
+                                    && price_paid > cap
                                 {
-                                    self.player.earn(revenue);
-                                    total_revenue += revenue;
-                                    total_items_sold += sales;
-                                    sales_by_product.push((product.name.clone(), sales, revenue));
+                                    fines.push((
+                                        format!("{} sold above price cap at {}", product.name, store_name),
+                                        Regulations::overcharge_fine(price_paid, cap, sales),
+                                    ));
+                                }
+
+                                self.player.earn(revenue);
+                                store_revenue += revenue;
+                                total_revenue += revenue;
+                                total_items_sold += sales;
+                                sales_by_product.push((product.name.clone(), sales, revenue));
+                                service.units_sold += sales;
+                                match tier {
+                                    QualityTier::Premium => service.premium_units += sales,
+                                    QualityTier::Economy => service.economy_units += sales,
+                                    QualityTier::Standard => {}
                                 }
                             }
                         }
                     }
                 }
             }
+
+            // Bundle discounts only cover units matched by every partner product
+            for (idx, promotion) in promotions.iter().enumerate() {
+                let product_ids = match &promotion.kind {
+                    PromotionKind::Bundle { product_ids, .. } => product_ids,
+                    _ => continue,
+                };
+                let sets = promotion.bundles_sold(|id| bundle_units.get(&(idx, id)).map(|u| u.0).unwrap_or(0));
+                if sets == 0 {
+                    continue;
+                }
+                let mut discount = 0.0;
+                for id in product_ids {
+                    if let Some(&(_, unit_price, sale_idx)) = bundle_units.get(&(idx, *id)) {
+                        let member_discount = sets as f64 * unit_price * (1.0 - promotion.price_multiplier());
+                        sales_by_product[sale_idx].2 -= member_discount;
+                        discount += member_discount;
+                    }
+                }
+                self.player.cash -= discount;
+                store_revenue -= discount;
+                total_revenue -= discount;
+                promotion_discounts += discount;
+                promotion_sales.push((
+                    store_name.clone(),
+                    self.describe_promotion(promotion),
+                    sets * product_ids.len() as u32,
+                    discount,
+                ));
+            }
+
+            // Products we don't carry at all: price them at the default markup
+            // to estimate what shoppers asked for and couldn't find
+            for product in self.products.iter().filter(|p| p.product_type.can_sell_retail()) {
+                if demand_log.contains_key(&product.id) {
+                    continue;
+                }
+                let wholesale = self
+                    .regional_wholesale_price(self.player.stores[store_idx].region_id, product.id)
+                    .unwrap_or(product.base_price);
+                let retail_price = Market::suggest_retail_price(wholesale, 50.0);
+                let demand = match &shopping_day {
+                    Some(day) => day.records.get(&product.id).map(|r| r.demand).unwrap_or(0),
+                    None => {
+                        let interest = self.product_interest(product, retail_price, &shelf_ratios);
+                        let product_customers = (customer_count as f64 * interest) as u32;
+                        self.market
+                            .calculate_demand(product, retail_price, product_customers, &promotions)
+                    }
+                };
+                demand_log.insert(product.id, DemandRecord::new(demand, 0));
+                if demand > 0 {
+                    let lost_revenue = demand as f64 * retail_price;
+                    total_lost_revenue += lost_revenue;
+                    lost_sales.push((store_name.clone(), product.name.clone(), demand, lost_revenue));
+                }
+            }
+            self.player.stores[store_idx].demand_log = demand_log;
+
+            // Loyalty members earn rewards on today's spending
+            let store = &mut self.player.stores[store_idx];
+            if let Some(program) = store.loyalty_program.as_mut() {
+                let rewards = program.rewards_cost(store_revenue);
+                program.advance_day();
+                loyalty_rewards += rewards;
+                total_expenses += rewards;
+            }
+
+            // Update reputation from today's service
+            if priced_products > 0 {
+                service.avg_price_ratio = price_ratio_total / priced_products as f64;
+            }
+            let store = &mut self.player.stores[store_idx];
+            let old_reputation = store.reputation.score;
+            store.reputation.update(&service);
+            reputation_changes.push((store_name.clone(), old_reputation, store.reputation.score));
+
+            // Count down promotions
+            for ended in self.player.stores[store_idx].advance_promotions() {
+                promotions_ended.push((store_name.clone(), self.describe_promotion(&ended)));
+            }
         }
 
         // Process each factory
         let factory_count = self.player.factories.len();
         let mut auto_transfers: Vec<(String, String, String, u32)> = Vec::new();
+        let mut distribution_held: Vec<(String, String, u32)> = Vec::new();
+        let mut standing_orders: Vec<(String, OrderLogEntry)> = Vec::new();
+        let mut procurement_reports: Vec<ProcurementReport> = Vec::new();
+        let mut defects: Vec<(String, String, u32, bool)> = Vec::new();
+        let mut seconds_revenue = 0.0;
 
         for factory_idx in 0..factory_count {
             // Calculate expenses for this factory
@@ -575,56 +2045,225 @@ impl GameState {
 
             // Advance production and collect completed items
             let completed = self.player.factories[factory_idx].advance_production();
+
+            // Defective units are scrapped or sold to an outlet as seconds
+            let handling = self.player.factories[factory_idx].defect_handling;
+            for result in completed.iter().filter(|r| r.defective > 0) {
+                let product_name = self
+                    .get_product(result.product_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default();
+                let sold = handling == DefectHandling::SellAsSeconds && !result.intermediate;
+                if sold {
+                    let normal_price = self
+                        .get_product(result.product_id)
+                        .map(|p| self.market.reference_price(p))
+                        .unwrap_or(0.0);
+                    let revenue = normal_price * DefectHandling::SECONDS_PRICE * result.defective as f64;
+                    self.player.earn(revenue);
+                    self.taxes.record_revenue(revenue);
+                    seconds_revenue += revenue;
+                }
+                defects.push((factory_name.clone(), product_name, result.defective, sold));
+            }
+            let mut produced: HashMap<u32, u32> = HashMap::new();
+            for result in completed.iter().filter(|r| !r.intermediate) {
+                *produced.entry(result.product_id).or_insert(0) += result.quantity;
+            }
             production_completed.extend(completed);
 
             // Process auto-transfers if enabled
             let factory = &self.player.factories[factory_idx];
             if factory.auto_transfer && !factory.connected_stores.is_empty() {
-                // Get primary store for auto-transfer
-                if let Some(primary_store_id) = factory.primary_store() {
-                    // Find store index
-                    if let Some(store_idx) = self.player.stores.iter().position(|s| s.id == primary_store_id) {
-                        let store_name = self.player.stores[store_idx].name.clone();
-
-                        // Transfer all finished goods
-                        let product_ids: Vec<u32> = self.player.factories[factory_idx]
-                            .finished_goods
-                            .keys()
-                            .copied()
-                            .collect();
-
-                        for product_id in product_ids {
-                            let quantity = self.player.factories[factory_idx].get_finished_good(product_id);
-                            if quantity > 0 {
-                                // Get product info for retail price
-                                if let Some(product) = self.get_product(product_id) {
-                                    let product_name = product.name.clone();
-                                    let retail_price = Market::suggest_retail_price(product.base_price, 50.0);
-
-                                    // Take from factory and add to store
-                                    if let Ok(transferred) = self.player.factories[factory_idx]
-                                        .take_finished_goods(product_id, quantity)
-                                    {
-                                        self.player.stores[store_idx]
-                                            .add_inventory(product_id, transferred, retail_price);
-                                        auto_transfers.push((
-                                            factory_name.clone(),
-                                            store_name.clone(),
-                                            product_name,
-                                            transferred,
-                                        ));
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+                self.distribute_finished_goods(factory_idx, &produced, &mut auto_transfers, &mut distribution_held);
+            }
+
+            // Buy raw materials before standing orders claim them
+            if let Some(report) = self.run_procurement(factory_idx) {
+                procurement_reports.push(report);
+            }
+
+            // Restart standing orders in the slots freed up today
+            for entry in self.player.factories[factory_idx].run_standing_orders(&self.recipes, self.day) {
+                standing_orders.push((factory_name.clone(), entry));
             }
         }
 
         // Deduct expenses
         self.player.cash -= total_expenses;
 
+        // Count down advertising campaigns
+        let campaigns_ended: Vec<String> = self
+            .player
+            .advance_campaigns()
+            .iter()
+            .map(|c| self.describe_campaign(c))
+            .collect();
+        let active_campaigns = self.player.campaigns.len();
+
+        // ==================== INFLATION ====================
+
+        // Expiring leases are renewed at today's price level and local property costs
+        let price_level = self.market.price_level;
+        let rent_level = |regions: &[Region], region_id: u32| {
+            let multiplier = regions
+                .iter()
+                .find(|r| r.id == region_id)
+                .map(|r| r.rent_multiplier)
+                .unwrap_or(1.0);
+            price_level * multiplier
+        };
+        let mut lease_renewals = Vec::new();
+        for store in &mut self.player.stores {
+            if let Some((old_rent, new_rent)) = store.advance_lease(rent_level(&self.regions, store.region_id)) {
+                lease_renewals.push((store.name.clone(), old_rent, new_rent));
+            }
+        }
+        for factory in &mut self.player.factories {
+            if let Some((old_rent, new_rent)) = factory.advance_lease(rent_level(&self.regions, factory.region_id)) {
+                lease_renewals.push((factory.name.clone(), old_rent, new_rent));
+            }
+        }
+
+        // Periodic cost-of-living review for all staff
+        let wage_review = if self.day.is_multiple_of(Market::WAGE_REVIEW_DAYS) {
+            let factor = self.market.review_wages();
+            for store in &mut self.player.stores {
+                store.apply_wage_review(factor);
+            }
+            for factory in &mut self.player.factories {
+                factory.apply_wage_review(factor);
+            }
+            Some(factor)
+        } else {
+            None
+        };
+
+        // ==================== REGULATION ====================
+
+        let mut regulation_events = Vec::new();
+        match self.regulations.update_price_controls(economic_state) {
+            Some(true) => {
+                let lowered = self.clamp_prices_to_caps();
+                regulation_events.push(format!(
+                    "Emergency price controls: food capped at {:.0}% of normal prices ({} shelf price(s) lowered to the cap)",
+                    Regulations::FOOD_PRICE_CAP * 100.0,
+                    lowered
+                ));
+            }
+            Some(false) => regulation_events.push("Food price controls lifted".to_string()),
+            None => {}
+        }
+
+        if self.day.is_multiple_of(Regulations::WAGE_REVIEW_DAYS)
+            && let Some((old_wage, new_wage)) = self.regulations.review_minimum_wage(economic_state)
+        {
+            let raised: u32 = self
+                .player
+                .stores
+                .iter_mut()
+                .map(|s| s.apply_minimum_wage(new_wage))
+                .chain(self.player.factories.iter_mut().map(|f| f.apply_minimum_wage(new_wage)))
+                .sum();
+            regulation_events.push(format!(
+                "Minimum wage raised ${:.2} -> ${:.2}/day ({} staff given a raise)",
+                old_wage, new_wage, raised
+            ));
+        }
+
+        // Expired licences are renewed automatically if the player can pay
+        for region_id in self.regulations.advance_licences() {
+            let region_name = self.region(region_id).map(|r| r.name.clone()).unwrap_or_default();
+            match self.buy_business_licence(region_id) {
+                Ok(fee) => regulation_events.push(format!("Business licence in {} renewed for ${:.2}", region_name, fee)),
+                Err(_) => regulation_events.push(format!("Business licence in {} lapsed - renew it to avoid fines", region_name)),
+            }
+        }
+        let unlicensed_fine = Regulations::UNLICENSED_FINE * self.market.price_level;
+        for store in &self.player.stores {
+            if !self.regulations.has_licence(store.region_id) {
+                fines.push((format!("{} operating without a licence", store.name), unlicensed_fine));
+            }
+        }
+
+        for event in &regulation_events {
+            self.news.publish(self.day, NewsCategory::Business, event);
+        }
+        let total_fines: f64 = fines.iter().map(|(_, amount)| amount).sum();
+        self.player.cash -= total_fines;
+        self.regulations.total_fines += total_fines;
+
+        // ==================== FOREIGN TRADE ====================
+
+        let market = &self.market;
+        self.trade.advance_rates(|i| market.salted_random(30 + i as u64));
+        let exchange_rates: Vec<(String, f64, f64)> = self
+            .trade
+            .rates
+            .iter()
+            .map(|r| (r.currency.code().to_string(), r.rate, r.change()))
+            .collect();
+
+        let mut imports_arrived = Vec::new();
+        let mut imports_undeliverable = Vec::new();
+        let mut exports_paid = Vec::new();
+        let mut export_revenue = 0.0;
+        for shipment in self.trade.advance_shipments() {
+            let product_name = self
+                .get_product(shipment.product_id)
+                .map(|p| p.name.clone())
+                .unwrap_or_default();
+            match shipment.kind {
+                ShipmentKind::Import { destination, unit_cost } => {
+                    // Imports arrive priced at the default markup over their landed cost (or the legal cap)
+                    let retail_price =
+                        self.capped_price(shipment.product_id, Market::suggest_retail_price(unit_cost, 50.0));
+                    // Goods for a location that no longer exists go to the first one of its kind
+                    let delivered_to = match destination {
+                        Destination::Store(store_id) => {
+                            let found = self.player.stores.iter().position(|s| s.id == store_id);
+                            let idx = found.or((!self.player.stores.is_empty()).then_some(0));
+                            idx.map(|idx| {
+                                let store = &mut self.player.stores[idx];
+                                store.add_inventory(shipment.product_id, shipment.quantity, retail_price);
+                                (store.name.clone(), found.is_none())
+                            })
+                        }
+                        Destination::Factory(factory_id) => {
+                            let found = self.player.factories.iter().position(|f| f.id == factory_id);
+                            let idx = found.or((!self.player.factories.is_empty()).then_some(0));
+                            idx.map(|idx| {
+                                let factory = &mut self.player.factories[idx];
+                                // Imports come at the standard grade
+                                factory.add_raw_material(shipment.product_id, shipment.quantity, unit_cost, MaterialGrade::Standard);
+                                (factory.name.clone(), found.is_none())
+                            })
+                        }
+                    };
+                    match delivered_to {
+                        Some((location, false)) => {
+                            imports_arrived.push((shipment.partner, product_name, shipment.quantity, location));
+                        }
+                        Some((location, true)) => imports_arrived.push((
+                            shipment.partner,
+                            product_name,
+                            shipment.quantity,
+                            format!("{} (redirected)", location),
+                        )),
+                        None => imports_undeliverable.push((shipment.partner, product_name, shipment.quantity)),
+                    }
+                }
+                ShipmentKind::Export { currency, payment } => {
+                    // Paid at the exchange rate on the day the goods arrive
+                    let received = payment * self.trade.rate(currency);
+                    self.player.earn(received);
+                    self.taxes.record_revenue(received);
+                    export_revenue += received;
+                    exports_paid.push((shipment.partner, product_name, shipment.quantity, received));
+                }
+            }
+        }
+
         // ==================== LOAN PROCESSING ====================
 
         // 1. Accrue interest on all loans
@@ -637,21 +2276,21 @@ impl GameState {
         // 2. Process auto-payments for line of credit loans
         let loan_ids: Vec<u32> = self.player.loans.iter().map(|l| l.id).collect();
         for loan_id in loan_ids {
-            if let Some(loan) = self.player.get_loan(loan_id) {
-                if loan.loan_type == LoanType::LineOfCredit {
-                    let auto_payment = loan.get_auto_payment();
-                    if auto_payment > 0.0 && self.player.cash >= auto_payment {
-                        if let Some(paid) = self.player.make_loan_payment(loan_id, auto_payment) {
-                            loan_payments.push((loan_id, paid));
-                        }
-                    } else if auto_payment > 0.0 {
-                        // Can't afford auto-payment, pay what we can
-                        let available = self.player.cash.max(0.0);
-                        if available > 0.0 {
-                            if let Some(paid) = self.player.make_loan_payment(loan_id, available) {
-                                loan_payments.push((loan_id, paid));
-                            }
-                        }
+            if let Some(loan) = self.player.get_loan(loan_id)
+                && loan.loan_type == LoanType::LineOfCredit
+            {
+                let auto_payment = loan.get_auto_payment();
+                if auto_payment > 0.0 && self.player.cash >= auto_payment {
+                    if let Some(paid) = self.player.make_loan_payment(loan_id, auto_payment) {
+                        loan_payments.push((loan_id, paid));
+                    }
+                } else if auto_payment > 0.0 {
+                    // Can't afford auto-payment, pay what we can
+                    let available = self.player.cash.max(0.0);
+                    if available > 0.0
+                        && let Some(paid) = self.player.make_loan_payment(loan_id, available)
+                    {
+                        loan_payments.push((loan_id, paid));
                     }
                 }
             }
@@ -707,6 +2346,46 @@ impl GameState {
         // 6. Clean up paid-off loans
         self.player.cleanup_loans();
 
+        // ==================== LOGISTICS ====================
+
+        // Lease payments on the fleet; trips were paid when the trucks left
+        let fleet_costs = self.logistics.fixed_costs();
+        self.player.cash -= fleet_costs;
+        self.taxes.record_expense(fleet_costs);
+        let shipping_costs = std::mem::take(&mut self.shipping_costs) + fleet_costs;
+
+        // ==================== TAXES ====================
+
+        let total_rent: f64 = expenses_by_store
+            .iter()
+            .chain(&expenses_by_factory)
+            .map(|(_, rent, _)| rent)
+            .sum();
+        let total_salaries: f64 = expenses_by_store
+            .iter()
+            .chain(&expenses_by_factory)
+            .map(|(_, _, salaries)| salaries)
+            .sum();
+
+        // Sales and payroll taxes are remitted daily
+        let sales_tax = self.taxes.record_sales(total_revenue);
+        let payroll_tax = self.taxes.record_payroll(total_salaries);
+        self.taxes.record_rent(total_rent);
+        self.taxes.record_interest(loan_interest_accrued);
+        self.taxes.record_expense(loyalty_rewards);
+        let depreciation = self.taxes.depreciate();
+        self.player.cash -= sales_tax + payroll_tax;
+
+        // Corporate income tax is assessed at the end of each quarter
+        let tax_filing = if TaxLedger::is_filing_day(self.day) {
+            let filing = self.taxes.file_return(self.day);
+            self.player.cash -= filing.tax_due;
+            Some(filing)
+        } else {
+            None
+        };
+        let corporate_tax = tax_filing.as_ref().map(|f| f.tax_due).unwrap_or(0.0);
+
         // ==================== STOCK MARKET PROCESSING ====================
 
         // Update stock prices
@@ -736,7 +2415,12 @@ impl GameState {
 
         self.day += 1;
 
-        let net_profit = total_revenue - total_expenses - loan_interest_accrued + dividends_earned;
+        let net_profit = total_revenue + export_revenue + seconds_revenue - total_expenses - loan_interest_accrued + dividends_earned
+            - sales_tax
+            - payroll_tax
+            - corporate_tax
+            - total_fines
+            - shipping_costs;
 
         DayResult {
             total_revenue,
@@ -749,25 +2433,96 @@ impl GameState {
             net_profit,
             economic_state,
             economic_change,
+            date: self.market.calendar.date_string(),
+            holiday: self.market.calendar.holiday.as_ref().map(|h| h.name.to_string()),
             loan_interest_accrued,
             loan_payments,
             loans_due,
             loans_due_soon,
             term_loan_penalties,
             auto_transfers,
+            distribution_held,
+            deliveries_arrived,
+            shipping_costs,
+            standing_orders,
+            procurement_reports,
+            defects,
+            seconds_revenue,
             competitor_events,
             player_market_share,
+            regional_shares,
             stock_changes,
             dividends_earned,
+            manager_reports,
+            active_campaigns,
+            campaigns_ended,
+            promotion_sales,
+            promotion_discounts,
+            promotions_ended,
+            loyalty_rewards,
+            reputation_changes,
+            lost_sales,
+            total_lost_revenue,
+            price_level,
+            inflation_rate: self.market.inflation_rate(),
+            lease_renewals,
+            wage_review,
+            events_started,
+            events_ended,
+            active_events,
+            headlines,
+            shopper_stats,
+            sales_tax,
+            payroll_tax,
+            depreciation,
+            tax_filing,
+            regulation_events,
+            fines,
+            exchange_rates,
+            imports_arrived,
+            imports_undeliverable,
+            exports_paid,
+        }
+    }
+
+    /// Calculates a store's daily customers including staff, local market share and
+    /// population, advertising and weekday/holiday traffic
+    /// The player's stores in a region never draw more than the player's share of its market
+    fn store_customer_count(&self, store_idx: usize) -> u32 {
+        let region = self.store_region(store_idx);
+        let customers = self.unbounded_customer_count(store_idx);
+
+        let regional_total: f64 = (0..self.player.stores.len())
+            .filter(|&idx| self.player.stores[idx].region_id == region.id)
+            .map(|idx| self.unbounded_customer_count(idx))
+            .sum();
+        let pool = region.market.player_customer_pool() as f64;
+        if regional_total > pool {
+            (customers * pool / regional_total) as u32
+        } else {
+            customers as u32
         }
     }
 
-    /// Calculates average markup across all stores
-    fn calculate_average_markup(&self) -> f64 {
+    /// Calculates a store's customers before capping at the regional customer pool
+    fn unbounded_customer_count(&self, store_idx: usize) -> f64 {
+        let store = &self.player.stores[store_idx];
+        let region = self.store_region(store_idx);
+        let marketing_multiplier = self.player.store_marketing_multiplier(store.id);
+        let calendar_multiplier = self.market.calendar.traffic_multiplier();
+        store.effective_customers() as f64
+            * region.customer_multiplier()
+            * region.sales_multiplier
+            * marketing_multiplier
+            * calendar_multiplier
+    }
+
+    /// Calculates average markup across the player's stores in a region
+    fn calculate_average_markup(&self, region_id: u32) -> f64 {
         let mut total_markup = 0.0;
         let mut item_count = 0;
 
-        for store in &self.player.stores {
+        for store in self.player.stores.iter().filter(|s| s.region_id == region_id) {
             for (product_id, item) in &store.inventory {
                 if let Some(product) = self.get_product(*product_id) {
                     let markup = ((item.retail_price - product.base_price) / product.base_price) * 100.0;
@@ -830,3 +2585,282 @@ impl Default for GameState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_shelf_records_lost_sales() {
+        let mut game = GameState::new();
+        game.player.stores[0].add_inventory(1, 1, 1.0);
+        let retail_products = game.products.iter().filter(|p| p.product_type.can_sell_retail()).count();
+
+        for _ in 0..2 {
+            let result = game.advance_day();
+            let store = &game.player.stores[0];
+            // Yesterday's log replaces the day before: one record per retail product
+            assert_eq!(store.demand_log.len(), retail_products);
+
+            for (_, name, lost, _) in &result.lost_sales {
+                let product = game.products.iter().find(|p| &p.name == name).unwrap();
+                let record = store.demand_log[&product.id];
+                assert_eq!(*lost, record.demand - record.sold);
+            }
+        }
+        // The single loaf sold on the first day, so the shelf was empty on the second
+        assert_eq!(game.player.stores[0].get_quantity(1), 0);
+        let bread = game.player.stores[0].demand_log[&1];
+        assert_eq!(bread.sold, 0);
+        assert_eq!(bread.lost(), bread.demand);
+        assert!(game.player.stores[0].last_demand(1) > 0);
+    }
+
+    #[test]
+    fn test_forecast_reprices_substitute_effects() {
+        let mut game = GameState::new();
+        // Phone chargers next to USB cables, a substitute at its normal price
+        game.player.stores[0].add_inventory(6, 50, 15.0);
+        game.player.stores[0].add_inventory(7, 50, 8.0);
+        let forecast = game.forecast_prices(6, 1, &[15.0, 40.0]).unwrap();
+
+        let charger = game.get_product(6).unwrap();
+        let shelf_ratios = game.shelf_price_ratios(0, &[]);
+        let customers = game.store_customer_count(0) as f64;
+        for f in &forecast {
+            let interest = game.product_interest(charger, f.price, &shelf_ratios);
+            let expected = game.market.expected_demand(charger, f.price, customers * interest, &[]);
+            assert!((f.daily_units - expected).abs() < 1e-9);
+        }
+        // Overpriced chargers lose shoppers to cables
+        assert!(
+            game.product_interest(charger, 40.0, &shelf_ratios) < game.product_interest(charger, 15.0, &shelf_ratios)
+        );
+    }
+
+    #[test]
+    fn test_no_price_suggestion_without_stock() {
+        let mut game = GameState::new();
+        game.player.stores[0].add_inventory(1, 0, 3.0);
+        assert_eq!(game.suggest_optimal_price(1, 7).unwrap_err(), "No stock to forecast");
+
+        game.player.stores[0].add_inventory(1, 20, 3.0);
+        let best = game.suggest_optimal_price(1, 7).unwrap();
+        assert!(best.price > game.store_wholesale_price(1).unwrap());
+    }
+
+    #[test]
+    fn test_price_controls_lower_prices_above_the_cap() {
+        let mut game = GameState::new();
+        game.player.stores[0].add_inventory(1, 10, 10.0); // Bread, far above the cap
+        game.player.stores[0].add_inventory(2, 10, 3.0); // Milk, under it
+        game.player.stores[0].add_inventory(5, 10, 100.0); // Headphones aren't capped
+
+        game.regulations.update_price_controls(EconomicState::Collapse);
+        assert_eq!(game.clamp_prices_to_caps(), 1);
+
+        let bread_cap = game.price_cap(game.get_product(1).unwrap()).unwrap();
+        let store = &game.player.stores[0];
+        assert_eq!(store.get_price(1), Some(bread_cap));
+        assert_eq!(store.get_price(2), Some(3.0));
+        assert_eq!(store.get_price(5), Some(100.0));
+    }
+
+    #[test]
+    fn test_stock_bought_under_price_controls_starts_at_the_cap() {
+        let mut game = GameState::new();
+        game.player.cash = 10_000.0;
+        game.player.add_store("Metro Shop", 2);
+        game.regulations.grant_licence(2);
+        game.current_store = 1;
+        game.market.economic_state = EconomicState::Collapse;
+        game.regulations.update_price_controls(EconomicState::Collapse);
+
+        let wholesale = game.store_wholesale_price(1).unwrap();
+        let cap = game.price_cap(game.get_product(1).unwrap()).unwrap();
+        assert!(Market::suggest_retail_price(wholesale, 50.0) > cap);
+
+        game.buy_inventory(1, 50).unwrap();
+        assert!(game.player.stores[1].get_price(1).unwrap() <= cap);
+
+        let result = game.advance_day();
+        assert!(result.fines.iter().all(|(reason, _)| !reason.contains("price cap")));
+    }
+
+    #[test]
+    fn test_forecasts_value_the_quality_tier() {
+        let suggest = |quality: f64| {
+            let mut game = GameState::new();
+            game.player.stores[0].add_inventory_with_quality(16, 20, 50.0, quality);
+            game.suggest_optimal_price(16, 7).unwrap()
+        };
+        let premium = suggest(0.9);
+        let standard = suggest(QualityTier::DEFAULT_SCORE);
+        let economy = suggest(0.2);
+        assert!(premium.price > standard.price);
+        assert!(standard.price > economy.price);
+    }
+
+    #[test]
+    fn test_managers_keep_the_quality_premium() {
+        let mut game = GameState::new();
+        game.player.stores[0].add_inventory_with_quality(1, 500, 2.0, 0.9);
+        game.hire_store_manager("Bob", ManagerMandate::new(50.0, 0.0, 0)).unwrap();
+
+        game.run_store_manager(0).unwrap();
+        let wholesale = game.store_wholesale_price(1).unwrap();
+        let expected = ManagerMandate::default().target_price(wholesale) * QualityTier::Premium.price_multiplier();
+        assert!((game.player.stores[0].get_price(1).unwrap() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_seconds_follow_the_price_level() {
+        let mut game = GameState::new();
+        game.player.add_factory("Plant", Region::HOME_ID);
+        let chair = Recipe::producing(&game.recipes, 16).unwrap().clone();
+        let factory = &mut game.player.factories[0];
+        factory.add_raw_material(11, 4, 1.0, MaterialGrade::Standard);
+        factory.start_production_batch(&chair, 2).unwrap();
+        // The next unit off the line is defective
+        factory.defect_backlog = 0.99;
+        game.market.price_level = 2.0;
+
+        let result = game.advance_day();
+        let defective: u32 = result.defects.iter().map(|d| d.2).sum();
+        assert!(defective > 0);
+        let base_price = game.get_product(16).unwrap().base_price;
+        let at_base = base_price * DefectHandling::SECONDS_PRICE * defective as f64;
+        assert!(result.seconds_revenue > at_base * 1.9);
+    }
+
+    #[test]
+    fn test_price_suggestions_respect_the_cap() {
+        let mut game = GameState::new();
+        game.player.stores[0].add_inventory(1, 20, 2.0);
+        game.regulations.update_price_controls(EconomicState::Collapse);
+        let cap = game.price_cap(game.get_product(1).unwrap()).unwrap();
+
+        let suggestion = game.suggest_optimal_price(1, 7).unwrap();
+        assert!(suggestion.price <= cap + 1e-9);
+        assert!(game.set_retail_price(1, suggestion.price).is_ok());
+        assert!(game.forecast_markups(1, 7).unwrap().iter().all(|f| f.price <= cap + 1e-9));
+    }
+
+    #[test]
+    fn test_imports_for_missing_locations_are_not_lost() {
+        let mut game = GameState::new();
+        let to_store = ShipmentKind::Import { destination: Destination::Store(99), unit_cost: 1.0 };
+        let to_factory = ShipmentKind::Import { destination: Destination::Factory(99), unit_cost: 1.0 };
+        game.trade.ship("Supplier", 1, 10, 1, to_store);
+        game.trade.ship("Supplier", 11, 20, 1, to_factory);
+
+        let result = game.advance_day();
+        // The store order goes to the first store; with no factory the lumber is reported
+        assert_eq!(result.imports_arrived.len(), 1);
+        assert!(result.imports_arrived[0].3.ends_with("(redirected)"));
+        assert!(game.player.stores[0].get_quantity(1) > 0);
+        assert_eq!(result.imports_undeliverable.len(), 1);
+        assert_eq!(result.imports_undeliverable[0].2, 20);
+    }
+
+    #[test]
+    fn test_manual_split_skips_disconnected_store() {
+        let mut game = GameState::new();
+        game.player.add_store("Second Store", Region::HOME_ID);
+        game.player.add_factory("Plant", Region::HOME_ID);
+        let (first_id, second_id) = (game.player.stores[0].id, game.player.stores[1].id);
+        let factory = &mut game.player.factories[0];
+        factory.connect_store(first_id);
+        factory.connect_store(second_id);
+        factory
+            .set_distribution(DistributionPolicy::Manual(vec![(first_id, 50.0), (second_id, 50.0)]))
+            .unwrap();
+        factory.disconnect_store(second_id);
+        factory.finished_goods.insert(16, 10);
+
+        let produced = HashMap::from([(16, 10)]);
+        let (mut transfers, mut held) = (Vec::new(), Vec::new());
+        game.distribute_finished_goods(0, &produced, &mut transfers, &mut held);
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].1, "My First Store");
+        assert_eq!(transfers[0].3, 5);
+        assert_eq!(held[0].2, 5);
+        assert_eq!(game.logistics.in_transit_to(second_id, 16), 0);
+
+        // Half of what's left goes out the next day; nothing new is reported as held
+        let (mut transfers, mut held) = (Vec::new(), Vec::new());
+        game.distribute_finished_goods(0, &HashMap::new(), &mut transfers, &mut held);
+        assert_eq!(transfers[0].3 + game.player.factories[0].get_finished_good(16), 5);
+        assert!(held.is_empty());
+    }
+
+    #[test]
+    fn test_sold_truck_leaves_the_tax_books() {
+        let mut game = GameState::new();
+        game.player.cash = 20_000.0;
+        let id = game.buy_truck().unwrap();
+        assert!(game.taxes.assets.iter().any(|a| a.name == "Truck #1"));
+
+        let proceeds = game.retire_truck(id).unwrap();
+        assert!(game.taxes.assets.iter().all(|a| a.name != "Truck #1"));
+        assert!((game.taxes.period_revenue - proceeds).abs() < 1e-9);
+        assert!((game.taxes.period_deductions.depreciation - Truck::PURCHASE_PRICE).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_transfers_arrive_after_the_route_days() {
+        let mut game = GameState::new();
+        game.player.add_store("Harbor Shop", 3);
+        game.player.add_factory("Plant", Region::HOME_ID);
+        let harbor_id = game.player.stores[1].id;
+        game.player.factories[0].connect_store(harbor_id);
+        game.player.factories[0].finished_goods.insert(16, 10);
+        game.current_factory = Some(0);
+
+        let days = game.transit_days(0, 1);
+        assert_eq!(days, 2);
+        assert_eq!(game.transfer_to_store(16, 10, 1), Ok(10));
+
+        for day in 1..=days {
+            let result = game.advance_day();
+            if day == 1 {
+                assert!(result.shipping_costs > 0.0);
+            }
+            if day < days {
+                assert!(result.deliveries_arrived.is_empty());
+                assert_eq!(game.logistics.in_transit_to(harbor_id, 16), 10);
+            } else {
+                assert_eq!(result.deliveries_arrived, vec![("Plant".to_string(), "Harbor Shop".to_string(), 10)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_tax_rates_are_configurable() {
+        let mut game = GameState::new();
+        assert!(game.set_tax_policy(TaxPolicy::new(0.1, 1.0, 0.2)).is_err());
+        assert!(game.set_tax_policy(TaxPolicy::new(-0.1, 0.05, 0.2)).is_err());
+        assert_eq!(game.taxes.policy, TaxPolicy::default());
+
+        game.set_tax_policy(TaxPolicy::new(0.0, 0.0, 0.3)).unwrap();
+        assert_eq!(game.taxes.policy.corporate_tax_rate, 0.3);
+        assert_eq!(game.advance_day().sales_tax, 0.0);
+    }
+
+    #[test]
+    fn test_hires_between_reviews_get_inflation_once() {
+        let mut game = GameState::new();
+        // Prices have risen 20% since the last wage review
+        game.market.price_level = 1.2;
+        let salary = game.going_wage(Employee::BASE_SALARY);
+        game.current_store_mut().hire_employee("Ann", salary).unwrap();
+        game.hire_store_manager("Bob", ManagerMandate::default()).unwrap();
+
+        let factor = game.market.review_wages();
+        game.player.stores[0].apply_wage_review(factor);
+
+        let store = &game.player.stores[0];
+        assert!((store.employees[0].salary - Employee::BASE_SALARY * 1.2).abs() < 1e-9);
+        assert!((store.manager.as_ref().unwrap().salary - StoreManager::BASE_SALARY * 1.2).abs() < 1e-9);
+    }
+}
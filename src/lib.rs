@@ -1,11 +1,29 @@
+pub mod calendar;
 pub mod competitor;
+pub mod distribution;
 pub mod economy;
+pub mod event;
 pub mod factory;
 pub mod game;
 pub mod loan;
+pub mod logistics;
+pub mod manager;
+pub mod marketing;
+pub mod news;
+pub mod planning;
 pub mod player;
+pub mod procurement;
 pub mod product;
+pub mod promotion;
+pub mod quality;
 pub mod recipe;
+pub mod region;
+pub mod regulation;
+pub mod reputation;
+pub mod shopper;
 pub mod stock;
 pub mod store;
+pub mod tax;
+pub mod trade;
+pub mod transfer;
 pub mod ui;
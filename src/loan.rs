@@ -134,10 +134,10 @@ impl Loan {
 
     /// Decrement days remaining for term loans
     pub fn decrement_days(&mut self) {
-        if let Some(ref mut days) = self.days_remaining {
-            if *days > 0 {
-                *days -= 1;
-            }
+        if let Some(ref mut days) = self.days_remaining
+            && *days > 0
+        {
+            *days -= 1;
         }
     }
 
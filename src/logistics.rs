@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+/// How the player holds a truck
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TruckOwnership {
+    /// Bought outright
+    Owned,
+    /// Rented for a daily fee
+    Leased,
+}
+
+/// A truck in the player's fleet
+#[derive(Debug, Clone)]
+pub struct Truck {
+    pub id: u32,
+    pub name: String,
+    pub ownership: TruckOwnership,
+    /// Units it can carry per trip
+    pub capacity: u32,
+    /// Days until it is back from its current trip (0 = available)
+    pub busy_days: u32,
+}
+
+impl Truck {
+    /// Units a truck can carry
+    pub const CAPACITY: u32 = 200;
+    /// Price of buying a truck
+    pub const PURCHASE_PRICE: f64 = 8000.0;
+    /// Daily lease payment
+    pub const LEASE_DAILY: f64 = 60.0;
+    /// Fuel and driver cost per day on the road
+    pub const RUNNING_COST: f64 = 40.0;
+
+    pub fn new(id: u32, name: &str, ownership: TruckOwnership) -> Self {
+        Truck {
+            id,
+            name: name.to_string(),
+            ownership,
+            capacity: Self::CAPACITY,
+            busy_days: 0,
+        }
+    }
+
+    /// Returns true if the truck is parked and ready for a trip
+    pub fn is_available(&self) -> bool {
+        self.busy_days == 0
+    }
+
+    /// Returns the daily cost of holding the truck, whether or not it drives
+    pub fn fixed_cost(&self) -> f64 {
+        match self.ownership {
+            TruckOwnership::Owned => 0.0,
+            TruckOwnership::Leased => Self::LEASE_DAILY,
+        }
+    }
+}
+
+/// One product line on a delivery
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cargo {
+    pub product_id: u32,
+    pub quantity: u32,
+    /// Retail price for the store shelf
+    pub retail_price: f64,
+    /// Quality score of the goods
+    pub quality: f64,
+}
+
+impl Cargo {
+    pub fn new(product_id: u32, quantity: u32, retail_price: f64, quality: f64) -> Self {
+        Cargo {
+            product_id,
+            quantity,
+            retail_price,
+            quality,
+        }
+    }
+}
+
+/// Goods on the way from a factory to a store
+#[derive(Debug, Clone)]
+pub struct Delivery {
+    pub id: u32,
+    /// Truck carrying the goods (None for a courier)
+    pub truck_id: Option<u32>,
+    pub factory_id: u32,
+    pub store_id: u32,
+    pub cargo: Vec<Cargo>,
+    pub days_remaining: u32,
+    /// Day the goods left the factory
+    pub departed: u32,
+}
+
+impl Delivery {
+    /// Returns the number of units on board
+    pub fn units(&self) -> u32 {
+        self.cargo.iter().map(|c| c.quantity).sum()
+    }
+}
+
+/// The player's trucks and everything currently on the road
+#[derive(Debug, Clone, Default)]
+pub struct Logistics {
+    pub trucks: Vec<Truck>,
+    pub deliveries: Vec<Delivery>,
+    /// Days on the road between two regions, keyed by (lower ID, higher ID)
+    pub routes: HashMap<(u32, u32), u32>,
+    next_truck_id: u32,
+    next_delivery_id: u32,
+}
+
+impl Logistics {
+    /// Courier fee per unit per day of transit when no truck is free
+    pub const COURIER_RATE: f64 = 0.5;
+    /// Days to deliver within a region
+    pub const LOCAL_TRANSIT_DAYS: u32 = 1;
+    /// Days assumed between regions with no route on record
+    pub const UNCHARTED_TRANSIT_DAYS: u32 = 4;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a fleet-less logistics network with the given (region, region, days) routes
+    pub fn with_routes(routes: &[(u32, u32, u32)]) -> Self {
+        let mut logistics = Self::new();
+        for &(from_region, to_region, days) in routes {
+            logistics.set_route(from_region, to_region, days);
+        }
+        logistics
+    }
+
+    /// Sets the days on the road between two regions (either direction)
+    pub fn set_route(&mut self, from_region: u32, to_region: u32, days: u32) {
+        let key = (from_region.min(to_region), from_region.max(to_region));
+        self.routes.insert(key, days.max(1));
+    }
+
+    /// Returns the transit days between two regions
+    pub fn transit_days(&self, from_region: u32, to_region: u32) -> u32 {
+        let key = (from_region.min(to_region), from_region.max(to_region));
+        match self.routes.get(&key) {
+            Some(&days) => days,
+            None if from_region == to_region => Self::LOCAL_TRANSIT_DAYS,
+            None => Self::UNCHARTED_TRANSIT_DAYS,
+        }
+    }
+
+    /// Returns the cost of sending a truck out and back on a route
+    pub fn trip_cost(transit_days: u32) -> f64 {
+        Truck::RUNNING_COST * (transit_days * 2) as f64
+    }
+
+    /// Returns the courier fee for sending units on a route
+    pub fn courier_cost(units: u32, transit_days: u32) -> f64 {
+        Self::COURIER_RATE * units as f64 * transit_days as f64
+    }
+
+    /// Adds a truck to the fleet, returns its ID
+    pub fn add_truck(&mut self, ownership: TruckOwnership) -> u32 {
+        self.next_truck_id += 1;
+        let id = self.next_truck_id;
+        self.trucks.push(Truck::new(id, &format!("Truck #{}", id), ownership));
+        id
+    }
+
+    /// Removes an available truck from the fleet
+    pub fn remove_truck(&mut self, truck_id: u32) -> Result<Truck, String> {
+        let idx = self
+            .trucks
+            .iter()
+            .position(|t| t.id == truck_id)
+            .ok_or("Truck not found")?;
+        if !self.trucks[idx].is_available() {
+            return Err("That truck is out on a delivery".to_string());
+        }
+        Ok(self.trucks.remove(idx))
+    }
+
+    /// Returns the daily cost of the fleet (lease payments)
+    pub fn fixed_costs(&self) -> f64 {
+        self.trucks.iter().map(|t| t.fixed_cost()).sum()
+    }
+
+    /// Returns units of a product on the way to a store
+    pub fn in_transit_to(&self, store_id: u32, product_id: u32) -> u32 {
+        self.deliveries
+            .iter()
+            .filter(|d| d.store_id == store_id)
+            .flat_map(|d| d.cargo.iter())
+            .filter(|c| c.product_id == product_id)
+            .map(|c| c.quantity)
+            .sum()
+    }
+
+    /// Sends goods from a factory to a store
+    /// Goods join a truck already leaving today on the same route if it has room,
+    /// otherwise take a free truck, otherwise go by courier.
+    /// Returns the shipping cost to charge now
+    pub fn dispatch(
+        &mut self,
+        factory_id: u32,
+        store_id: u32,
+        cargo: Cargo,
+        transit_days: u32,
+        day: u32,
+    ) -> f64 {
+        let mut quantity = cargo.quantity;
+        let mut cost = 0.0;
+
+        // Fill trucks already loading for this route
+        for delivery in self.deliveries.iter_mut().filter(|d| {
+            d.factory_id == factory_id && d.store_id == store_id && d.departed == day && d.truck_id.is_some()
+        }) {
+            let capacity = self
+                .trucks
+                .iter()
+                .find(|t| Some(t.id) == delivery.truck_id)
+                .map(|t| t.capacity)
+                .unwrap_or(0);
+            let room = capacity.saturating_sub(delivery.units()).min(quantity);
+            if room > 0 {
+                delivery.cargo.push(Cargo { quantity: room, ..cargo });
+                quantity -= room;
+            }
+        }
+
+        // Send out free trucks
+        while quantity > 0 {
+            let truck = match self.trucks.iter_mut().find(|t| t.is_available()) {
+                Some(t) => t,
+                None => break,
+            };
+            truck.busy_days = transit_days * 2;
+            let load = quantity.min(truck.capacity);
+            let truck_id = truck.id;
+            quantity -= load;
+            cost += Self::trip_cost(transit_days);
+            self.add_delivery(Some(truck_id), factory_id, store_id, Cargo { quantity: load, ..cargo }, transit_days, day);
+        }
+
+        // Anything left goes by courier
+        if quantity > 0 {
+            cost += Self::courier_cost(quantity, transit_days);
+            self.add_delivery(None, factory_id, store_id, Cargo { quantity, ..cargo }, transit_days, day);
+        }
+
+        cost
+    }
+
+    fn add_delivery(
+        &mut self,
+        truck_id: Option<u32>,
+        factory_id: u32,
+        store_id: u32,
+        cargo: Cargo,
+        transit_days: u32,
+        day: u32,
+    ) {
+        self.next_delivery_id += 1;
+        self.deliveries.push(Delivery {
+            id: self.next_delivery_id,
+            truck_id,
+            factory_id,
+            store_id,
+            cargo: vec![cargo],
+            days_remaining: transit_days,
+            departed: day,
+        });
+    }
+
+    /// Moves every truck and delivery on by a day
+    /// Returns the deliveries that arrived
+    pub fn advance(&mut self) -> Vec<Delivery> {
+        for truck in &mut self.trucks {
+            truck.busy_days = truck.busy_days.saturating_sub(1);
+        }
+        let mut arrived = Vec::new();
+        let mut on_the_road = Vec::new();
+        for mut delivery in self.deliveries.drain(..) {
+            delivery.days_remaining = delivery.days_remaining.saturating_sub(1);
+            if delivery.days_remaining == 0 {
+                arrived.push(delivery);
+            } else {
+                on_the_road.push(delivery);
+            }
+        }
+        self.deliveries = on_the_road;
+        arrived
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goods_share_a_truck_then_overflow_to_courier() {
+        let mut logistics = Logistics::new();
+        logistics.add_truck(TruckOwnership::Leased);
+
+        let first = logistics.dispatch(1, 1, Cargo::new(16, 150, 10.0, 0.5), 2, 1);
+        assert!((first - Logistics::trip_cost(2)).abs() < 1e-9);
+        // Fills the truck's last 50 units, the rest by courier
+        let second = logistics.dispatch(1, 1, Cargo::new(17, 80, 20.0, 0.5), 2, 1);
+        assert!((second - Logistics::courier_cost(30, 2)).abs() < 1e-9);
+        assert_eq!(logistics.deliveries.len(), 2);
+        assert_eq!(logistics.in_transit_to(1, 17), 80);
+
+        assert!(logistics.advance().is_empty());
+        let arrived = logistics.advance();
+        assert_eq!(arrived.iter().map(|d| d.units()).sum::<u32>(), 230);
+        // The truck still has to drive back
+        assert!(!logistics.trucks[0].is_available());
+        logistics.advance();
+        logistics.advance();
+        assert!(logistics.trucks[0].is_available());
+    }
+
+    #[test]
+    fn test_transit_days_follow_the_route_table() {
+        let logistics = Logistics::with_routes(&[(1, 3, 2), (3, 2, 5)]);
+        assert_eq!(logistics.transit_days(1, 1), Logistics::LOCAL_TRANSIT_DAYS);
+        assert_eq!(logistics.transit_days(3, 1), 2);
+        assert_eq!(logistics.transit_days(2, 3), 5);
+        assert_eq!(logistics.transit_days(1, 2), Logistics::UNCHARTED_TRANSIT_DAYS);
+    }
+}
@@ -1,9 +1,9 @@
 use capitalism_tycoon::game::GameState;
 use capitalism_tycoon::ui::{
     clear_screen, display_bankruptcy, display_day_result, display_goodbye, display_header,
-    display_menu, display_store, display_welcome, handle_buy_inventory, handle_manage_factories,
+    display_menu, display_news, display_store, display_welcome, handle_buy_inventory, handle_foreign_trade, handle_manage_factories,
     handle_manage_investments, handle_manage_loans, handle_manage_staff, handle_manage_stores,
-    handle_set_prices, MenuChoice,
+    handle_set_prices, handle_tax_summary, MenuChoice,
 };
 
 fn main() {
@@ -53,6 +53,15 @@ fn main() {
             MenuChoice::ManageInvestments => {
                 handle_manage_investments(&mut game);
             }
+            MenuChoice::ReadNews => {
+                display_news(&game);
+            }
+            MenuChoice::TaxSummary => {
+                handle_tax_summary(&mut game);
+            }
+            MenuChoice::ForeignTrade => {
+                handle_foreign_trade(&mut game);
+            }
             MenuChoice::Quit => {
                 display_goodbye(&game);
                 break;
@@ -0,0 +1,148 @@
+/// The standing instructions a store manager follows each day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ManagerMandate {
+    /// Target markup over wholesale (e.g., 50.0 for 50%)
+    pub target_margin: f64,
+    /// Maximum amount the manager may spend on restocking per day
+    pub restock_budget: f64,
+    /// Number of employees the manager keeps on staff (0-3)
+    pub staffing_level: usize,
+}
+
+impl ManagerMandate {
+    /// Creates a new mandate, clamping values to sensible ranges
+    pub fn new(target_margin: f64, restock_budget: f64, staffing_level: usize) -> Self {
+        ManagerMandate {
+            target_margin: target_margin.max(0.0),
+            restock_budget: restock_budget.max(0.0),
+            staffing_level: staffing_level.min(3),
+        }
+    }
+
+    /// Returns the retail price the manager wants for a given wholesale price
+    pub fn target_price(&self, wholesale: f64) -> f64 {
+        wholesale * (1.0 + self.target_margin / 100.0)
+    }
+}
+
+impl Default for ManagerMandate {
+    fn default() -> Self {
+        ManagerMandate::new(50.0, 300.0, 1)
+    }
+}
+
+/// Represents a manager who runs a store automatically
+#[derive(Debug, Clone)]
+pub struct StoreManager {
+    pub name: String,
+    pub salary: f64,
+    pub mandate: ManagerMandate,
+}
+
+impl StoreManager {
+    /// Days of expected sales the manager keeps on the shelves
+    pub const DAYS_OF_COVER: u32 = 3;
+    /// Daily salary at the starting price level
+    pub const BASE_SALARY: f64 = 80.0;
+
+    /// Creates a new manager with the default salary
+    pub fn new(name: &str, mandate: ManagerMandate) -> Self {
+        StoreManager {
+            name: name.to_string(),
+            salary: Self::BASE_SALARY, // $80/day
+            mandate,
+        }
+    }
+
+    /// Returns how many units to order to cover expected sales
+    pub fn restock_quantity(expected_daily_sales: u32, on_hand: u32) -> u32 {
+        let target = expected_daily_sales.max(1) * Self::DAYS_OF_COVER;
+        target.saturating_sub(on_hand)
+    }
+
+    /// Returns the change in headcount needed to meet the mandate
+    /// (positive = hire, negative = fire)
+    pub fn staffing_change(&self, current_employees: usize) -> i32 {
+        self.mandate.staffing_level as i32 - current_employees as i32
+    }
+}
+
+/// Summary of the decisions a manager made during a day
+#[derive(Debug, Clone)]
+pub struct ManagerReport {
+    pub store_name: String,
+    pub manager_name: String,
+    /// (product_name, quantity, cost)
+    pub restocked: Vec<(String, u32, f64)>,
+    /// (product_name, old_price, new_price)
+    pub price_changes: Vec<(String, f64, f64)>,
+    pub hired: Vec<String>,
+    pub fired: Vec<String>,
+    /// Products the manager could not restock (budget or cash ran out)
+    pub skipped: Vec<String>,
+}
+
+impl ManagerReport {
+    pub fn new(store_name: &str, manager_name: &str) -> Self {
+        ManagerReport {
+            store_name: store_name.to_string(),
+            manager_name: manager_name.to_string(),
+            restocked: Vec::new(),
+            price_changes: Vec::new(),
+            hired: Vec::new(),
+            fired: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Returns total spent on restocking
+    pub fn total_spent(&self) -> f64 {
+        self.restocked.iter().map(|(_, _, cost)| cost).sum()
+    }
+
+    /// Returns true if the manager did nothing today
+    pub fn is_empty(&self) -> bool {
+        self.restocked.is_empty()
+            && self.price_changes.is_empty()
+            && self.hired.is_empty()
+            && self.fired.is_empty()
+            && self.skipped.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mandate_clamps_values() {
+        let mandate = ManagerMandate::new(-10.0, -50.0, 7);
+        assert_eq!(mandate.target_margin, 0.0);
+        assert_eq!(mandate.restock_budget, 0.0);
+        assert_eq!(mandate.staffing_level, 3);
+    }
+
+    #[test]
+    fn test_target_price() {
+        let mandate = ManagerMandate::new(50.0, 100.0, 1);
+        assert!((mandate.target_price(10.0) - 15.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_restock_quantity() {
+        // 10/day for 3 days = 30 units, already have 12
+        assert_eq!(StoreManager::restock_quantity(10, 12), 18);
+        // Already well stocked
+        assert_eq!(StoreManager::restock_quantity(10, 50), 0);
+        // Slow sellers still keep a minimum on the shelf
+        assert_eq!(StoreManager::restock_quantity(0, 0), 3);
+    }
+
+    #[test]
+    fn test_staffing_change() {
+        let manager = StoreManager::new("Pat", ManagerMandate::new(50.0, 100.0, 2));
+        assert_eq!(manager.staffing_change(0), 2);
+        assert_eq!(manager.staffing_change(3), -1);
+        assert_eq!(manager.staffing_change(2), 0);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::product::Category;
+
+/// Advertising channel used by a campaign
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CampaignChannel {
+    /// Cheap, local reach
+    Flyers,
+    /// Moderate reach
+    Radio,
+    /// Expensive, strongest reach
+    Tv,
+    /// Good value, moderate reach
+    Online,
+}
+
+impl CampaignChannel {
+    /// Returns all channels
+    pub fn all() -> Vec<CampaignChannel> {
+        vec![
+            CampaignChannel::Flyers,
+            CampaignChannel::Radio,
+            CampaignChannel::Tv,
+            CampaignChannel::Online,
+        ]
+    }
+
+    /// Returns the cost per day of running this channel
+    pub fn daily_cost(&self) -> f64 {
+        match self {
+            CampaignChannel::Flyers => 40.0,
+            CampaignChannel::Radio => 120.0,
+            CampaignChannel::Tv => 350.0,
+            CampaignChannel::Online => 90.0,
+        }
+    }
+
+    /// Returns the advertising intensity this channel adds while active
+    pub fn reach(&self) -> f64 {
+        match self {
+            CampaignChannel::Flyers => 0.15,
+            CampaignChannel::Radio => 0.35,
+            CampaignChannel::Tv => 0.80,
+            CampaignChannel::Online => 0.30,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CampaignChannel::Flyers => "Flyers",
+            CampaignChannel::Radio => "Radio",
+            CampaignChannel::Tv => "TV",
+            CampaignChannel::Online => "Online",
+        }
+    }
+}
+
+/// What a campaign is promoting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CampaignTarget {
+    /// A single store (by store ID)
+    Store(u32),
+    /// A product category across all stores
+    Category(Category),
+    /// The whole brand (all stores, all products)
+    Brand,
+}
+
+impl CampaignTarget {
+    /// Returns the cost multiplier for the breadth of the target
+    pub fn cost_multiplier(&self) -> f64 {
+        match self {
+            CampaignTarget::Store(_) => 1.0,
+            CampaignTarget::Category(_) => 1.5,
+            CampaignTarget::Brand => 2.5,
+        }
+    }
+}
+
+/// Represents an advertising campaign
+#[derive(Debug, Clone)]
+pub struct MarketingCampaign {
+    pub id: u32,
+    pub channel: CampaignChannel,
+    pub target: CampaignTarget,
+    pub days_remaining: u32,
+}
+
+impl MarketingCampaign {
+    /// Maximum customer boost from advertising (+50%)
+    pub const MAX_BOOST: f64 = 0.5;
+    /// Shortest campaign that can be booked
+    pub const MIN_DAYS: u32 = 3;
+    /// Longest campaign that can be booked
+    pub const MAX_DAYS: u32 = 30;
+
+    pub fn new(id: u32, channel: CampaignChannel, target: CampaignTarget, days: u32) -> Self {
+        MarketingCampaign {
+            id,
+            channel,
+            target,
+            days_remaining: days,
+        }
+    }
+
+    /// Returns the total upfront cost of a campaign
+    pub fn cost(channel: CampaignChannel, target: CampaignTarget, days: u32) -> f64 {
+        channel.daily_cost() * target.cost_multiplier() * days as f64
+    }
+
+    /// Converts accumulated advertising intensity into a boost with diminishing returns
+    /// Each extra campaign adds less than the last, capped at MAX_BOOST
+    pub fn boost_from_intensity(intensity: f64) -> f64 {
+        Self::MAX_BOOST * (1.0 - (-intensity.max(0.0)).exp())
+    }
+
+    /// Returns the intensity this campaign contributes to a store's traffic
+    pub fn store_intensity(&self, store_id: u32) -> f64 {
+        match self.target {
+            CampaignTarget::Store(id) if id == store_id => self.channel.reach(),
+            CampaignTarget::Brand => self.channel.reach(),
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the intensity this campaign contributes to a category's demand
+    pub fn category_intensity(&self, category: Category) -> f64 {
+        match self.target {
+            CampaignTarget::Category(c) if c == category => self.channel.reach(),
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the intensity this campaign contributes to brand awareness (market share)
+    pub fn brand_intensity(&self) -> f64 {
+        match self.target {
+            CampaignTarget::Brand => self.channel.reach(),
+            CampaignTarget::Category(_) => self.channel.reach() * 0.5,
+            CampaignTarget::Store(_) => self.channel.reach() * 0.25,
+        }
+    }
+
+    /// Counts down one day, returns true if the campaign has ended
+    pub fn tick(&mut self) -> bool {
+        self.days_remaining = self.days_remaining.saturating_sub(1);
+        self.days_remaining == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_campaign_cost() {
+        let cost = MarketingCampaign::cost(CampaignChannel::Radio, CampaignTarget::Brand, 10);
+        assert!((cost - 120.0 * 2.5 * 10.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_diminishing_returns() {
+        let one = MarketingCampaign::boost_from_intensity(0.5);
+        let two = MarketingCampaign::boost_from_intensity(1.0);
+        assert!(two > one);
+        assert!(two - one < one); // Second dose adds less than the first
+        assert!(MarketingCampaign::boost_from_intensity(100.0) <= MarketingCampaign::MAX_BOOST);
+        assert_eq!(MarketingCampaign::boost_from_intensity(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_target_intensity() {
+        let campaign = MarketingCampaign::new(1, CampaignChannel::Tv, CampaignTarget::Store(2), 5);
+        assert_eq!(campaign.store_intensity(2), 0.8);
+        assert_eq!(campaign.store_intensity(1), 0.0);
+        assert_eq!(campaign.category_intensity(Category::Food), 0.0);
+
+        let food = MarketingCampaign::new(2, CampaignChannel::Flyers, CampaignTarget::Category(Category::Food), 5);
+        assert_eq!(food.category_intensity(Category::Food), 0.15);
+        assert_eq!(food.store_intensity(2), 0.0);
+    }
+
+    #[test]
+    fn test_tick() {
+        let mut campaign = MarketingCampaign::new(1, CampaignChannel::Online, CampaignTarget::Brand, 2);
+        assert!(!campaign.tick());
+        assert!(campaign.tick());
+    }
+}
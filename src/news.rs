@@ -0,0 +1,129 @@
+/// Section of the paper a headline runs in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NewsCategory {
+    /// Leading indicators about where the economy is heading
+    Economy,
+    /// Rumors and reports about macro events
+    Events,
+    /// Competitor moves
+    Business,
+}
+
+impl NewsCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NewsCategory::Economy => "ECONOMY",
+            NewsCategory::Events => "WORLD",
+            NewsCategory::Business => "BUSINESS",
+        }
+    }
+}
+
+/// A single news headline
+#[derive(Debug, Clone)]
+pub struct Headline {
+    pub day: u32,
+    pub category: NewsCategory,
+    pub text: String,
+}
+
+impl Headline {
+    /// Returns an economy headline for a leading indicator reading (-1.0 to 1.0)
+    /// `pick` (0.0 to 1.0) chooses between equivalent phrasings
+    pub fn economy(reading: f64, pick: f64) -> &'static str {
+        let options: &[&'static str] = match reading {
+            r if r > 0.6 => &[
+                "Consumer confidence hits multi-year high",
+                "Factories report order books overflowing",
+                "Economists scramble to raise growth forecasts",
+            ],
+            r if r > 0.2 => &[
+                "Retail spending edges up for another week",
+                "Hiring picks up in the service sector",
+                "Business sentiment quietly improves",
+            ],
+            r if r >= -0.2 => &[
+                "Analysts split on the economic outlook",
+                "Markets drift sideways on mixed data",
+                "Economy shows no clear direction",
+            ],
+            r if r >= -0.6 => &[
+                "Shoppers tighten belts as prices bite",
+                "Layoff notices tick upward",
+                "Manufacturers report softening orders",
+            ],
+            _ => &[
+                "Consumer confidence plunges to record low",
+                "Economists warn of a sharp downturn ahead",
+                "Credit markets freeze as lenders pull back",
+            ],
+        };
+        let index = (pick * options.len() as f64) as usize;
+        options[index.min(options.len() - 1)]
+    }
+}
+
+/// Rolling archive of daily headlines
+#[derive(Debug, Clone, Default)]
+pub struct NewsFeed {
+    pub headlines: Vec<Headline>,
+}
+
+impl NewsFeed {
+    /// Headlines kept in the archive
+    pub const MAX_HEADLINES: usize = 60;
+
+    pub fn new() -> Self {
+        NewsFeed {
+            headlines: Vec::new(),
+        }
+    }
+
+    /// Publishes a headline, dropping the oldest ones past the archive limit
+    pub fn publish(&mut self, day: u32, category: NewsCategory, text: &str) {
+        self.headlines.push(Headline {
+            day,
+            category,
+            text: text.to_string(),
+        });
+        if self.headlines.len() > Self::MAX_HEADLINES {
+            let excess = self.headlines.len() - Self::MAX_HEADLINES;
+            self.headlines.drain(..excess);
+        }
+    }
+
+    /// Returns the headlines published on a given day
+    pub fn for_day(&self, day: u32) -> Vec<&Headline> {
+        self.headlines.iter().filter(|h| h.day == day).collect()
+    }
+
+    /// Returns the most recent headlines, newest first
+    pub fn recent(&self, count: usize) -> Vec<&Headline> {
+        self.headlines.iter().rev().take(count).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_economy_headline_follows_reading() {
+        assert!(Headline::economy(0.9, 0.0).contains("high"));
+        assert!(Headline::economy(-0.9, 0.0).contains("record low"));
+        // Any pick in range yields a headline
+        assert!(!Headline::economy(0.0, 0.999).is_empty());
+        assert!(!Headline::economy(0.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_feed_keeps_recent_headlines() {
+        let mut feed = NewsFeed::new();
+        for day in 1..=(NewsFeed::MAX_HEADLINES as u32 + 5) {
+            feed.publish(day, NewsCategory::Economy, "Markets drift sideways");
+        }
+        assert_eq!(feed.headlines.len(), NewsFeed::MAX_HEADLINES);
+        assert_eq!(feed.recent(1)[0].day, NewsFeed::MAX_HEADLINES as u32 + 5);
+        assert!(feed.for_day(1).is_empty());
+    }
+}
@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use crate::factory::Factory;
+use crate::recipe::Recipe;
+
+/// Units of a product the player wants made
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProductionTarget {
+    pub product_id: u32,
+    pub quantity: u32,
+}
+
+impl ProductionTarget {
+    pub fn new(product_id: u32, quantity: u32) -> Self {
+        ProductionTarget { product_id, quantity }
+    }
+}
+
+/// Raw materials a factory needs for its share of the plan
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaterialRequirement {
+    pub factory_idx: usize,
+    pub product_id: u32,
+    pub required: u32,
+    pub on_hand: u32,
+    pub to_buy: u32,
+    pub unit_cost: f64,
+}
+
+impl MaterialRequirement {
+    /// Returns the cost of the units still to buy
+    pub fn cost(&self) -> f64 {
+        self.to_buy as f64 * self.unit_cost
+    }
+}
+
+/// One production job placed on a factory's calendar (days counted from today)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledJob {
+    pub factory_idx: usize,
+    pub recipe_id: u32,
+    pub start_day: u32,
+    pub finish_day: u32,
+}
+
+/// Something that stops the plan being met
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shortfall {
+    /// Nothing the factories can make produces this product
+    NoRecipe { product_id: u32, quantity: u32 },
+    /// Units that can't be finished before the deadline
+    Capacity { product_id: u32, quantity: u32 },
+    /// Cash missing to buy the materials
+    Cash(f64),
+}
+
+/// A material requirements plan: what to buy, where and when to make it,
+/// and what can't be done in time
+#[derive(Debug, Clone)]
+pub struct ProductionPlan {
+    pub deadline: u32,
+    pub requirements: Vec<MaterialRequirement>,
+    pub jobs: Vec<ScheduledJob>,
+    pub shortfalls: Vec<Shortfall>,
+}
+
+impl ProductionPlan {
+    /// Returns the total cost of materials still to buy
+    pub fn purchase_cost(&self) -> f64 {
+        self.requirements.iter().map(|r| r.cost()).sum()
+    }
+
+    /// Returns production slot-days the plan uses
+    pub fn slot_days(&self) -> u32 {
+        self.jobs.iter().map(|j| j.finish_day - j.start_day).sum()
+    }
+
+    /// Returns the day the last job finishes
+    pub fn finish_day(&self) -> u32 {
+        self.jobs.iter().map(|j| j.finish_day).max().unwrap_or(0)
+    }
+
+    /// Returns true if every target can be met
+    pub fn is_feasible(&self) -> bool {
+        self.shortfalls.is_empty()
+    }
+}
+
+/// Returns the slot-days one batch of a recipe takes, including every component made for it
+fn tree_slot_days(recipe: &Recipe, recipes: &[Recipe]) -> u32 {
+    let components: u32 = recipe
+        .ingredients
+        .iter()
+        .filter_map(|ing| {
+            let component = Recipe::producing(recipes, ing.product_id)?;
+            let batches = ing.quantity.div_ceil(component.output_quantity.max(1));
+            Some(batches * tree_slot_days(component, recipes))
+        })
+        .sum();
+    recipe.production_days + components
+}
+
+/// Explodes the products wanted at one factory level by level: (recipe_id, batches) per
+/// level, top level first. Components already in the factory's stock are netted off.
+fn explode(
+    mut needs: BTreeMap<u32, u32>,
+    mut components_in_stock: BTreeMap<u32, u32>,
+    recipes: &[Recipe],
+) -> Vec<BTreeMap<u32, u32>> {
+    let mut levels = Vec::new();
+    while !needs.is_empty() {
+        let mut level = BTreeMap::new();
+        let mut next_needs = BTreeMap::new();
+        for (product_id, quantity) in needs {
+            // Bought, not made
+            let recipe = match Recipe::producing(recipes, product_id) {
+                Some(r) => r,
+                None => continue,
+            };
+            let mut quantity = quantity;
+            if recipe.intermediate
+                && let Some(stock) = components_in_stock.get_mut(&product_id)
+            {
+                let used = quantity.min(*stock);
+                *stock -= used;
+                quantity -= used;
+            }
+            if quantity == 0 {
+                continue;
+            }
+            let batches = quantity.div_ceil(recipe.output_quantity.max(1));
+            *level.entry(recipe.id).or_insert(0) += batches;
+            for ing in &recipe.ingredients {
+                if Recipe::producing(recipes, ing.product_id).is_some() {
+                    *next_needs.entry(ing.product_id).or_insert(0) += ing.quantity * batches;
+                }
+            }
+        }
+        levels.push(level);
+        needs = next_needs;
+    }
+    levels
+}
+
+/// Works out the jobs and materials needed to make the targets within the deadline
+/// Each batch of a target is given to one factory, along with every component it needs
+/// (a factory can only assemble from its own stock), choosing the least loaded factory.
+/// Within a factory, components are made from their own recipes (netting off components
+/// already in stock), ingredients with no recipe are bought, and jobs are packed into
+/// the earliest free slot.
+pub fn plan_production(
+    targets: &[ProductionTarget],
+    deadline: u32,
+    factories: &[Factory],
+    recipes: &[Recipe],
+    unit_cost: impl Fn(usize, u32) -> Option<f64>,
+) -> ProductionPlan {
+    let mut shortfalls = Vec::new();
+
+    let mut wanted: BTreeMap<u32, u32> = BTreeMap::new();
+    for target in targets {
+        *wanted.entry(target.product_id).or_insert(0) += target.quantity;
+    }
+
+    // Hand out batches to the factory with the fewest slot-days queued per slot
+    let mut load: Vec<u32> = factories
+        .iter()
+        .map(|f| f.production_queue.iter().map(|j| j.days_remaining).sum())
+        .collect();
+    let mut needs_by_factory: Vec<BTreeMap<u32, u32>> = vec![BTreeMap::new(); factories.len()];
+    for (&product_id, &quantity) in &wanted {
+        let recipe = match Recipe::producing(recipes, product_id) {
+            Some(r) => r,
+            None => {
+                shortfalls.push(Shortfall::NoRecipe { product_id, quantity });
+                continue;
+            }
+        };
+        let batch_days = tree_slot_days(recipe, recipes);
+        let mut remaining = quantity;
+        while remaining > 0 {
+            let per_slot = |idx: usize| (load[idx] + batch_days) as f64 / factories[idx].production_slots().max(1) as f64;
+            let factory_idx = match (0..factories.len()).min_by(|&a, &b| per_slot(a).total_cmp(&per_slot(b))) {
+                Some(idx) => idx,
+                None => break,
+            };
+            let units = remaining.min(recipe.output_quantity.max(1));
+            *needs_by_factory[factory_idx].entry(product_id).or_insert(0) += units;
+            load[factory_idx] += batch_days;
+            remaining -= units;
+        }
+        if remaining > 0 {
+            shortfalls.push(Shortfall::Capacity { product_id, quantity: remaining });
+        }
+    }
+
+    let mut jobs = Vec::new();
+    let mut unscheduled: BTreeMap<u32, u32> = BTreeMap::new();
+    for (factory_idx, needs) in needs_by_factory.into_iter().enumerate() {
+        let factory = &factories[factory_idx];
+        let components = factory.components.iter().map(|(id, qty)| (*id, *qty)).collect();
+        let levels = explode(needs, components, recipes);
+
+        // Each slot is free from the day its current job finishes
+        let mut slots: Vec<u32> = factory.production_queue.iter().map(|j| j.days_remaining).collect();
+        slots.resize(factory.production_slots().max(slots.len()), 0);
+
+        // Schedule the deepest components first; each level starts once the one below is done
+        let mut ready_day = 0;
+        for level in levels.iter().rev() {
+            let mut level_finish = ready_day;
+            for (&recipe_id, &batches) in level {
+                let recipe = match recipes.iter().find(|r| r.id == recipe_id) {
+                    Some(r) => r,
+                    None => continue,
+                };
+                for _ in 0..batches {
+                    let slot = slots.iter_mut().min_by_key(|free_at| (**free_at).max(ready_day));
+                    match slot {
+                        Some(free_at) if (*free_at).max(ready_day) + recipe.production_days <= deadline => {
+                            let start_day = (*free_at).max(ready_day);
+                            let finish_day = start_day + recipe.production_days;
+                            *free_at = finish_day;
+                            level_finish = level_finish.max(finish_day);
+                            jobs.push(ScheduledJob {
+                                factory_idx,
+                                recipe_id,
+                                start_day,
+                                finish_day,
+                            });
+                        }
+                        _ => {
+                            *unscheduled.entry(recipe.output_product_id).or_insert(0) += recipe.output_quantity;
+                        }
+                    }
+                }
+            }
+            ready_day = level_finish;
+        }
+    }
+    shortfalls.extend(
+        unscheduled
+            .into_iter()
+            .map(|(product_id, quantity)| Shortfall::Capacity { product_id, quantity }),
+    );
+
+    // Bought materials go to the factory that runs the job
+    let mut required: BTreeMap<(usize, u32), u32> = BTreeMap::new();
+    for job in &jobs {
+        let recipe = match recipes.iter().find(|r| r.id == job.recipe_id) {
+            Some(r) => r,
+            None => continue,
+        };
+        for ing in &recipe.ingredients {
+            if Recipe::producing(recipes, ing.product_id).is_none() {
+                *required.entry((job.factory_idx, ing.product_id)).or_insert(0) += ing.quantity;
+            }
+        }
+    }
+    let requirements = required
+        .into_iter()
+        .map(|((factory_idx, product_id), required)| {
+            let on_hand = factories[factory_idx].get_raw_material(product_id);
+            MaterialRequirement {
+                factory_idx,
+                product_id,
+                required,
+                on_hand,
+                to_buy: required.saturating_sub(on_hand),
+                unit_cost: unit_cost(factory_idx, product_id).unwrap_or(0.0),
+            }
+        })
+        .collect();
+
+    ProductionPlan {
+        deadline,
+        requirements,
+        jobs,
+        shortfalls,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quality::{MaterialGrade, QualityTier};
+
+    #[test]
+    fn test_plan_explodes_components_and_schedules() {
+        let recipes = Recipe::default_recipes();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory.add_raw_material(12, 1, 1.0, MaterialGrade::Standard);
+        factory.add_components(22, 1, 3.0, QualityTier::DEFAULT_SCORE);
+
+        // Two laptops: 4 boards (1 in stock), 2 frames, then 2 laptop jobs of 2 days
+        let targets = [ProductionTarget::new(21, 2)];
+        let plan = plan_production(&targets, 10, &[factory], &recipes, |_, _| Some(1.0));
+        assert!(plan.is_feasible());
+
+        let steel = plan.requirements.iter().find(|r| r.product_id == 12).unwrap();
+        assert_eq!((steel.required, steel.on_hand, steel.to_buy), (4, 1, 3));
+        let electronics = plan.requirements.iter().find(|r| r.product_id == 15).unwrap();
+        assert_eq!(electronics.required, 6);
+
+        // 5 one-day component jobs on 2 slots take 3 days, then the laptops take 2
+        assert_eq!(plan.finish_day(), 5);
+        assert_eq!(plan.slot_days(), 5 + 4);
+    }
+
+    #[test]
+    fn test_plan_keeps_component_trees_on_one_factory() {
+        let recipes = Recipe::default_recipes();
+        let mut stocked = Factory::new(1, "Stocked", 1);
+        stocked.add_components(22, 1, 3.0, QualityTier::DEFAULT_SCORE);
+        let empty = Factory::new(2, "Empty", 1);
+
+        // Two smartphones (board + plastic each): one per factory
+        let targets = [ProductionTarget::new(20, 2)];
+        let plan = plan_production(&targets, 10, &[stocked, empty], &recipes, |_, _| Some(1.0));
+        assert!(plan.is_feasible());
+
+        let jobs_at = |factory_idx: usize, recipe_id: u32| {
+            plan.jobs
+                .iter()
+                .filter(|j| j.factory_idx == factory_idx && j.recipe_id == recipe_id)
+                .count()
+        };
+        let board = Recipe::producing(&recipes, 22).unwrap().id;
+        let phone = Recipe::producing(&recipes, 20).unwrap().id;
+        // The stocked factory uses its own board; the other makes one for its phone
+        assert_eq!((jobs_at(0, board), jobs_at(0, phone)), (0, 1));
+        assert_eq!((jobs_at(1, board), jobs_at(1, phone)), (1, 1));
+        let board_finish = plan.jobs.iter().find(|j| j.recipe_id == board).unwrap().finish_day;
+        let phone_start = plan.jobs.iter().find(|j| j.factory_idx == 1 && j.recipe_id == phone).unwrap().start_day;
+        assert!(phone_start >= board_finish);
+
+        // Board materials are only needed where the board is made
+        assert!(plan.requirements.iter().all(|r| r.product_id != 15 || r.factory_idx == 1));
+    }
+
+    #[test]
+    fn test_plan_reports_shortfalls() {
+        let recipes = Recipe::default_recipes();
+        let factory = Factory::new(1, "Plant", 1);
+        let targets = [ProductionTarget::new(16, 10), ProductionTarget::new(1, 5)];
+        let plan = plan_production(&targets, 2, &[factory], &recipes, |_, _| Some(1.0));
+
+        // 2 slots x 2 days of one-day chair jobs
+        assert!(plan.shortfalls.contains(&Shortfall::Capacity { product_id: 16, quantity: 6 }));
+        assert!(plan.shortfalls.contains(&Shortfall::NoRecipe { product_id: 1, quantity: 5 }));
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 use crate::factory::Factory;
 use crate::loan::Loan;
+use crate::marketing::MarketingCampaign;
+use crate::product::Category;
+use crate::region::Region;
 use crate::stock::StockHolding;
 use crate::store::Store;
 
@@ -13,23 +16,28 @@ pub struct Player {
     pub loans: Vec<Loan>,
     /// Stock portfolio: stock_id -> holding
     pub portfolio: HashMap<u32, StockHolding>,
+    /// Active advertising campaigns
+    pub campaigns: Vec<MarketingCampaign>,
     next_store_id: u32,
     next_factory_id: u32,
     next_loan_id: u32,
+    next_campaign_id: u32,
 }
 
 impl Player {
-    /// Creates a new player with starting cash and one store
+    /// Creates a new player with starting cash and one store in the home region
     pub fn new(starting_cash: f64, store_name: &str) -> Self {
         Player {
             cash: starting_cash,
-            stores: vec![Store::new(1, store_name)],
+            stores: vec![Store::new(1, store_name, Region::HOME_ID)],
             factories: Vec::new(),
             loans: Vec::new(),
             portfolio: HashMap::new(),
+            campaigns: Vec::new(),
             next_store_id: 2,
             next_factory_id: 1,
             next_loan_id: 1,
+            next_campaign_id: 1,
         }
     }
 
@@ -54,8 +62,8 @@ impl Player {
     }
 
     /// Adds a new store to the player's portfolio
-    pub fn add_store(&mut self, name: &str) {
-        let store = Store::new(self.next_store_id, name);
+    pub fn add_store(&mut self, name: &str, region_id: u32) {
+        let store = Store::new(self.next_store_id, name, region_id);
         self.stores.push(store);
         self.next_store_id += 1;
     }
@@ -71,8 +79,8 @@ impl Player {
     }
 
     /// Adds a new factory to the player's portfolio
-    pub fn add_factory(&mut self, name: &str) {
-        let factory = Factory::new(self.next_factory_id, name);
+    pub fn add_factory(&mut self, name: &str, region_id: u32) {
+        let factory = Factory::new(self.next_factory_id, name, region_id);
         self.factories.push(factory);
         self.next_factory_id += 1;
     }
@@ -180,6 +188,51 @@ impl Player {
         self.next_loan_id
     }
 
+    // ==================== MARKETING METHODS ====================
+
+    /// Adds a new campaign, assigning it an ID. Returns the ID.
+    pub fn add_campaign(&mut self, mut campaign: MarketingCampaign) -> u32 {
+        campaign.id = self.next_campaign_id;
+        self.next_campaign_id += 1;
+        let id = campaign.id;
+        self.campaigns.push(campaign);
+        id
+    }
+
+    /// Returns the customer multiplier from campaigns targeting a store (or the brand)
+    pub fn store_marketing_multiplier(&self, store_id: u32) -> f64 {
+        let intensity: f64 = self.campaigns.iter().map(|c| c.store_intensity(store_id)).sum();
+        1.0 + MarketingCampaign::boost_from_intensity(intensity)
+    }
+
+    /// Returns the demand multiplier from campaigns targeting a category
+    pub fn category_marketing_multiplier(&self, category: Category) -> f64 {
+        let intensity: f64 = self.campaigns.iter().map(|c| c.category_intensity(category)).sum();
+        1.0 + MarketingCampaign::boost_from_intensity(intensity)
+    }
+
+    /// Returns the brand awareness multiplier used for market share
+    pub fn brand_marketing_multiplier(&self) -> f64 {
+        let intensity: f64 = self.campaigns.iter().map(|c| c.brand_intensity()).sum();
+        1.0 + MarketingCampaign::boost_from_intensity(intensity)
+    }
+
+    /// Counts down all campaigns and removes finished ones
+    /// Returns the campaigns that ended
+    pub fn advance_campaigns(&mut self) -> Vec<MarketingCampaign> {
+        let mut ended = Vec::new();
+        let mut still_running = Vec::new();
+        for mut campaign in self.campaigns.drain(..) {
+            if campaign.tick() {
+                ended.push(campaign);
+            } else {
+                still_running.push(campaign);
+            }
+        }
+        self.campaigns = still_running;
+        ended
+    }
+
     // ==================== STOCK PORTFOLIO METHODS ====================
 
     /// Buys shares of a stock
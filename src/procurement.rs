@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use crate::factory::{Factory, OrderTarget};
+use crate::recipe::Recipe;
+
+/// A rule for buying raw materials automatically
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProcurementRule {
+    /// Keep at least this many units of a raw material on hand
+    KeepStocked { product_id: u32, quantity: u32 },
+    /// Buy what the factory's standing orders need for the next few days
+    CoverOrders { days: u32 },
+}
+
+/// A factory's standing instructions for buying raw materials each day
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcurementPolicy {
+    pub rules: Vec<ProcurementRule>,
+    /// Maximum amount spent on raw materials per day
+    pub daily_budget: f64,
+}
+
+impl ProcurementPolicy {
+    /// Default daily spending limit
+    pub const DEFAULT_BUDGET: f64 = 500.0;
+    /// Maximum rules per factory
+    pub const MAX_RULES: usize = 8;
+
+    pub fn new(daily_budget: f64) -> Self {
+        ProcurementPolicy {
+            rules: Vec::new(),
+            daily_budget: daily_budget.max(0.0),
+        }
+    }
+
+    /// Adds a rule (replacing a stock rule for the same material, or the existing cover rule)
+    pub fn add_rule(&mut self, rule: ProcurementRule) -> Result<(), String> {
+        match rule {
+            ProcurementRule::KeepStocked { quantity: 0, .. } => {
+                return Err("Stock level must be at least 1".to_string());
+            }
+            ProcurementRule::CoverOrders { days: 0 } => {
+                return Err("Must cover at least 1 day".to_string());
+            }
+            _ => {}
+        }
+        let same = |existing: &ProcurementRule| match (existing, &rule) {
+            (
+                ProcurementRule::KeepStocked { product_id: a, .. },
+                ProcurementRule::KeepStocked { product_id: b, .. },
+            ) => a == b,
+            (ProcurementRule::CoverOrders { .. }, ProcurementRule::CoverOrders { .. }) => true,
+            _ => false,
+        };
+        if let Some(existing) = self.rules.iter_mut().find(|r| same(r)) {
+            *existing = rule;
+            return Ok(());
+        }
+        if self.rules.len() >= Self::MAX_RULES {
+            return Err(format!("Maximum of {} procurement rules per factory", Self::MAX_RULES));
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Removes a rule by index
+    pub fn remove_rule(&mut self, index: usize) -> Result<ProcurementRule, String> {
+        if index >= self.rules.len() {
+            return Err("Invalid procurement rule index".to_string());
+        }
+        Ok(self.rules.remove(index))
+    }
+
+    /// Returns true if there is nothing to buy automatically
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Returns the units of each ingredient the factory should hold (product_id -> quantity)
+    /// Ingredients are included whether or not they can be bought; the caller decides
+    pub fn targets(&self, factory: &Factory, recipes: &[Recipe]) -> BTreeMap<u32, u32> {
+        let mut targets = BTreeMap::new();
+        for rule in &self.rules {
+            match *rule {
+                ProcurementRule::KeepStocked { product_id, quantity } => {
+                    let target = targets.entry(product_id).or_insert(0);
+                    *target = quantity.max(*target);
+                }
+                ProcurementRule::CoverOrders { days } => {
+                    let mut needed: BTreeMap<u32, u32> = BTreeMap::new();
+                    for order in &factory.standing_orders {
+                        let recipe = match recipes.iter().find(|r| r.id == order.recipe_id) {
+                            Some(r) => r,
+                            None => continue,
+                        };
+                        let jobs = planned_jobs(order.target, recipe, days, factory.production_slots());
+                        for ing in &recipe.ingredients {
+                            *needed.entry(ing.product_id).or_insert(0) += jobs * ing.quantity;
+                        }
+                    }
+                    for (product_id, quantity) in needed {
+                        let target = targets.entry(product_id).or_insert(0);
+                        *target = quantity.max(*target);
+                    }
+                }
+            }
+        }
+        targets
+    }
+}
+
+impl Default for ProcurementPolicy {
+    fn default() -> Self {
+        ProcurementPolicy::new(Self::DEFAULT_BUDGET)
+    }
+}
+
+/// Estimates the jobs a standing order will start over the coming days
+/// (a daily count is limited by production slots; a stock level needs one full refill)
+fn planned_jobs(target: OrderTarget, recipe: &Recipe, days: u32, slots: usize) -> u32 {
+    match target {
+        OrderTarget::DailyCount(count) => count.min(slots as u32) * days,
+        OrderTarget::StockLevel(level) => level.div_ceil(recipe.output_quantity.max(1)),
+    }
+}
+
+/// Summary of the raw materials a factory bought automatically during a day
+#[derive(Debug, Clone)]
+pub struct ProcurementReport {
+    pub factory_name: String,
+    /// (product_name, quantity, cost)
+    pub purchased: Vec<(String, u32, f64)>,
+    /// Materials that could not be bought (budget or cash ran out)
+    pub skipped: Vec<String>,
+}
+
+impl ProcurementReport {
+    pub fn new(factory_name: &str) -> Self {
+        ProcurementReport {
+            factory_name: factory_name.to_string(),
+            purchased: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Returns total spent on raw materials
+    pub fn total_spent(&self) -> f64 {
+        self.purchased.iter().map(|(_, _, cost)| cost).sum()
+    }
+
+    /// Returns true if nothing was bought or skipped
+    pub fn is_empty(&self) -> bool {
+        self.purchased.is_empty() && self.skipped.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factory::StandingOrder;
+
+    #[test]
+    fn test_cover_orders_targets_ingredients() {
+        let recipes = Recipe::default_recipes();
+        let board = Recipe::producing(&recipes, 22).unwrap();
+        let mut factory = Factory::new(1, "Plant", 1);
+        factory
+            .add_standing_order(StandingOrder::new(board.id, OrderTarget::DailyCount(1)))
+            .unwrap();
+
+        let mut policy = ProcurementPolicy::default();
+        policy.add_rule(ProcurementRule::CoverOrders { days: 3 }).unwrap();
+        policy
+            .add_rule(ProcurementRule::KeepStocked { product_id: 14, quantity: 10 })
+            .unwrap();
+
+        let targets = policy.targets(&factory, &recipes);
+        assert_eq!(targets.get(&15), Some(&6));
+        // The larger of the two rules wins
+        assert_eq!(targets.get(&14), Some(&10));
+    }
+
+    #[test]
+    fn test_rules_replace_same_material() {
+        let mut policy = ProcurementPolicy::default();
+        policy
+            .add_rule(ProcurementRule::KeepStocked { product_id: 12, quantity: 5 })
+            .unwrap();
+        policy
+            .add_rule(ProcurementRule::KeepStocked { product_id: 12, quantity: 8 })
+            .unwrap();
+        assert_eq!(policy.rules.len(), 1);
+        assert!(policy
+            .add_rule(ProcurementRule::CoverOrders { days: 0 })
+            .is_err());
+    }
+}
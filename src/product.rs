@@ -4,6 +4,7 @@ pub enum ProductType {
     RawMaterial,      // Can only be used in manufacturing, not sold retail
     RetailGood,       // Regular products sold in stores
     ManufacturedGood, // Made in factories, sold in stores
+    Component,        // Made in factories, used as an ingredient in other recipes
 }
 
 impl ProductType {
@@ -16,6 +17,11 @@ impl ProductType {
     pub fn is_raw_material(&self) -> bool {
         matches!(self, ProductType::RawMaterial)
     }
+
+    /// Returns true if this product is an intermediate good made and used in factories
+    pub fn is_component(&self) -> bool {
+        matches!(self, ProductType::Component)
+    }
 }
 
 /// Represents a category of products in the game
@@ -50,6 +56,11 @@ impl Category {
         ]
     }
 
+    /// Returns true if products in this category are sold in stores
+    pub fn is_retail(&self) -> bool {
+        !matches!(self, Category::RawMaterial)
+    }
+
     /// Returns the display name for the category
     pub fn name(&self) -> &'static str {
         match self {
@@ -130,6 +141,9 @@ impl Product {
             Product::new_with_type(19, "Blender", 55.00, Category::Electronics, ProductType::ManufacturedGood),
             Product::new_with_type(20, "Smartphone", 150.00, Category::Electronics, ProductType::ManufacturedGood),
             Product::new_with_type(21, "Laptop", 400.00, Category::Electronics, ProductType::ManufacturedGood),
+            // Components (IDs 22-23)
+            Product::new_with_type(22, "Circuit Board", 40.00, Category::RawMaterial, ProductType::Component),
+            Product::new_with_type(23, "Steel Frame", 20.00, Category::RawMaterial, ProductType::Component),
         ]
     }
 
@@ -156,4 +170,118 @@ impl Product {
             .filter(|p| matches!(p.product_type, ProductType::ManufacturedGood))
             .collect()
     }
+
+    /// Returns only components (intermediate goods)
+    pub fn components() -> Vec<Product> {
+        Self::default_products()
+            .into_iter()
+            .filter(|p| p.product_type.is_component())
+            .collect()
+    }
+}
+
+/// How two products affect each other's demand
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelationKind {
+    /// Shoppers buy one or the other; the relatively cheaper one steals demand
+    Substitute,
+    /// Shoppers buy them together; stocking one lifts the other
+    Complement,
+}
+
+/// A demand relationship between two products
+#[derive(Debug, Clone)]
+pub struct ProductRelationship {
+    pub product_a: u32,
+    pub product_b: u32,
+    pub kind: RelationKind,
+    /// How strongly the products interact (0.0 to 1.0)
+    pub strength: f64,
+}
+
+impl ProductRelationship {
+    pub fn new(product_a: u32, product_b: u32, kind: RelationKind, strength: f64) -> Self {
+        ProductRelationship {
+            product_a,
+            product_b,
+            kind,
+            strength,
+        }
+    }
+
+    /// Returns the default relationships table
+    /// Product IDs: 1=Bread, 3=Cheese, 5=Headphones, 6=Phone Charger, 7=USB Cable, 8=T-Shirt,
+    ///   9=Jeans, 16=Wooden Chair, 17=Steel Table, 18=Designer Jacket, 20=Smartphone, 21=Laptop
+    pub fn default_relationships() -> Vec<ProductRelationship> {
+        vec![
+            // Competing for the same budget or wardrobe slot
+            ProductRelationship::new(20, 5, RelationKind::Substitute, 0.2),
+            ProductRelationship::new(8, 18, RelationKind::Substitute, 0.25),
+            ProductRelationship::new(20, 21, RelationKind::Substitute, 0.3),
+            ProductRelationship::new(6, 7, RelationKind::Substitute, 0.3),
+            // Bought together
+            ProductRelationship::new(6, 20, RelationKind::Complement, 0.3),
+            ProductRelationship::new(16, 17, RelationKind::Complement, 0.35),
+            ProductRelationship::new(1, 3, RelationKind::Complement, 0.15),
+            ProductRelationship::new(8, 9, RelationKind::Complement, 0.1),
+            ProductRelationship::new(7, 21, RelationKind::Complement, 0.2),
+        ]
+    }
+
+    /// Returns the other product in the relationship, if this one involves the given product
+    pub fn partner_of(&self, product_id: u32) -> Option<u32> {
+        if self.product_a == product_id {
+            Some(self.product_b)
+        } else if self.product_b == product_id {
+            Some(self.product_a)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the demand multiplier for a product given both products' price ratios
+    /// (shelf price / normal price) when the partner is on the same shelf
+    pub fn demand_effect(&self, own_ratio: f64, partner_ratio: f64) -> f64 {
+        match self.kind {
+            RelationKind::Substitute => {
+                // Positive when we're relatively cheaper, negative when the partner is
+                let gap = (partner_ratio - own_ratio) / own_ratio.max(partner_ratio).max(0.01);
+                1.0 + self.strength * gap.clamp(-1.0, 1.0)
+            }
+            RelationKind::Complement => {
+                // A full lift when the partner is fairly priced, less when it's marked up
+                1.0 + self.strength * (1.0 / partner_ratio.max(0.01)).min(1.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cheaper_substitute_steals_demand() {
+        let phones = ProductRelationship::new(20, 5, RelationKind::Substitute, 0.2);
+        // Headphones discounted, smartphone marked up
+        assert!(phones.demand_effect(1.5, 0.8) < 1.0);
+        assert!(phones.demand_effect(0.8, 1.5) > 1.0);
+        assert!((phones.demand_effect(1.2, 1.2) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_complement_lifts_demand() {
+        let furniture = ProductRelationship::new(16, 17, RelationKind::Complement, 0.35);
+        assert!((furniture.demand_effect(1.5, 1.0) - 1.35).abs() < 1e-9);
+        assert!(furniture.demand_effect(1.5, 2.0) < 1.35);
+        assert!(furniture.demand_effect(1.5, 2.0) > 1.0);
+    }
+
+    #[test]
+    fn test_partner_of() {
+        let rel = ProductRelationship::new(6, 20, RelationKind::Complement, 0.3);
+        assert_eq!(rel.partner_of(6), Some(20));
+        assert_eq!(rel.partner_of(20), Some(6));
+        assert_eq!(rel.partner_of(1), None);
+    }
 }
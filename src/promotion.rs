@@ -0,0 +1,246 @@
+use crate::product::{Category, Product};
+
+/// The kind of deal a promotion offers
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromotionKind {
+    /// Percentage off every product in a category (e.g., 20.0 for 20% off)
+    CategoryDiscount { category: Category, percent: f64 },
+    /// Buy one, get one free on a single product
+    BuyOneGetOne { product_id: u32 },
+    /// Discount on a set of products bought together (e.g., Bread + Milk)
+    /// Only units matched by sales of every partner product are discounted
+    Bundle { product_ids: Vec<u32>, percent: f64 },
+}
+
+impl PromotionKind {
+    /// Checks the discount is in range and a bundle has at least 2 different products
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            PromotionKind::CategoryDiscount { percent, .. } | PromotionKind::Bundle { percent, .. }
+                if *percent <= 0.0 || *percent > Promotion::MAX_DISCOUNT =>
+            {
+                Err(format!(
+                    "Discount must be between 0% and {:.0}%",
+                    Promotion::MAX_DISCOUNT
+                ))
+            }
+            PromotionKind::Bundle { product_ids, .. } if product_ids.len() < 2 => {
+                Err("A bundle needs at least 2 products".to_string())
+            }
+            PromotionKind::Bundle { product_ids, .. }
+                if product_ids.iter().enumerate().any(|(i, id)| product_ids[..i].contains(id)) =>
+            {
+                Err("A bundle can't list the same product twice".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Represents a time-limited promotion running in a store
+#[derive(Debug, Clone)]
+pub struct Promotion {
+    pub kind: PromotionKind,
+    pub days_remaining: u32,
+}
+
+impl Promotion {
+    /// Maximum discount percentage allowed on a promotion
+    pub const MAX_DISCOUNT: f64 = 75.0;
+
+    pub fn new(kind: PromotionKind, days: u32) -> Self {
+        Promotion {
+            kind,
+            days_remaining: days,
+        }
+    }
+
+    /// Returns true if this promotion covers the given product
+    pub fn applies_to(&self, product: &Product) -> bool {
+        match &self.kind {
+            PromotionKind::CategoryDiscount { category, .. } => product.category == *category,
+            PromotionKind::BuyOneGetOne { product_id } => product.id == *product_id,
+            PromotionKind::Bundle { product_ids, .. } => product_ids.contains(&product.id),
+        }
+    }
+
+    /// Returns the fraction of the shelf price the customer actually pays
+    pub fn price_multiplier(&self) -> f64 {
+        match &self.kind {
+            PromotionKind::CategoryDiscount { percent, .. } => 1.0 - percent / 100.0,
+            // Customer pays for one unit out of every two taken
+            PromotionKind::BuyOneGetOne { .. } => 0.5,
+            PromotionKind::Bundle { percent, .. } => 1.0 - percent / 100.0,
+        }
+    }
+
+    /// Returns extra demand from the promotion beyond the lower price
+    /// (deals draw attention on their own)
+    pub fn demand_multiplier(&self) -> f64 {
+        match &self.kind {
+            PromotionKind::CategoryDiscount { .. } => 1.05,
+            PromotionKind::BuyOneGetOne { .. } => 1.2,
+            PromotionKind::Bundle { .. } => 1.15,
+        }
+    }
+
+    /// Picks the most generous promotion covering a product (promotions don't stack)
+    pub fn best_for<'a>(product: &Product, promotions: &'a [Promotion]) -> Option<&'a Promotion> {
+        Self::best_index(product, promotions).map(|idx| &promotions[idx])
+    }
+
+    /// Returns the index of the promotion `best_for` would pick
+    pub fn best_index(product: &Product, promotions: &[Promotion]) -> Option<usize> {
+        promotions
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.applies_to(product))
+            .min_by(|(_, a), (_, b)| a.price_multiplier().total_cmp(&b.price_multiplier()))
+            .map(|(idx, _)| idx)
+    }
+
+    /// Returns true if the discount only applies to products bought together
+    pub fn needs_co_purchase(&self) -> bool {
+        matches!(self.kind, PromotionKind::Bundle { .. })
+    }
+
+    /// Returns the number of complete bundles bought, given each product's units sold
+    /// under this promotion (0 for other kinds of promotion)
+    pub fn bundles_sold(&self, units_sold: impl Fn(u32) -> u32) -> u32 {
+        match &self.kind {
+            PromotionKind::Bundle { product_ids, .. } => {
+                product_ids.iter().map(|id| units_sold(*id)).min().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+    /// Counts down one day, returns true if the promotion has ended
+    pub fn tick(&mut self) -> bool {
+        self.days_remaining = self.days_remaining.saturating_sub(1);
+        self.days_remaining == 0
+    }
+
+    /// Returns a short description of the promotion
+    pub fn describe(&self, get_name: impl Fn(u32) -> String) -> String {
+        match &self.kind {
+            PromotionKind::CategoryDiscount { category, percent } => {
+                format!("{:.0}% off {}", percent, category.name())
+            }
+            PromotionKind::BuyOneGetOne { product_id } => {
+                format!("BOGO {}", get_name(*product_id))
+            }
+            PromotionKind::Bundle { product_ids, percent } => {
+                let names: Vec<String> = product_ids.iter().map(|id| get_name(*id)).collect();
+                format!("{} bundle {:.0}% off", names.join("+"), percent)
+            }
+        }
+    }
+}
+
+/// A store loyalty program that brings customers back more often
+#[derive(Debug, Clone)]
+pub struct LoyaltyProgram {
+    /// Days the program has been running
+    pub days_active: u32,
+}
+
+impl LoyaltyProgram {
+    /// One-time cost to enroll a store in a loyalty program
+    pub const ENROLLMENT_COST: f64 = 1000.0;
+    /// Share of revenue paid back to members as rewards
+    pub const REWARD_RATE: f64 = 0.03;
+    /// Maximum extra repeat visits once the program matures (+25%)
+    pub const MAX_VISIT_BONUS: f64 = 0.25;
+
+    pub fn new() -> Self {
+        LoyaltyProgram { days_active: 0 }
+    }
+
+    /// Returns the customer multiplier from repeat visits
+    /// Membership builds up gradually, so the bonus grows over the first few weeks
+    pub fn visit_multiplier(&self) -> f64 {
+        1.0 + Self::MAX_VISIT_BONUS * (1.0 - (-(self.days_active as f64) / 20.0).exp())
+    }
+
+    /// Returns the rewards owed to members on a day's revenue
+    pub fn rewards_cost(&self, revenue: f64) -> f64 {
+        revenue * Self::REWARD_RATE
+    }
+
+    /// Advances the program by one day
+    pub fn advance_day(&mut self) {
+        self.days_active += 1;
+    }
+}
+
+impl Default for LoyaltyProgram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_promotion_does_not_stack() {
+        let bread = Product::new(1, "Bread", 2.00, Category::Food);
+        let promotions = vec![
+            Promotion::new(PromotionKind::CategoryDiscount { category: Category::Food, percent: 10.0 }, 3),
+            Promotion::new(PromotionKind::BuyOneGetOne { product_id: 1 }, 3),
+            Promotion::new(PromotionKind::BuyOneGetOne { product_id: 2 }, 3),
+        ];
+        let best = Promotion::best_for(&bread, &promotions).unwrap();
+        assert_eq!(best.kind, PromotionKind::BuyOneGetOne { product_id: 1 });
+        assert_eq!(best.price_multiplier(), 0.5);
+
+        let jeans = Product::new(9, "Jeans", 35.00, Category::Clothing);
+        assert!(Promotion::best_for(&jeans, &promotions).is_none());
+    }
+
+    #[test]
+    fn test_bundle_applies_to_members() {
+        let bundle = Promotion::new(PromotionKind::Bundle { product_ids: vec![1, 2], percent: 20.0 }, 5);
+        assert!(bundle.applies_to(&Product::new(2, "Milk", 3.50, Category::Food)));
+        assert!(!bundle.applies_to(&Product::new(3, "Cheese", 5.00, Category::Food)));
+        assert!((bundle.price_multiplier() - 0.8).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_bundle_discount_needs_every_partner() {
+        let bundle = Promotion::new(PromotionKind::Bundle { product_ids: vec![1, 2], percent: 20.0 }, 5);
+        assert!(bundle.needs_co_purchase());
+        // 5 loaves but only 3 milks: 3 complete bundles
+        assert_eq!(bundle.bundles_sold(|id| if id == 1 { 5 } else { 3 }), 3);
+        assert_eq!(bundle.bundles_sold(|id| if id == 1 { 5 } else { 0 }), 0);
+
+        let bogo = Promotion::new(PromotionKind::BuyOneGetOne { product_id: 1 }, 5);
+        assert!(!bogo.needs_co_purchase());
+        assert_eq!(bogo.bundles_sold(|_| 5), 0);
+    }
+
+    #[test]
+    fn test_bundle_rejects_repeated_products() {
+        let repeated = PromotionKind::Bundle { product_ids: vec![1, 1], percent: 50.0 };
+        assert!(repeated.validate().is_err());
+        let padded = PromotionKind::Bundle { product_ids: vec![1, 2, 1], percent: 50.0 };
+        assert!(padded.validate().is_err());
+        let pair = PromotionKind::Bundle { product_ids: vec![1, 2], percent: 50.0 };
+        assert!(pair.validate().is_ok());
+    }
+
+    #[test]
+    fn test_loyalty_bonus_grows_and_caps() {
+        let mut program = LoyaltyProgram::new();
+        assert_eq!(program.visit_multiplier(), 1.0);
+        program.advance_day();
+        let early = program.visit_multiplier();
+        for _ in 0..200 {
+            program.advance_day();
+        }
+        assert!(program.visit_multiplier() > early);
+        assert!(program.visit_multiplier() <= 1.0 + LoyaltyProgram::MAX_VISIT_BONUS);
+    }
+}
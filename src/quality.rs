@@ -0,0 +1,127 @@
+/// How good a batch of goods turned out, from its quality score (0-1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualityTier {
+    Premium,
+    Standard,
+    Economy,
+}
+
+impl QualityTier {
+    /// Quality score of goods bought from wholesalers
+    pub const DEFAULT_SCORE: f64 = 0.5;
+
+    /// Returns the tier for a quality score
+    pub fn from_score(score: f64) -> Self {
+        if score >= 0.75 {
+            QualityTier::Premium
+        } else if score >= 0.4 {
+            QualityTier::Standard
+        } else {
+            QualityTier::Economy
+        }
+    }
+
+    /// Returns how much more (or less) customers will pay for this tier
+    pub fn price_multiplier(&self) -> f64 {
+        match self {
+            QualityTier::Premium => 1.25,
+            QualityTier::Standard => 1.0,
+            QualityTier::Economy => 0.85,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            QualityTier::Premium => "Premium",
+            QualityTier::Standard => "Standard",
+            QualityTier::Economy => "Economy",
+        }
+    }
+}
+
+/// Grade of raw materials a factory buys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaterialGrade {
+    Standard,
+    Premium,
+}
+
+impl MaterialGrade {
+    /// Returns the price of this grade relative to standard materials
+    pub fn price_multiplier(&self) -> f64 {
+        match self {
+            MaterialGrade::Standard => 1.0,
+            MaterialGrade::Premium => 1.5,
+        }
+    }
+
+    /// Returns the quality score this grade adds
+    pub fn quality_bonus(&self) -> f64 {
+        match self {
+            MaterialGrade::Standard => 0.0,
+            MaterialGrade::Premium => 0.15,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MaterialGrade::Standard => "Standard",
+            MaterialGrade::Premium => "Premium",
+        }
+    }
+}
+
+/// What a factory does with defective units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefectHandling {
+    /// Throw them away
+    Scrap,
+    /// Sell them to a discount outlet
+    SellAsSeconds,
+}
+
+impl DefectHandling {
+    /// Share of the normal price an outlet pays for seconds
+    pub const SECONDS_PRICE: f64 = 0.4;
+}
+
+/// Skill assumed when a factory has no workers (the owner runs the line)
+pub const OWNER_SKILL: f64 = 0.5;
+/// Highest equipment level a factory can reach
+pub const MAX_EQUIPMENT_LEVEL: u32 = 3;
+/// Cost of upgrading equipment, per level reached
+pub const EQUIPMENT_UPGRADE_COST: f64 = 5000.0;
+/// Defect rate of a line with a quality score of zero
+const MAX_DEFECT_RATE: f64 = 0.25;
+
+/// Returns a batch's quality score (0-1) from average worker skill (0-1),
+/// equipment level (1-3) and material grade
+pub fn quality_score(worker_skill: f64, equipment_level: u32, grade: MaterialGrade) -> f64 {
+    line_quality(worker_skill, equipment_level, grade.quality_bonus())
+}
+
+/// Returns a batch's quality score from skill, equipment level and the
+/// quality bonus of the materials actually used (a mix of grades averages out)
+pub fn line_quality(worker_skill: f64, equipment_level: u32, material_bonus: f64) -> f64 {
+    let equipment = equipment_level.clamp(1, MAX_EQUIPMENT_LEVEL) - 1;
+    (0.25 + 0.35 * worker_skill + 0.1 * equipment as f64 + material_bonus).clamp(0.0, 1.0)
+}
+
+/// Returns the share of units that come out defective at a quality score
+pub fn defect_rate(quality: f64) -> f64 {
+    MAX_DEFECT_RATE * (1.0 - quality.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_better_inputs_raise_quality_and_cut_defects() {
+        let basic = quality_score(OWNER_SKILL, 1, MaterialGrade::Standard);
+        let best = quality_score(1.0, MAX_EQUIPMENT_LEVEL, MaterialGrade::Premium);
+        assert_eq!(QualityTier::from_score(basic), QualityTier::Standard);
+        assert_eq!(QualityTier::from_score(best), QualityTier::Premium);
+        assert!(defect_rate(best) < defect_rate(basic));
+    }
+}
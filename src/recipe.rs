@@ -20,6 +20,8 @@ pub struct Recipe {
     pub output_product_id: u32,
     pub output_quantity: u32,
     pub production_days: u32,
+    /// True if the output is a component kept at the factory for other recipes
+    pub intermediate: bool,
 }
 
 impl Recipe {
@@ -39,6 +41,22 @@ impl Recipe {
             output_product_id,
             output_quantity,
             production_days,
+            intermediate: false,
+        }
+    }
+
+    /// Creates a recipe whose output is a component for other recipes
+    pub fn new_intermediate(
+        id: u32,
+        name: &str,
+        ingredients: Vec<RecipeIngredient>,
+        output_product_id: u32,
+        output_quantity: u32,
+        production_days: u32,
+    ) -> Self {
+        Recipe {
+            intermediate: true,
+            ..Self::new(id, name, ingredients, output_product_id, output_quantity, production_days)
         }
     }
 
@@ -46,6 +64,7 @@ impl Recipe {
     /// Product IDs:
     ///   Raw Materials: 11=Lumber, 12=Steel, 13=Fabric, 14=Plastic, 15=Electronics
     ///   Manufactured: 16=Chair, 17=Table, 18=Jacket, 19=Blender, 20=Smartphone, 21=Laptop
+    ///   Components: 22=Circuit Board, 23=Steel Frame
     pub fn default_recipes() -> Vec<Recipe> {
         vec![
             // Wooden Chair: 2 Lumber -> 1 Chair (1 day)
@@ -90,30 +109,51 @@ impl Recipe {
                 1,
                 2,
             ),
-            // Smartphone: 2 Electronics + 1 Plastic -> 1 Smartphone (3 days)
+            // Smartphone: 1 Circuit Board + 1 Plastic -> 1 Smartphone (2 days)
             Recipe::new(
                 5,
                 "Smartphone",
                 vec![
-                    RecipeIngredient::new(15, 2),
+                    RecipeIngredient::new(22, 1),
                     RecipeIngredient::new(14, 1),
                 ],
                 20,
                 1,
-                3,
+                2,
             ),
-            // Laptop: 3 Electronics + 1 Steel + 1 Plastic -> 1 Laptop (3 days)
+            // Laptop: 2 Circuit Boards + 1 Steel Frame + 1 Plastic -> 1 Laptop (2 days)
             Recipe::new(
                 6,
                 "Laptop",
                 vec![
-                    RecipeIngredient::new(15, 3),
-                    RecipeIngredient::new(12, 1),
+                    RecipeIngredient::new(22, 2),
+                    RecipeIngredient::new(23, 1),
                     RecipeIngredient::new(14, 1),
                 ],
                 21,
                 1,
-                3,
+                2,
+            ),
+            // Circuit Board: 2 Electronics + 1 Plastic -> 1 Circuit Board (1 day)
+            Recipe::new_intermediate(
+                7,
+                "Circuit Board",
+                vec![
+                    RecipeIngredient::new(15, 2),
+                    RecipeIngredient::new(14, 1),
+                ],
+                22,
+                1,
+                1,
+            ),
+            // Steel Frame: 2 Steel -> 1 Steel Frame (1 day)
+            Recipe::new_intermediate(
+                8,
+                "Steel Frame",
+                vec![RecipeIngredient::new(12, 2)],
+                23,
+                1,
+                1,
             ),
         ]
     }
@@ -125,4 +165,39 @@ impl Recipe {
             .map(|ing| get_price(ing.product_id) * ing.quantity as f64)
             .sum()
     }
+
+    /// Returns the recipe that makes a product, if any
+    pub fn producing(recipes: &[Recipe], product_id: u32) -> Option<&Recipe> {
+        recipes.iter().find(|r| r.output_product_id == product_id)
+    }
+
+    /// Calculates the raw material cost of one batch, costing components at the
+    /// raw materials that go into them
+    pub fn rolled_up_cost(&self, recipes: &[Recipe], get_price: &impl Fn(u32) -> f64) -> f64 {
+        self.ingredients
+            .iter()
+            .map(|ing| {
+                let unit_cost = match Self::producing(recipes, ing.product_id) {
+                    Some(sub) => sub.rolled_up_cost(recipes, get_price) / sub.output_quantity as f64,
+                    None => get_price(ing.product_id),
+                };
+                unit_cost * ing.quantity as f64
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolled_up_cost_includes_components() {
+        let recipes = Recipe::default_recipes();
+        let laptop = recipes.iter().find(|r| r.name == "Laptop").unwrap();
+        // Every raw material costs $1: 2 boards x 3 + frame 2 + 1 plastic
+        let cost = laptop.rolled_up_cost(&recipes, &|_| 1.0);
+        assert!((cost - 9.0).abs() < 1e-9);
+        assert!(Recipe::producing(&recipes, 22).unwrap().intermediate);
+    }
 }
@@ -0,0 +1,126 @@
+use crate::competitor::{CompetitiveMarket, Competitor, PricingStrategy};
+
+/// A city with its own shoppers, cost of doing business and local competitors
+#[derive(Debug)]
+pub struct Region {
+    pub id: u32,
+    pub name: String,
+    /// Potential customers living in the region
+    pub population: u32,
+    /// Local spending power (stacks with the economic state)
+    pub sales_multiplier: f64,
+    /// Local wholesale price level (shipping, suppliers)
+    pub price_multiplier: f64,
+    /// Local property costs (store/factory purchase price and rent)
+    pub rent_multiplier: f64,
+    /// Competitors operating here and the player's share of this region
+    pub market: CompetitiveMarket,
+}
+
+impl Region {
+    /// The region the player starts in
+    pub const HOME_ID: u32 = 1;
+    /// Population the base store traffic is calibrated for
+    pub const BASELINE_POPULATION: u32 = 500;
+
+    pub fn new(
+        id: u32,
+        name: &str,
+        population: u32,
+        sales_multiplier: f64,
+        price_multiplier: f64,
+        rent_multiplier: f64,
+        competitors: Vec<Competitor>,
+    ) -> Self {
+        Region {
+            id,
+            name: name.to_string(),
+            population,
+            sales_multiplier,
+            price_multiplier,
+            rent_multiplier,
+            market: CompetitiveMarket::with_competitors(competitors, population),
+        }
+    }
+
+    /// Returns the default set of regions
+    pub fn default_regions() -> Vec<Region> {
+        vec![
+            Region::new(1, "Riverside", 500, 1.0, 1.0, 1.0, Competitor::default_competitors()),
+            Region::new(
+                2,
+                "Metro City",
+                900,
+                1.2,
+                1.1,
+                1.6,
+                vec![
+                    Competitor::new(1, "MegaMart", 4, PricingStrategy::Aggressive),
+                    Competitor::new(2, "Quality Goods Co", 3, PricingStrategy::Premium),
+                    Competitor::new(3, "UrbanMart", 2, PricingStrategy::Neutral),
+                ],
+            ),
+            Region::new(
+                3,
+                "Harbor Town",
+                300,
+                0.85,
+                0.9,
+                0.7,
+                vec![
+                    Competitor::new(1, "ValueStore", 1, PricingStrategy::Neutral),
+                    Competitor::new(2, "Dockside Goods", 1, PricingStrategy::Aggressive),
+                ],
+            ),
+        ]
+    }
+
+    /// Returns the road times between the default regions as (region, region, days)
+    /// Harbor Town is down the river from Riverside; Metro City is inland from both.
+    pub fn default_routes() -> Vec<(u32, u32, u32)> {
+        vec![(1, 2, 2), (1, 3, 2), (2, 3, 3)]
+    }
+
+    /// Returns how many more (or fewer) shoppers a store sees than in a baseline town
+    /// Follows the current market size, which grows and shrinks with the economy
+    pub fn population_multiplier(&self) -> f64 {
+        self.market.total_market_size as f64 / Self::BASELINE_POPULATION as f64
+    }
+
+    /// Returns the customer multiplier for player stores here (market share and population)
+    pub fn customer_multiplier(&self) -> f64 {
+        self.market.player_customer_multiplier() * self.population_multiplier()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_regions() {
+        let regions = Region::default_regions();
+        assert_eq!(regions[0].id, Region::HOME_ID);
+        assert!((regions[0].population_multiplier() - 1.0).abs() < 1e-9);
+        assert!(regions.iter().all(|r| !r.market.competitors.is_empty()));
+    }
+
+    #[test]
+    fn test_every_pair_of_default_regions_has_a_route() {
+        let regions = Region::default_regions();
+        let routes = Region::default_routes();
+        for (i, a) in regions.iter().enumerate() {
+            for b in &regions[i + 1..] {
+                assert!(routes.iter().any(|&(x, y, _)| (x, y) == (a.id, b.id) || (y, x) == (a.id, b.id)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_market_share_is_per_region() {
+        let mut regions = Region::default_regions();
+        regions[0].market.calculate_market_shares(2, 50.0, 1.0);
+        regions[1].market.calculate_market_shares(0, 50.0, 1.0);
+        assert!(regions[0].market.player_market_share > regions[1].market.player_market_share);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::economy::EconomicState;
+use crate::product::Category;
+
+/// Permission to run stores in a region
+#[derive(Debug, Clone)]
+pub struct BusinessLicence {
+    pub region_id: u32,
+    pub days_remaining: u32,
+}
+
+/// Government rules the player must follow, changing with the economy
+#[derive(Debug, Clone)]
+pub struct Regulations {
+    /// Lowest daily salary any employee or worker may be paid
+    pub minimum_wage: f64,
+    /// True while emergency price controls cap food prices
+    pub price_controls: bool,
+    pub licences: Vec<BusinessLicence>,
+    /// Fines paid so far
+    pub total_fines: f64,
+}
+
+impl Regulations {
+    /// Minimum wage when the game starts
+    pub const STARTING_MINIMUM_WAGE: f64 = 40.0;
+    /// Days between minimum wage reviews
+    pub const WAGE_REVIEW_DAYS: u32 = 120;
+    /// Highest food price allowed under price controls, relative to the normal price
+    pub const FOOD_PRICE_CAP: f64 = 1.25;
+    /// Fine for selling above the cap, as a multiple of the overcharge
+    pub const OVERCHARGE_FINE_MULTIPLIER: f64 = 3.0;
+    /// Base cost of a business licence (scaled by regional property costs and prices)
+    pub const LICENCE_FEE: f64 = 500.0;
+    /// Days a licence is valid
+    pub const LICENCE_DAYS: u32 = 360;
+    /// Daily fine per store operating without a licence (scaled by prices)
+    pub const UNLICENSED_FINE: f64 = 100.0;
+
+    /// Creates the starting rules with a licence for the player's home region
+    pub fn new(home_region_id: u32) -> Self {
+        let mut regulations = Regulations {
+            minimum_wage: Self::STARTING_MINIMUM_WAGE,
+            price_controls: false,
+            licences: Vec::new(),
+            total_fines: 0.0,
+        };
+        regulations.grant_licence(home_region_id);
+        regulations
+    }
+
+    /// Returns true if the player may operate stores in the region
+    pub fn has_licence(&self, region_id: u32) -> bool {
+        self.licences.iter().any(|l| l.region_id == region_id)
+    }
+
+    /// Returns the days left on a region's licence
+    pub fn licence_days(&self, region_id: u32) -> Option<u32> {
+        self.licences
+            .iter()
+            .find(|l| l.region_id == region_id)
+            .map(|l| l.days_remaining)
+    }
+
+    /// Issues (or renews) a licence for a full term
+    pub fn grant_licence(&mut self, region_id: u32) {
+        match self.licences.iter_mut().find(|l| l.region_id == region_id) {
+            Some(licence) => licence.days_remaining = Self::LICENCE_DAYS,
+            None => self.licences.push(BusinessLicence {
+                region_id,
+                days_remaining: Self::LICENCE_DAYS,
+            }),
+        }
+    }
+
+    /// Returns the cost of a licence in a region
+    pub fn licence_fee(rent_multiplier: f64, price_level: f64) -> f64 {
+        Self::LICENCE_FEE * rent_multiplier * price_level
+    }
+
+    /// Counts down all licences, removing and returning the regions whose licence expired
+    pub fn advance_licences(&mut self) -> Vec<u32> {
+        let mut expired = Vec::new();
+        for licence in &mut self.licences {
+            licence.days_remaining = licence.days_remaining.saturating_sub(1);
+            if licence.days_remaining == 0 {
+                expired.push(licence.region_id);
+            }
+        }
+        self.licences.retain(|l| l.days_remaining > 0);
+        expired
+    }
+
+    /// Returns how much the government raises the minimum wage at a review
+    fn wage_raise(economic_state: EconomicState) -> f64 {
+        match economic_state {
+            EconomicState::Collapse | EconomicState::Recession => 0.0,
+            EconomicState::Standard => 0.03,
+            EconomicState::Growth => 0.05,
+            EconomicState::Booming => 0.08,
+            EconomicState::Prosperity => 0.10,
+        }
+    }
+
+    /// Reviews the minimum wage (never lowered)
+    /// Returns (old, new) if it went up
+    pub fn review_minimum_wage(&mut self, economic_state: EconomicState) -> Option<(f64, f64)> {
+        let raise = Self::wage_raise(economic_state);
+        if raise <= 0.0 {
+            return None;
+        }
+        let old = self.minimum_wage;
+        self.minimum_wage *= 1.0 + raise;
+        Some((old, self.minimum_wage))
+    }
+
+    /// Imposes food price controls during a collapse and lifts them afterwards
+    /// Returns Some(true) when imposed, Some(false) when lifted
+    pub fn update_price_controls(&mut self, economic_state: EconomicState) -> Option<bool> {
+        let should_apply = economic_state == EconomicState::Collapse;
+        if should_apply == self.price_controls {
+            return None;
+        }
+        self.price_controls = should_apply;
+        Some(should_apply)
+    }
+
+    /// Returns the highest legal price for a product, if it is capped
+    pub fn price_cap(&self, category: Category, reference_price: f64) -> Option<f64> {
+        if self.price_controls && category == Category::Food {
+            Some(reference_price * Self::FOOD_PRICE_CAP)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the fine for selling units above a price cap
+    pub fn overcharge_fine(price_paid: f64, cap: f64, units: u32) -> f64 {
+        (price_paid - cap).max(0.0) * units as f64 * Self::OVERCHARGE_FINE_MULTIPLIER
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_food_price_cap_only_in_collapse() {
+        let mut regulations = Regulations::new(1);
+        assert_eq!(regulations.price_cap(Category::Food, 2.0), None);
+
+        assert_eq!(regulations.update_price_controls(EconomicState::Collapse), Some(true));
+        assert_eq!(regulations.price_cap(Category::Food, 2.0), Some(2.5));
+        assert_eq!(regulations.price_cap(Category::Electronics, 2.0), None);
+
+        assert_eq!(regulations.update_price_controls(EconomicState::Collapse), None);
+        assert_eq!(regulations.update_price_controls(EconomicState::Recession), Some(false));
+    }
+
+    #[test]
+    fn test_licences_expire() {
+        let mut regulations = Regulations::new(1);
+        assert!(regulations.has_licence(1));
+        assert!(!regulations.has_licence(2));
+        for _ in 1..Regulations::LICENCE_DAYS {
+            assert!(regulations.advance_licences().is_empty());
+        }
+        assert_eq!(regulations.advance_licences(), vec![1]);
+        assert!(!regulations.has_licence(1));
+    }
+
+    #[test]
+    fn test_minimum_wage_rises_in_good_times() {
+        let mut regulations = Regulations::new(1);
+        assert_eq!(regulations.review_minimum_wage(EconomicState::Recession), None);
+        let (old, new) = regulations.review_minimum_wage(EconomicState::Booming).unwrap();
+        assert!(new > old);
+    }
+}
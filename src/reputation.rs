@@ -0,0 +1,191 @@
+/// Summary of how well a store served its customers on a given day
+#[derive(Debug, Clone, Default)]
+pub struct ServiceReport {
+    /// Number of products the store carries
+    pub products_carried: u32,
+    /// Products that were empty or sold out during the day
+    pub products_stocked_out: u32,
+    /// Average ratio of shelf price to base price across stocked products
+    pub avg_price_ratio: f64,
+    /// Customers that visited
+    pub customers: u32,
+    /// Staff on the floor (employees, plus the owner or manager)
+    pub staff: u32,
+    /// Units sold
+    pub units_sold: u32,
+    /// Units sold from premium-quality stock
+    pub premium_units: u32,
+    /// Units sold from economy-quality stock
+    pub economy_units: u32,
+}
+
+impl ServiceReport {
+    /// Returns the fraction of carried products that stayed in stock (0.0 if nothing carried)
+    pub fn availability(&self) -> f64 {
+        if self.products_carried == 0 {
+            return 0.0;
+        }
+        1.0 - self.products_stocked_out as f64 / self.products_carried as f64
+    }
+
+    /// Returns customers per staff member
+    pub fn customers_per_staff(&self) -> f64 {
+        self.customers as f64 / self.staff.max(1) as f64
+    }
+
+    /// Returns the share of premium units sold minus the share of economy units (-1 to 1)
+    pub fn quality_balance(&self) -> f64 {
+        if self.units_sold == 0 {
+            return 0.0;
+        }
+        (self.premium_units as f64 - self.economy_units as f64) / self.units_sold as f64
+    }
+}
+
+/// A store's standing with customers (0-100), persisting across days
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reputation {
+    pub score: f64,
+}
+
+impl Reputation {
+    /// Starting (neutral) reputation
+    pub const NEUTRAL: f64 = 50.0;
+    /// Price ratio customers consider fair (up to 60% over base)
+    pub const FAIR_PRICE_RATIO: f64 = 1.6;
+    /// Price ratio customers consider gouging (over 100% above base)
+    pub const GOUGING_PRICE_RATIO: f64 = 2.0;
+    /// Customers one staff member can serve well
+    pub const CUSTOMERS_PER_STAFF: f64 = 40.0;
+    /// Daily change from selling nothing but premium (or economy) goods
+    pub const QUALITY_WEIGHT: f64 = 0.5;
+
+    pub fn new() -> Self {
+        Reputation {
+            score: Self::NEUTRAL,
+        }
+    }
+
+    /// Returns the traffic multiplier from reputation (0.6x at 0, 1.0x at 50, 1.4x at 100)
+    pub fn traffic_multiplier(&self) -> f64 {
+        0.6 + 0.8 * self.score / 100.0
+    }
+
+    /// Returns a display label for the reputation
+    pub fn label(&self) -> &'static str {
+        match self.score {
+            s if s >= 80.0 => "Excellent",
+            s if s >= 60.0 => "Good",
+            s if s >= 40.0 => "Average",
+            s if s >= 20.0 => "Poor",
+            _ => "Terrible",
+        }
+    }
+
+    /// Updates the score from a day's service and returns the change
+    pub fn update(&mut self, report: &ServiceReport) -> f64 {
+        let mut delta = 0.0;
+
+        // Availability: full shelves build trust, empty shelves erode it
+        if report.products_carried == 0 {
+            delta -= 2.0;
+        } else {
+            let availability = report.availability();
+            if availability >= 0.9 {
+                delta += 1.0;
+            } else {
+                delta -= (1.0 - availability) * 4.0;
+            }
+
+            // Pricing: fair prices are rewarded, gouging is punished
+            if report.avg_price_ratio <= Self::FAIR_PRICE_RATIO {
+                delta += 0.5;
+            } else if report.avg_price_ratio > Self::GOUGING_PRICE_RATIO {
+                delta -= ((report.avg_price_ratio - Self::GOUGING_PRICE_RATIO) * 5.0).min(5.0);
+            }
+        }
+
+        // Quality: well-made goods win customers over, shoddy ones put them off
+        delta += report.quality_balance() * Self::QUALITY_WEIGHT;
+
+        // Staffing: long queues leave a bad impression
+        if report.customers_per_staff() > Self::CUSTOMERS_PER_STAFF {
+            delta -= 1.0;
+        } else {
+            delta += 0.25;
+        }
+
+        let old_score = self.score;
+        self.score = (self.score + delta).clamp(0.0, 100.0);
+        self.score - old_score
+    }
+}
+
+impl Default for Reputation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(carried: u32, stocked_out: u32, price_ratio: f64, customers: u32, staff: u32) -> ServiceReport {
+        ServiceReport {
+            products_carried: carried,
+            products_stocked_out: stocked_out,
+            avg_price_ratio: price_ratio,
+            customers,
+            staff,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_good_service_raises_reputation() {
+        let mut reputation = Reputation::new();
+        let change = reputation.update(&report(5, 0, 1.4, 30, 2));
+        assert!(change > 0.0);
+        assert!(reputation.score > Reputation::NEUTRAL);
+    }
+
+    #[test]
+    fn test_stockouts_and_gouging_lower_reputation() {
+        let mut reputation = Reputation::new();
+        let change = reputation.update(&report(4, 3, 3.0, 80, 1));
+        assert!(change < 0.0);
+    }
+
+    #[test]
+    fn test_product_quality_moves_reputation() {
+        let sold = |premium_units, economy_units| ServiceReport {
+            units_sold: 10,
+            premium_units,
+            economy_units,
+            ..report(5, 0, 1.4, 30, 2)
+        };
+        let mut premium = Reputation::new();
+        let mut economy = Reputation::new();
+        let premium_change = premium.update(&sold(10, 0));
+        let economy_change = economy.update(&sold(0, 10));
+        assert!((premium_change - economy_change - 2.0 * Reputation::QUALITY_WEIGHT).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_score_is_clamped() {
+        let mut reputation = Reputation { score: 99.9 };
+        reputation.update(&report(5, 0, 1.0, 10, 4));
+        assert_eq!(reputation.score, 100.0);
+
+        let mut reputation = Reputation { score: 0.5 };
+        reputation.update(&report(0, 0, 0.0, 100, 1));
+        assert_eq!(reputation.score, 0.0);
+    }
+
+    #[test]
+    fn test_traffic_multiplier() {
+        assert!((Reputation::new().traffic_multiplier() - 1.0).abs() < 0.001);
+        assert!((Reputation { score: 100.0 }.traffic_multiplier() - 1.4).abs() < 0.001);
+    }
+}
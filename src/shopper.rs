@@ -0,0 +1,289 @@
+use crate::product::Category;
+use crate::store::DemandRecord;
+use std::collections::HashMap;
+
+/// How customer demand is simulated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DemandModel {
+    /// Closed-form expected demand per product (fast, smooth)
+    Formula,
+    /// Individual shoppers with budgets and shopping lists (richer, noisier)
+    Agents,
+}
+
+impl DemandModel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DemandModel::Formula => "Formula",
+            DemandModel::Agents => "Agent-based",
+        }
+    }
+}
+
+/// Small deterministic random number generator (SplitMix64)
+#[derive(Debug, Clone)]
+pub struct SimRng {
+    state: u64,
+}
+
+impl SimRng {
+    pub fn new(seed: u64) -> Self {
+        SimRng { state: seed }
+    }
+
+    /// Returns the next raw 64-bit value
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value between 0.0 (inclusive) and 1.0 (exclusive)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns true with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+}
+
+/// A product as a shopper sees it when walking into the store
+#[derive(Debug, Clone)]
+pub struct ShelfOffer {
+    pub product_id: u32,
+    pub category: Category,
+    /// Price the shopper pays (after promotions)
+    pub price: f64,
+    /// Price the shopper considers normal
+    pub reference_price: f64,
+    /// Units on the shelf (0 if sold out or not carried)
+    pub available: u32,
+    /// Chance a shopper has this product on their list today
+    pub want_chance: f64,
+}
+
+impl ShelfOffer {
+    /// Returns the shelf price over the normal price
+    pub fn price_ratio(&self) -> f64 {
+        self.price / self.reference_price
+    }
+}
+
+/// A simulated shopper
+#[derive(Debug, Clone)]
+pub struct Shopper {
+    /// Money the shopper is willing to spend today
+    pub budget: f64,
+    /// How strongly prices put this shopper off (0.5 = relaxed, 1.5 = bargain hunter)
+    pub price_sensitivity: f64,
+    /// Category preferences (multiplies the chance of wanting products in that category)
+    pub preferences: HashMap<Category, f64>,
+    /// Indexes into the shelf of products the shopper came for, in the order they shop
+    pub shopping_list: Vec<usize>,
+}
+
+impl Shopper {
+    /// Typical daily shopping budget at the starting price level
+    pub const BASE_BUDGET: f64 = 60.0;
+    /// Share of shoppers out for a big-ticket purchase
+    pub const BIG_SPENDER_CHANCE: f64 = 0.08;
+    /// Budget multiplier for big spenders
+    pub const BIG_SPENDER_BUDGET: f64 = 12.0;
+
+    /// Generates a shopper with a random budget, temperament, preferences and shopping list
+    /// `budget_scale` is the price level times the economy's spending multiplier
+    pub fn generate(rng: &mut SimRng, shelf: &[ShelfOffer], budget_scale: f64) -> Self {
+        let mut budget = Self::BASE_BUDGET * budget_scale * (0.25 + rng.next_f64() * 1.5);
+        if rng.chance(Self::BIG_SPENDER_CHANCE) {
+            budget *= Self::BIG_SPENDER_BUDGET;
+        }
+        let price_sensitivity = 0.5 + rng.next_f64();
+
+        // Each shopper leans toward some categories and away from others
+        let preferences: HashMap<Category, f64> = Category::retail_categories()
+            .into_iter()
+            .map(|category| (category, 0.5 + rng.next_f64()))
+            .collect();
+
+        let mut shopping_list: Vec<usize> = shelf
+            .iter()
+            .enumerate()
+            .filter(|(_, offer)| {
+                let preference = preferences.get(&offer.category).copied().unwrap_or(1.0);
+                rng.chance((offer.want_chance * preference).min(0.95))
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        // Shop the list in a random order (Fisher-Yates)
+        for i in (1..shopping_list.len()).rev() {
+            let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+            shopping_list.swap(i, j);
+        }
+
+        Shopper {
+            budget,
+            price_sensitivity,
+            preferences,
+            shopping_list,
+        }
+    }
+}
+
+/// Results of a day of simulated shoppers at one store
+#[derive(Debug, Clone, Default)]
+pub struct ShoppingDay {
+    /// Demand and sales per product (product_id -> record), including products not carried
+    pub records: HashMap<u32, DemandRecord>,
+    pub shoppers: u32,
+    /// Items shoppers bought at a competitor because our price was too high
+    pub lost_to_competitors: u32,
+    /// Shoppers who ran out of money before finishing their list
+    pub budget_exhausted: u32,
+    /// Shoppers who left with at least one item
+    pub buyers: u32,
+    pub items_bought: u32,
+}
+
+impl ShoppingDay {
+    /// Average number of items per shopper who bought anything
+    pub fn average_basket(&self) -> f64 {
+        if self.buyers == 0 {
+            0.0
+        } else {
+            self.items_bought as f64 / self.buyers as f64
+        }
+    }
+}
+
+/// Runs a day of individual shoppers through a store
+/// `rival_price_ratio` is what the cheapest competitor charges relative to normal prices;
+/// shoppers facing a steeper price may buy that item from the competitor instead
+pub fn simulate_shoppers(
+    seed: u64,
+    shopper_count: u32,
+    shelf: &[ShelfOffer],
+    budget_scale: f64,
+    rival_price_ratio: f64,
+) -> ShoppingDay {
+    let mut rng = SimRng::new(seed);
+    let mut day = ShoppingDay {
+        shoppers: shopper_count,
+        ..Default::default()
+    };
+    let mut stock: Vec<u32> = shelf.iter().map(|offer| offer.available).collect();
+
+    for _ in 0..shopper_count {
+        let mut shopper = Shopper::generate(&mut rng, shelf, budget_scale);
+        let mut basket = 0;
+        let mut ran_out = false;
+
+        for &index in &shopper.shopping_list {
+            let offer = &shelf[index];
+            let ratio = offer.price_ratio();
+
+            // Too pricey compared with the competition: shop there instead
+            if ratio > rival_price_ratio {
+                let walk_chance =
+                    ((ratio - rival_price_ratio) / rival_price_ratio * shopper.price_sensitivity).min(0.9);
+                if rng.chance(walk_chance) {
+                    day.lost_to_competitors += 1;
+                    continue;
+                }
+            }
+
+            // Willing to pay? Bargains may tempt a second unit
+            let appeal = 1.0 - (ratio - 1.0) * 0.5 * shopper.price_sensitivity;
+            if !rng.chance(appeal) {
+                continue;
+            }
+            let wanted = if appeal > 1.0 && rng.chance(appeal - 1.0) { 2 } else { 1 };
+
+            // Only what the shopper can afford counts as demand
+            let affordable = (shopper.budget / offer.price).floor() as u32;
+            if affordable == 0 {
+                ran_out = true;
+                continue;
+            }
+            let record = day.records.entry(offer.product_id).or_default();
+            record.demand += wanted.min(affordable);
+            let bought = wanted.min(affordable).min(stock[index]);
+            if bought > 0 {
+                stock[index] -= bought;
+                record.sold += bought;
+                shopper.budget -= bought as f64 * offer.price;
+                basket += bought;
+            }
+        }
+
+        if ran_out {
+            day.budget_exhausted += 1;
+        }
+        if basket > 0 {
+            day.buyers += 1;
+            day.items_bought += basket;
+        }
+    }
+
+    day
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shelf() -> Vec<ShelfOffer> {
+        vec![
+            ShelfOffer {
+                product_id: 1,
+                category: Category::Food,
+                price: 3.0,
+                reference_price: 2.0,
+                available: 100,
+                want_chance: 0.3,
+            },
+            ShelfOffer {
+                product_id: 21,
+                category: Category::Electronics,
+                price: 600.0,
+                reference_price: 400.0,
+                available: 5,
+                want_chance: 0.05,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_same_seed_same_outcome() {
+        let a = simulate_shoppers(7, 200, &shelf(), 1.0, 1.5);
+        let b = simulate_shoppers(7, 200, &shelf(), 1.0, 1.5);
+        assert_eq!(a.records, b.records);
+        assert_eq!(a.items_bought, b.items_bought);
+    }
+
+    #[test]
+    fn test_sales_respect_stock() {
+        let day = simulate_shoppers(3, 500, &shelf(), 1.0, 1.5);
+        let laptops = day.records.get(&21).copied().unwrap_or_default();
+        assert!(laptops.sold <= 5);
+        assert!(laptops.sold <= laptops.demand);
+    }
+
+    #[test]
+    fn test_budgets_run_out_on_big_tickets() {
+        let day = simulate_shoppers(11, 500, &shelf(), 1.0, 1.5);
+        assert!(day.budget_exhausted > 0);
+    }
+
+    #[test]
+    fn test_overpriced_items_go_to_competitors() {
+        let mut pricey = shelf();
+        pricey[0].price = 8.0; // 4x normal
+        let day = simulate_shoppers(5, 300, &pricey, 1.0, 1.5);
+        assert!(day.lost_to_competitors > 0);
+    }
+}
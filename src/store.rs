@@ -1,4 +1,8 @@
 use std::collections::HashMap;
+use crate::manager::{ManagerMandate, StoreManager};
+use crate::promotion::{LoyaltyProgram, Promotion, PromotionKind};
+use crate::quality::QualityTier;
+use crate::reputation::Reputation;
 
 /// Represents an item in the store's inventory
 #[derive(Debug, Clone)]
@@ -6,6 +10,8 @@ pub struct InventoryItem {
     pub product_id: u32,
     pub quantity: u32,
     pub retail_price: f64,
+    /// Average quality score of the units on the shelf
+    pub quality: f64,
 }
 
 impl InventoryItem {
@@ -15,8 +21,34 @@ impl InventoryItem {
             product_id,
             quantity,
             retail_price,
+            quality: QualityTier::DEFAULT_SCORE,
         }
     }
+
+    /// Returns the quality tier of the units on the shelf
+    pub fn tier(&self) -> QualityTier {
+        QualityTier::from_score(self.quality)
+    }
+}
+
+/// Records a day's demand for a product at a store
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DemandRecord {
+    /// Units customers wanted (unconstrained by stock)
+    pub demand: u32,
+    /// Units actually sold
+    pub sold: u32,
+}
+
+impl DemandRecord {
+    pub fn new(demand: u32, sold: u32) -> Self {
+        DemandRecord { demand, sold }
+    }
+
+    /// Returns the units customers wanted but couldn't buy
+    pub fn lost(&self) -> u32 {
+        self.demand.saturating_sub(self.sold)
+    }
 }
 
 /// Represents an employee working at a store
@@ -27,11 +59,14 @@ pub struct Employee {
 }
 
 impl Employee {
+    /// Daily salary at the starting price level
+    pub const BASE_SALARY: f64 = 50.0;
+
     /// Creates a new employee with the given name and default salary
     pub fn new(name: &str) -> Self {
         Employee {
             name: name.to_string(),
-            salary: 50.0, // $50/day default salary
+            salary: Self::BASE_SALARY, // $50/day default salary
         }
     }
 }
@@ -41,31 +76,60 @@ impl Employee {
 pub struct Store {
     pub id: u32,
     pub name: String,
+    /// Region (city) the store operates in
+    pub region_id: u32,
     pub inventory: HashMap<u32, InventoryItem>,
     pub daily_customers: u32,
     pub employees: Vec<Employee>,
     pub daily_rent: f64,
+    /// Days until the lease is renewed and rent is repriced
+    pub lease_days_remaining: u32,
+    /// Optional manager who runs the store automatically
+    pub manager: Option<StoreManager>,
+    /// Time-limited promotions running in this store
+    pub promotions: Vec<Promotion>,
+    /// Loyalty program (if the store has enrolled)
+    pub loyalty_program: Option<LoyaltyProgram>,
+    /// Customer satisfaction, builds up or erodes over time
+    pub reputation: Reputation,
+    /// Yesterday's demand per product (product_id -> record), including products not stocked
+    pub demand_log: HashMap<u32, DemandRecord>,
 }
 
 impl Store {
-    /// Creates a new store with the given name and ID
-    pub fn new(id: u32, name: &str) -> Self {
+    /// Daily rent at the starting price level
+    pub const BASE_RENT: f64 = 100.0;
+    /// Length of a lease before rent is repriced
+    pub const LEASE_TERM_DAYS: u32 = 180;
+
+    /// Creates a new store with the given name and ID in a region
+    pub fn new(id: u32, name: &str, region_id: u32) -> Self {
         Store {
             id,
             name: name.to_string(),
+            region_id,
             inventory: HashMap::new(),
             daily_customers: 50, // Base number of daily customers
             employees: Vec::new(),
-            daily_rent: 100.0, // $100/day default rent
+            daily_rent: Self::BASE_RENT, // $100/day default rent
+            lease_days_remaining: Self::LEASE_TERM_DAYS,
+            manager: None,
+            promotions: Vec::new(),
+            loyalty_program: None,
+            reputation: Reputation::new(),
+            demand_log: HashMap::new(),
         }
     }
 
-    /// Hires a new employee (max 3 employees per store)
-    pub fn hire_employee(&mut self, name: &str) -> Result<(), String> {
+    /// Hires a new employee at the given daily salary (max 3 employees per store)
+    pub fn hire_employee(&mut self, name: &str, salary: f64) -> Result<(), String> {
         if self.employees.len() >= 3 {
             return Err("Maximum of 3 employees per store".to_string());
         }
-        self.employees.push(Employee::new(name));
+        self.employees.push(Employee {
+            salary,
+            ..Employee::new(name)
+        });
         Ok(())
     }
 
@@ -77,29 +141,177 @@ impl Store {
         Ok(self.employees.remove(index))
     }
 
+    /// Hires a manager at the going wage for the wage index (replaces any existing manager)
+    pub fn hire_manager(&mut self, name: &str, mandate: ManagerMandate, wage_index: f64) -> Option<StoreManager> {
+        self.manager.replace(StoreManager {
+            salary: StoreManager::BASE_SALARY * wage_index,
+            ..StoreManager::new(name, mandate)
+        })
+    }
+
+    /// Fires the store manager
+    pub fn fire_manager(&mut self) -> Result<StoreManager, String> {
+        self.manager.take().ok_or_else(|| "This store has no manager".to_string())
+    }
+
+    /// Returns true if the store has a manager
+    pub fn has_manager(&self) -> bool {
+        self.manager.is_some()
+    }
+
+    /// Starts a new promotion (max 5 running per store)
+    pub fn add_promotion(&mut self, kind: PromotionKind, days: u32) -> Result<(), String> {
+        if self.promotions.len() >= 5 {
+            return Err("Maximum of 5 promotions per store".to_string());
+        }
+        if days == 0 {
+            return Err("Promotion must run at least 1 day".to_string());
+        }
+        kind.validate()?;
+        self.promotions.push(Promotion::new(kind, days));
+        Ok(())
+    }
+
+    /// Cancels a promotion by index
+    pub fn cancel_promotion(&mut self, index: usize) -> Result<Promotion, String> {
+        if index >= self.promotions.len() {
+            return Err("Invalid promotion index".to_string());
+        }
+        Ok(self.promotions.remove(index))
+    }
+
+    /// Returns the promotions that can take effect today
+    /// (a bundle only runs while every product in it is on the shelf)
+    pub fn active_promotions(&self) -> Vec<Promotion> {
+        self.promotions
+            .iter()
+            .filter(|p| match &p.kind {
+                PromotionKind::Bundle { product_ids, .. } => {
+                    product_ids.iter().all(|id| self.get_quantity(*id) > 0)
+                }
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Counts down all promotions and removes finished ones
+    /// Returns the promotions that ended
+    pub fn advance_promotions(&mut self) -> Vec<Promotion> {
+        let mut ended = Vec::new();
+        let mut still_running = Vec::new();
+        for mut promotion in self.promotions.drain(..) {
+            if promotion.tick() {
+                ended.push(promotion);
+            } else {
+                still_running.push(promotion);
+            }
+        }
+        self.promotions = still_running;
+        ended
+    }
+
+    /// Enrolls the store in a loyalty program
+    pub fn enroll_loyalty_program(&mut self) -> Result<(), String> {
+        if self.loyalty_program.is_some() {
+            return Err("This store already has a loyalty program".to_string());
+        }
+        self.loyalty_program = Some(LoyaltyProgram::new());
+        Ok(())
+    }
+
+    /// Raises (or cuts) every salary by a cost-of-living factor
+    pub fn apply_wage_review(&mut self, factor: f64) {
+        for employee in &mut self.employees {
+            employee.salary *= factor;
+        }
+        if let Some(manager) = self.manager.as_mut() {
+            manager.salary *= factor;
+        }
+    }
+
+    /// Raises any employee paid below the minimum wage up to it
+    /// Returns the number of employees given a raise
+    pub fn apply_minimum_wage(&mut self, minimum_wage: f64) -> u32 {
+        let mut raised = 0;
+        for employee in self.employees.iter_mut().filter(|e| e.salary < minimum_wage) {
+            employee.salary = minimum_wage;
+            raised += 1;
+        }
+        raised
+    }
+
+    /// Signs a new lease with rent at the given price level
+    pub fn sign_lease(&mut self, price_level: f64) {
+        self.daily_rent = Self::BASE_RENT * price_level;
+        self.lease_days_remaining = Self::LEASE_TERM_DAYS;
+    }
+
+    /// Counts down the lease; on expiry renews it at the current price level
+    /// Returns (old_rent, new_rent) if the lease was renewed
+    pub fn advance_lease(&mut self, price_level: f64) -> Option<(f64, f64)> {
+        self.lease_days_remaining = self.lease_days_remaining.saturating_sub(1);
+        if self.lease_days_remaining > 0 {
+            return None;
+        }
+        let old_rent = self.daily_rent;
+        self.sign_lease(price_level);
+        Some((old_rent, self.daily_rent))
+    }
+
+    /// Calculates total daily salaries (employees + manager)
+    pub fn total_salaries(&self) -> f64 {
+        let employee_salaries: f64 = self.employees.iter().map(|e| e.salary).sum();
+        let manager_salary = self.manager.as_ref().map(|m| m.salary).unwrap_or(0.0);
+        employee_salaries + manager_salary
+    }
+
     /// Calculates total daily expenses (rent + salaries)
     pub fn daily_expenses(&self) -> f64 {
-        let total_salaries: f64 = self.employees.iter().map(|e| e.salary).sum();
-        self.daily_rent + total_salaries
+        self.daily_rent + self.total_salaries()
+    }
+
+    /// Returns base traffic adjusted by the store's reputation
+    pub fn base_traffic(&self) -> f64 {
+        self.daily_customers as f64 * self.reputation.traffic_multiplier()
     }
 
-    /// Calculates effective customer count (base + employee bonus)
+    /// Calculates effective customer count (base traffic + employee bonus + loyalty repeat visits)
     /// Each employee adds 20% more customers, max 3 employees (+60%)
     pub fn effective_customers(&self) -> u32 {
         let bonus_multiplier = 1.0 + (self.employees.len() as f64 * 0.2);
-        (self.daily_customers as f64 * bonus_multiplier) as u32
+        let loyalty_multiplier = self
+            .loyalty_program
+            .as_ref()
+            .map(|l| l.visit_multiplier())
+            .unwrap_or(1.0);
+        (self.base_traffic() * bonus_multiplier * loyalty_multiplier) as u32
     }
 
     /// Adds inventory to the store
     pub fn add_inventory(&mut self, product_id: u32, quantity: u32, retail_price: f64) {
-        if let Some(item) = self.inventory.get_mut(&product_id) {
-            item.quantity += quantity;
-        } else {
-            self.inventory.insert(
-                product_id,
-                InventoryItem::new(product_id, quantity, retail_price),
-            );
+        self.add_inventory_with_quality(product_id, quantity, retail_price, QualityTier::DEFAULT_SCORE);
+    }
+
+    /// Adds inventory of a known quality, averaging it with the units already on the shelf
+    pub fn add_inventory_with_quality(&mut self, product_id: u32, quantity: u32, retail_price: f64, quality: f64) {
+        let item = self
+            .inventory
+            .entry(product_id)
+            .or_insert_with(|| InventoryItem::new(product_id, 0, retail_price));
+        let total = item.quantity + quantity;
+        if total > 0 {
+            item.quality = (item.quality * item.quantity as f64 + quality * quantity as f64) / total as f64;
         }
+        item.quantity = total;
+    }
+
+    /// Returns the quality tier of a product on the shelf
+    pub fn quality_tier(&self, product_id: u32) -> QualityTier {
+        self.inventory
+            .get(&product_id)
+            .map(|item| item.tier())
+            .unwrap_or(QualityTier::Standard)
     }
 
     /// Sets the retail price for a product
@@ -145,8 +357,33 @@ impl Store {
             .sum()
     }
 
+    /// Returns yesterday's unconstrained demand for a product (0 if no data yet)
+    pub fn last_demand(&self, product_id: u32) -> u32 {
+        self.demand_log
+            .get(&product_id)
+            .map(|record| record.demand)
+            .unwrap_or(0)
+    }
+
     /// Returns total number of items in inventory
     pub fn total_items(&self) -> u32 {
         self.inventory.values().map(|item| item.quantity).sum()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lost_sales_are_unmet_demand() {
+        assert_eq!(DemandRecord::new(12, 5).lost(), 7);
+        // Selling more than was asked for (never happens) doesn't underflow
+        assert_eq!(DemandRecord::new(3, 5).lost(), 0);
+
+        let mut store = Store::new(1, "Shop", 1);
+        assert_eq!(store.last_demand(1), 0);
+        store.demand_log.insert(1, DemandRecord::new(12, 5));
+        assert_eq!(store.last_demand(1), 12);
+    }
+}
@@ -0,0 +1,305 @@
+/// Tax rates charged on the business
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaxPolicy {
+    /// Share of retail revenue remitted as sales tax
+    pub sales_tax_rate: f64,
+    /// Employer tax on salaries
+    pub payroll_tax_rate: f64,
+    /// Tax on each quarter's taxable profit
+    pub corporate_tax_rate: f64,
+}
+
+impl TaxPolicy {
+    pub fn new(sales_tax_rate: f64, payroll_tax_rate: f64, corporate_tax_rate: f64) -> Self {
+        TaxPolicy {
+            sales_tax_rate,
+            payroll_tax_rate,
+            corporate_tax_rate,
+        }
+    }
+}
+
+impl Default for TaxPolicy {
+    fn default() -> Self {
+        Self::new(0.06, 0.08, 0.21)
+    }
+}
+
+/// Deductible business expenses for a filing period
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TaxDeductions {
+    /// Inventory and raw material purchases
+    pub cost_of_goods: f64,
+    pub rent: f64,
+    pub salaries: f64,
+    pub payroll_tax: f64,
+    pub interest: f64,
+    /// Wear on stores and factories (spread over their useful life)
+    pub depreciation: f64,
+    /// Marketing, loyalty rewards and other running costs
+    pub other: f64,
+}
+
+impl TaxDeductions {
+    pub fn total(&self) -> f64 {
+        self.cost_of_goods
+            + self.rent
+            + self.salaries
+            + self.payroll_tax
+            + self.interest
+            + self.depreciation
+            + self.other
+    }
+}
+
+/// A property being written off over its useful life
+#[derive(Debug, Clone)]
+pub struct DepreciatingAsset {
+    pub name: String,
+    pub cost: f64,
+    pub days_remaining: u32,
+}
+
+impl DepreciatingAsset {
+    /// Days over which stores and factories are written off
+    pub const USEFUL_LIFE_DAYS: u32 = 720;
+
+    pub fn new(name: &str, cost: f64) -> Self {
+        DepreciatingAsset {
+            name: name.to_string(),
+            cost,
+            days_remaining: Self::USEFUL_LIFE_DAYS,
+        }
+    }
+
+    /// Returns one day's depreciation
+    pub fn daily_depreciation(&self) -> f64 {
+        self.cost / Self::USEFUL_LIFE_DAYS as f64
+    }
+
+    /// Returns the cost not yet written off
+    pub fn book_value(&self) -> f64 {
+        self.daily_depreciation() * self.days_remaining as f64
+    }
+}
+
+/// A completed quarterly corporate tax filing
+#[derive(Debug, Clone)]
+pub struct TaxFiling {
+    pub day: u32,
+    pub quarter: u32,
+    pub revenue: f64,
+    pub deductions: TaxDeductions,
+    /// Earlier losses used to reduce this quarter's taxable income
+    pub losses_applied: f64,
+    pub taxable_income: f64,
+    pub tax_due: f64,
+}
+
+impl TaxFiling {
+    /// Returns the profit before tax (negative for a loss)
+    pub fn profit(&self) -> f64 {
+        self.revenue - self.deductions.total()
+    }
+}
+
+/// The player's tax books: current quarter, losses carried forward and past filings
+#[derive(Debug, Clone, Default)]
+pub struct TaxLedger {
+    pub policy: TaxPolicy,
+    /// Revenue this quarter (after sales tax)
+    pub period_revenue: f64,
+    pub period_deductions: TaxDeductions,
+    pub assets: Vec<DepreciatingAsset>,
+    /// Losses from earlier quarters that offset future profit
+    pub loss_carryforward: f64,
+    pub filings: Vec<TaxFiling>,
+    /// Taxes paid so far (sales, payroll, corporate)
+    pub total_sales_tax: f64,
+    pub total_payroll_tax: f64,
+    pub total_corporate_tax: f64,
+}
+
+impl TaxLedger {
+    /// Days between corporate tax filings
+    pub const FILING_PERIOD_DAYS: u32 = 90;
+
+    pub fn new(policy: TaxPolicy) -> Self {
+        TaxLedger {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if a corporate tax return is due at the end of the given day
+    pub fn is_filing_day(day: u32) -> bool {
+        day > 0 && day.is_multiple_of(Self::FILING_PERIOD_DAYS)
+    }
+
+    /// Records a day's retail revenue, returns the sales tax owed on it
+    pub fn record_sales(&mut self, revenue: f64) -> f64 {
+        let tax = revenue * self.policy.sales_tax_rate;
+        self.period_revenue += revenue - tax;
+        self.total_sales_tax += tax;
+        tax
+    }
+
+    /// Records revenue not subject to sales tax (exports, seconds sold to outlets)
+    pub fn record_revenue(&mut self, revenue: f64) {
+        self.period_revenue += revenue;
+    }
+
+    /// Records a day's salaries, returns the payroll tax owed on them
+    pub fn record_payroll(&mut self, salaries: f64) -> f64 {
+        let tax = salaries * self.policy.payroll_tax_rate;
+        self.period_deductions.salaries += salaries;
+        self.period_deductions.payroll_tax += tax;
+        self.total_payroll_tax += tax;
+        tax
+    }
+
+    /// Records a day's rent
+    pub fn record_rent(&mut self, rent: f64) {
+        self.period_deductions.rent += rent;
+    }
+
+    /// Records interest accrued on loans
+    pub fn record_interest(&mut self, interest: f64) {
+        self.period_deductions.interest += interest;
+    }
+
+    /// Records inventory or raw material purchases
+    pub fn record_purchase(&mut self, amount: f64) {
+        self.period_deductions.cost_of_goods += amount;
+    }
+
+    /// Records other deductible running costs (marketing, loyalty rewards)
+    pub fn record_expense(&mut self, amount: f64) {
+        self.period_deductions.other += amount;
+    }
+
+    /// Starts depreciating a newly bought property
+    pub fn add_asset(&mut self, name: &str, cost: f64) {
+        self.assets.push(DepreciatingAsset::new(name, cost));
+    }
+
+    /// Takes a sold property off the books: writes off its remaining book value
+    /// and books the sale proceeds as revenue
+    /// Returns the gain on the sale (negative for a loss)
+    pub fn dispose_asset(&mut self, name: &str, proceeds: f64) -> f64 {
+        let book_value = match self.assets.iter().position(|a| a.name == name) {
+            Some(idx) => self.assets.remove(idx).book_value(),
+            // Already fully written off
+            None => 0.0,
+        };
+        self.period_deductions.depreciation += book_value;
+        self.period_revenue += proceeds;
+        proceeds - book_value
+    }
+
+    /// Books one day of depreciation on all assets, returns the amount
+    pub fn depreciate(&mut self) -> f64 {
+        let mut total = 0.0;
+        for asset in &mut self.assets {
+            total += asset.daily_depreciation();
+            asset.days_remaining -= 1;
+        }
+        self.assets.retain(|a| a.days_remaining > 0);
+        self.period_deductions.depreciation += total;
+        total
+    }
+
+    /// Files the quarterly return: taxes profit after earlier losses, carries
+    /// any loss forward, and starts a new period
+    pub fn file_return(&mut self, day: u32) -> TaxFiling {
+        let deductions = self.period_deductions;
+        let profit = self.period_revenue - deductions.total();
+
+        let (losses_applied, taxable_income) = if profit > 0.0 {
+            let applied = self.loss_carryforward.min(profit);
+            self.loss_carryforward -= applied;
+            (applied, profit - applied)
+        } else {
+            self.loss_carryforward += -profit;
+            (0.0, 0.0)
+        };
+        let tax_due = taxable_income * self.policy.corporate_tax_rate;
+        self.total_corporate_tax += tax_due;
+
+        let filing = TaxFiling {
+            day,
+            quarter: day / Self::FILING_PERIOD_DAYS,
+            revenue: self.period_revenue,
+            deductions,
+            losses_applied,
+            taxable_income,
+            tax_due,
+        };
+        self.filings.push(filing.clone());
+
+        self.period_revenue = 0.0;
+        self.period_deductions = TaxDeductions::default();
+        filing
+    }
+
+    /// Estimates the corporate tax owed so far this quarter
+    pub fn estimated_tax(&self) -> f64 {
+        let profit = self.period_revenue - self.period_deductions.total() - self.loss_carryforward;
+        profit.max(0.0) * self.policy.corporate_tax_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sales_and_payroll_tax() {
+        let mut ledger = TaxLedger::new(TaxPolicy::new(0.1, 0.05, 0.2));
+        assert!((ledger.record_sales(1000.0) - 100.0).abs() < 1e-9);
+        assert!((ledger.period_revenue - 900.0).abs() < 1e-9);
+        assert!((ledger.record_payroll(200.0) - 10.0).abs() < 1e-9);
+        assert!((ledger.period_deductions.total() - 210.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_losses_carry_forward() {
+        let mut ledger = TaxLedger::new(TaxPolicy::new(0.0, 0.0, 0.2));
+        ledger.record_purchase(500.0);
+        let loss = ledger.file_return(90);
+        assert_eq!(loss.tax_due, 0.0);
+        assert!((ledger.loss_carryforward - 500.0).abs() < 1e-9);
+
+        ledger.record_sales(800.0);
+        let filing = ledger.file_return(180);
+        assert!((filing.losses_applied - 500.0).abs() < 1e-9);
+        assert!((filing.tax_due - 60.0).abs() < 1e-9);
+        assert_eq!(ledger.loss_carryforward, 0.0);
+    }
+
+    #[test]
+    fn test_depreciation_writes_off_cost() {
+        let mut ledger = TaxLedger::default();
+        ledger.add_asset("Store", 7200.0);
+        let total: f64 = (0..DepreciatingAsset::USEFUL_LIFE_DAYS).map(|_| ledger.depreciate()).sum();
+        assert!((total - 7200.0).abs() < 1e-6);
+        assert!(ledger.assets.is_empty());
+    }
+
+    #[test]
+    fn test_disposed_asset_stops_depreciating() {
+        let mut ledger = TaxLedger::default();
+        ledger.add_asset("Truck #1", 7200.0);
+        ledger.add_asset("Store", 7200.0);
+        for _ in 0..360 {
+            ledger.depreciate();
+        }
+        // Half written off, sold for less than the remaining 3600
+        let gain = ledger.dispose_asset("Truck #1", 3000.0);
+        assert!((gain + 600.0).abs() < 1e-6);
+        assert_eq!(ledger.assets.len(), 1);
+        assert!((ledger.period_revenue - 3000.0).abs() < 1e-6);
+        assert!((ledger.period_deductions.depreciation - 7200.0 - 3600.0).abs() < 1e-6);
+        assert!((ledger.depreciate() - 10.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,372 @@
+use crate::product::Product;
+
+/// Foreign currencies suppliers and buyers trade in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Euro,
+    Yen,
+    Yuan,
+}
+
+impl Currency {
+    pub fn all() -> Vec<Currency> {
+        vec![Currency::Euro, Currency::Yen, Currency::Yuan]
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Euro => "EUR",
+            Currency::Yen => "JPY",
+            Currency::Yuan => "CNY",
+        }
+    }
+
+    /// Dollars per unit of the currency at the start of the game
+    pub fn base_rate(&self) -> f64 {
+        match self {
+            Currency::Euro => 1.10,
+            Currency::Yen => 0.0068,
+            Currency::Yuan => 0.14,
+        }
+    }
+}
+
+/// Today's value of a foreign currency in dollars
+#[derive(Debug, Clone)]
+pub struct ExchangeRate {
+    pub currency: Currency,
+    /// Dollars per unit of the currency
+    pub rate: f64,
+    pub previous_rate: f64,
+}
+
+impl ExchangeRate {
+    /// Largest random daily move (as a fraction of the rate)
+    pub const DAILY_VOLATILITY: f64 = 0.01;
+    /// Share of the gap to the base rate closed each day
+    pub const MEAN_REVERSION: f64 = 0.02;
+
+    pub fn new(currency: Currency) -> Self {
+        ExchangeRate {
+            currency,
+            rate: currency.base_rate(),
+            previous_rate: currency.base_rate(),
+        }
+    }
+
+    /// Moves the rate by a random shock (`roll` from 0.0 to 1.0) with a pull back toward its base rate
+    pub fn advance(&mut self, roll: f64) {
+        self.previous_rate = self.rate;
+        let shock = (roll * 2.0 - 1.0) * Self::DAILY_VOLATILITY;
+        let reversion = (self.currency.base_rate() / self.rate - 1.0) * Self::MEAN_REVERSION;
+        self.rate *= 1.0 + shock + reversion;
+    }
+
+    /// Returns today's change as a fraction
+    pub fn change(&self) -> f64 {
+        self.rate / self.previous_rate - 1.0
+    }
+}
+
+/// An overseas company selling goods priced in its own currency
+#[derive(Debug, Clone)]
+pub struct ForeignSupplier {
+    pub id: u32,
+    pub name: String,
+    pub country: String,
+    pub currency: Currency,
+    /// (product_id, unit price in the supplier's currency)
+    pub catalog: Vec<(u32, f64)>,
+    pub shipping_days: u32,
+    /// Import duty charged on the dollar value of the goods
+    pub tariff_rate: f64,
+}
+
+/// An overseas company buying manufactured goods, paying in its own currency
+#[derive(Debug, Clone)]
+pub struct ExportBuyer {
+    pub id: u32,
+    pub name: String,
+    pub country: String,
+    pub currency: Currency,
+    /// (product_id, unit price in the buyer's currency)
+    pub catalog: Vec<(u32, f64)>,
+    pub shipping_days: u32,
+}
+
+/// Prices a product in a foreign currency at a multiple of its normal dollar price
+fn foreign_price(products: &[Product], product_id: u32, factor: f64, currency: Currency) -> Option<(u32, f64)> {
+    let product = products.iter().find(|p| p.id == product_id)?;
+    Some((product_id, product.base_price * factor / currency.base_rate()))
+}
+
+impl ForeignSupplier {
+    /// Returns the default overseas suppliers
+    /// Product IDs: 5=Headphones, 6=Phone Charger, 7=USB Cable, 8=T-Shirt, 10=Socks,
+    ///   12=Steel, 13=Fabric, 14=Plastic, 15=Electronic Components
+    pub fn default_suppliers(products: &[Product]) -> Vec<ForeignSupplier> {
+        let supplier = |id: u32, name: &str, country: &str, currency: Currency, items: &[(u32, f64)], shipping_days: u32, tariff_rate: f64| {
+            ForeignSupplier {
+                id,
+                name: name.to_string(),
+                country: country.to_string(),
+                currency,
+                catalog: items
+                    .iter()
+                    .filter_map(|&(product_id, factor)| foreign_price(products, product_id, factor, currency))
+                    .collect(),
+                shipping_days,
+                tariff_rate,
+            }
+        };
+        vec![
+            supplier(1, "Rhein Stahlwerk", "Germany", Currency::Euro, &[(12, 0.8), (15, 0.9)], 6, 0.10),
+            supplier(2, "Osaka Trading", "Japan", Currency::Yen, &[(15, 0.8), (5, 0.75), (6, 0.8)], 8, 0.08),
+            supplier(
+                3,
+                "Shenzhen Supply",
+                "China",
+                Currency::Yuan,
+                &[(13, 0.6), (14, 0.55), (7, 0.5), (8, 0.6), (10, 0.55)],
+                12,
+                0.25,
+            ),
+        ]
+    }
+
+    /// Returns the supplier's unit price for a product in its currency
+    pub fn unit_price(&self, product_id: u32) -> Option<f64> {
+        self.catalog
+            .iter()
+            .find(|(id, _)| *id == product_id)
+            .map(|(_, price)| *price)
+    }
+}
+
+impl ExportBuyer {
+    /// Returns the default overseas buyers
+    /// Product IDs: 16=Wooden Chair, 17=Steel Table, 18=Designer Jacket, 19=Blender,
+    ///   20=Smartphone, 21=Laptop
+    pub fn default_buyers(products: &[Product]) -> Vec<ExportBuyer> {
+        let buyer = |id: u32, name: &str, country: &str, currency: Currency, items: &[(u32, f64)], shipping_days: u32| {
+            ExportBuyer {
+                id,
+                name: name.to_string(),
+                country: country.to_string(),
+                currency,
+                catalog: items
+                    .iter()
+                    .filter_map(|&(product_id, factor)| foreign_price(products, product_id, factor, currency))
+                    .collect(),
+                shipping_days,
+            }
+        };
+        vec![
+            buyer(1, "Maison Continental", "France", Currency::Euro, &[(16, 1.3), (17, 1.25), (18, 1.35)], 6),
+            buyer(2, "Tokyo Digital", "Japan", Currency::Yen, &[(19, 1.2), (20, 1.3), (21, 1.25)], 8),
+            buyer(3, "Canton Wholesale", "China", Currency::Yuan, &[(16, 1.1), (17, 1.15), (19, 1.1)], 12),
+        ]
+    }
+
+    /// Returns what the buyer pays per unit of a product in its currency
+    pub fn unit_price(&self, product_id: u32) -> Option<f64> {
+        self.catalog
+            .iter()
+            .find(|(id, _)| *id == product_id)
+            .map(|(_, price)| *price)
+    }
+}
+
+/// Dollar cost of an import order at today's exchange rate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImportQuote {
+    /// Goods cost in the supplier's currency
+    pub foreign_cost: f64,
+    /// Goods cost in dollars
+    pub goods_cost: f64,
+    pub tariff: f64,
+    pub shipping_days: u32,
+}
+
+impl ImportQuote {
+    pub fn total(&self) -> f64 {
+        self.goods_cost + self.tariff
+    }
+}
+
+/// Where imported goods are delivered
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Destination {
+    Store(u32),
+    Factory(u32),
+}
+
+/// What a shipment is for
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShipmentKind {
+    /// Goods coming in, already paid for (landed cost per unit)
+    Import { destination: Destination, unit_cost: f64 },
+    /// Goods going out, paid in foreign currency on arrival
+    Export { currency: Currency, payment: f64 },
+}
+
+/// Goods at sea
+#[derive(Debug, Clone)]
+pub struct Shipment {
+    pub id: u32,
+    /// Supplier or buyer name
+    pub partner: String,
+    pub product_id: u32,
+    pub quantity: u32,
+    pub days_remaining: u32,
+    pub kind: ShipmentKind,
+}
+
+impl Shipment {
+    /// Counts down one day, returns true if the shipment has arrived
+    pub fn tick(&mut self) -> bool {
+        self.days_remaining = self.days_remaining.saturating_sub(1);
+        self.days_remaining == 0
+    }
+}
+
+/// Exchange rates, overseas partners and shipments in transit
+#[derive(Debug, Clone)]
+pub struct ForeignTrade {
+    pub rates: Vec<ExchangeRate>,
+    pub suppliers: Vec<ForeignSupplier>,
+    pub buyers: Vec<ExportBuyer>,
+    pub shipments: Vec<Shipment>,
+    next_shipment_id: u32,
+}
+
+impl ForeignTrade {
+    pub fn new(products: &[Product]) -> Self {
+        ForeignTrade {
+            rates: Currency::all().into_iter().map(ExchangeRate::new).collect(),
+            suppliers: ForeignSupplier::default_suppliers(products),
+            buyers: ExportBuyer::default_buyers(products),
+            shipments: Vec::new(),
+            next_shipment_id: 1,
+        }
+    }
+
+    /// Returns today's dollars per unit of a currency
+    pub fn rate(&self, currency: Currency) -> f64 {
+        self.rates
+            .iter()
+            .find(|r| r.currency == currency)
+            .map(|r| r.rate)
+            .unwrap_or(currency.base_rate())
+    }
+
+    /// Moves every exchange rate; `roll(i)` supplies a random value for the i-th currency
+    pub fn advance_rates(&mut self, roll: impl Fn(usize) -> f64) {
+        for (i, rate) in self.rates.iter_mut().enumerate() {
+            rate.advance(roll(i));
+        }
+    }
+
+    pub fn supplier(&self, supplier_id: u32) -> Option<&ForeignSupplier> {
+        self.suppliers.iter().find(|s| s.id == supplier_id)
+    }
+
+    pub fn buyer(&self, buyer_id: u32) -> Option<&ExportBuyer> {
+        self.buyers.iter().find(|b| b.id == buyer_id)
+    }
+
+    /// Prices an import order at today's exchange rate
+    pub fn quote_import(&self, supplier_id: u32, product_id: u32, quantity: u32) -> Result<ImportQuote, String> {
+        let supplier = self.supplier(supplier_id).ok_or("Supplier not found")?;
+        let unit_price = supplier
+            .unit_price(product_id)
+            .ok_or_else(|| format!("{} doesn't sell that product", supplier.name))?;
+        let foreign_cost = unit_price * quantity as f64;
+        let goods_cost = foreign_cost * self.rate(supplier.currency);
+        Ok(ImportQuote {
+            foreign_cost,
+            goods_cost,
+            tariff: goods_cost * supplier.tariff_rate,
+            shipping_days: supplier.shipping_days,
+        })
+    }
+
+    /// Returns what an export would earn (foreign amount, dollars at today's rate)
+    pub fn quote_export(&self, buyer_id: u32, product_id: u32, quantity: u32) -> Result<(f64, f64), String> {
+        let buyer = self.buyer(buyer_id).ok_or("Buyer not found")?;
+        let unit_price = buyer
+            .unit_price(product_id)
+            .ok_or_else(|| format!("{} doesn't buy that product", buyer.name))?;
+        let foreign = unit_price * quantity as f64;
+        Ok((foreign, foreign * self.rate(buyer.currency)))
+    }
+
+    /// Puts a shipment to sea, returns its ID
+    pub fn ship(&mut self, partner: &str, product_id: u32, quantity: u32, days: u32, kind: ShipmentKind) -> u32 {
+        let id = self.next_shipment_id;
+        self.next_shipment_id += 1;
+        self.shipments.push(Shipment {
+            id,
+            partner: partner.to_string(),
+            product_id,
+            quantity,
+            days_remaining: days.max(1),
+            kind,
+        });
+        id
+    }
+
+    /// Counts down shipments in transit, returns the ones that arrived
+    pub fn advance_shipments(&mut self) -> Vec<Shipment> {
+        let mut arrived = Vec::new();
+        let mut i = 0;
+        while i < self.shipments.len() {
+            if self.shipments[i].tick() {
+                arrived.push(self.shipments.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+        arrived
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_rate_moves_affect_import_cost() {
+        let products = Product::default_products();
+        let mut trade = ForeignTrade::new(&products);
+        let before = trade.quote_import(1, 12, 100).unwrap();
+        assert!((before.tariff - before.goods_cost * 0.10).abs() < 1e-9);
+
+        // Strong euro: imports from Germany cost more
+        trade.advance_rates(|_| 1.0);
+        let after = trade.quote_import(1, 12, 100).unwrap();
+        assert!(after.total() > before.total());
+        assert!(trade.quote_import(1, 1, 10).is_err());
+    }
+
+    #[test]
+    fn test_rates_revert_to_base() {
+        let mut rate = ExchangeRate::new(Currency::Euro);
+        rate.rate = Currency::Euro.base_rate() * 1.3;
+        for _ in 0..200 {
+            rate.advance(0.5);
+        }
+        assert!((rate.rate / Currency::Euro.base_rate() - 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_shipments_arrive() {
+        let products = Product::default_products();
+        let mut trade = ForeignTrade::new(&products);
+        trade.ship("Tokyo Digital", 20, 5, 2, ShipmentKind::Export { currency: Currency::Yen, payment: 1000.0 });
+        assert!(trade.advance_shipments().is_empty());
+        let arrived = trade.advance_shipments();
+        assert_eq!(arrived.len(), 1);
+        assert!(trade.shipments.is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+/// Goods shipped from one of the player's factories to one of their stores
+#[derive(Debug, Clone)]
+pub struct InternalTransfer {
+    pub day: u32,
+    pub factory_id: u32,
+    pub store_id: u32,
+    pub product_id: u32,
+    pub quantity: u32,
+    /// Price per unit charged to the store (the factory's production cost)
+    pub transfer_price: f64,
+}
+
+impl InternalTransfer {
+    /// Returns the value of the shipment at the transfer price
+    pub fn value(&self) -> f64 {
+        self.transfer_price * self.quantity as f64
+    }
+}
+
+/// Totals of internal transfers for one factory or store
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TransferTotals {
+    pub units: u32,
+    pub value: f64,
+}
+
+/// Transfer pricing settings and the record of factory-to-store shipments
+#[derive(Debug, Clone)]
+pub struct TransferLedger {
+    /// Markup over transfer price used for new store lines (e.g., 50.0 for 50%)
+    pub retail_markup: f64,
+    pub transfers: Vec<InternalTransfer>,
+}
+
+impl TransferLedger {
+    /// Default retail markup over production cost
+    pub const DEFAULT_MARKUP: f64 = 50.0;
+
+    pub fn new(retail_markup: f64) -> Self {
+        TransferLedger {
+            retail_markup: retail_markup.max(0.0),
+            transfers: Vec::new(),
+        }
+    }
+
+    /// Returns the starting retail price for goods at a transfer price
+    pub fn retail_price(&self, transfer_price: f64) -> f64 {
+        transfer_price * (1.0 + self.retail_markup / 100.0)
+    }
+
+    /// Records a shipment
+    pub fn record(&mut self, transfer: InternalTransfer) {
+        self.transfers.push(transfer);
+    }
+
+    /// Returns everything a factory has shipped (its internal revenue)
+    pub fn factory_totals(&self, factory_id: u32) -> TransferTotals {
+        self.totals(|t| t.factory_id == factory_id)
+    }
+
+    /// Returns everything a store has received (its cost of goods from own factories)
+    pub fn store_totals(&self, store_id: u32) -> TransferTotals {
+        self.totals(|t| t.store_id == store_id)
+    }
+
+    fn totals(&self, filter: impl Fn(&InternalTransfer) -> bool) -> TransferTotals {
+        self.transfers
+            .iter()
+            .filter(|t| filter(t))
+            .fold(TransferTotals::default(), |acc, t| TransferTotals {
+                units: acc.units + t.quantity,
+                value: acc.value + t.value(),
+            })
+    }
+}
+
+impl Default for TransferLedger {
+    fn default() -> Self {
+        TransferLedger::new(Self::DEFAULT_MARKUP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_totals_by_factory_and_store() {
+        let mut ledger = TransferLedger::default();
+        let shipment = |factory_id, store_id, quantity| InternalTransfer {
+            day: 1,
+            factory_id,
+            store_id,
+            product_id: 16,
+            quantity,
+            transfer_price: 4.0,
+        };
+        ledger.record(shipment(1, 1, 10));
+        ledger.record(shipment(1, 2, 5));
+        ledger.record(shipment(2, 1, 1));
+
+        assert_eq!(ledger.factory_totals(1), TransferTotals { units: 15, value: 60.0 });
+        assert_eq!(ledger.store_totals(1), TransferTotals { units: 11, value: 44.0 });
+        assert!((ledger.retail_price(4.0) - 6.0).abs() < 1e-9);
+    }
+}
@@ -1,7 +1,21 @@
+use crate::distribution::DistributionPolicy;
 use crate::economy::Market;
 use crate::game::{DayResult, GameState};
 use crate::loan::{Loan, LoanType};
+use crate::logistics::{Logistics, Truck, TruckOwnership};
+use crate::factory::{BlockReason, FactoryWorker, OrderLogEntry, OrderTarget};
+use crate::manager::{ManagerMandate, StoreManager};
+use crate::marketing::{CampaignChannel, CampaignTarget, MarketingCampaign};
+use crate::product::{Category, RelationKind};
+use crate::promotion::{LoyaltyProgram, PromotionKind};
+use crate::planning::{ProductionTarget, Shortfall};
+use crate::procurement::ProcurementRule;
+use crate::quality::{self, DefectHandling, MaterialGrade, QualityTier};
 use crate::product::Product;
+use crate::shopper::DemandModel;
+use crate::store::Employee;
+use crate::tax::{TaxLedger, TaxPolicy};
+use crate::trade::ShipmentKind;
 use std::io::{self, Write};
 
 /// Menu options for the main game loop
@@ -16,6 +30,9 @@ pub enum MenuChoice {
     ManageFactories,
     ManageLoans,
     ManageInvestments,
+    ReadNews,
+    TaxSummary,
+    ForeignTrade,
     Quit,
 }
 
@@ -31,7 +48,7 @@ pub fn display_header(game: &GameState) {
     let daily_expenses = game.total_daily_expenses();
     let economic_state = &game.market.economic_state;
     let total_debt = game.player.total_debt();
-    let market_share = game.competitive_market.player_market_share * 100.0;
+    let market_share = game.current_region().market.player_market_share * 100.0;
     let portfolio_value = game.portfolio_value();
 
     println!("╔══════════════════════════════════════════════════════════════╗");
@@ -43,6 +60,18 @@ pub fn display_header(game: &GameState) {
         game.player.cash,
         game.player.net_worth()
     );
+    let calendar = game.calendar();
+    let holiday = calendar
+        .holiday
+        .as_ref()
+        .map(|h| format!("Holiday: {}", h.name))
+        .unwrap_or_else(|| if calendar.weekday.is_weekend() { "Weekend".to_string() } else { String::new() });
+    println!(
+        "║  {:22} │ {:6} │ {:26}  ║",
+        calendar.date_string(),
+        calendar.season.name(),
+        holiday
+    );
     println!(
         "║  Store: {:16} │  Daily Expenses: ${:>10.2}   ║",
         current_store.name,
@@ -58,6 +87,17 @@ pub fn display_header(game: &GameState) {
         total_debt,
         portfolio_value
     );
+    println!(
+        "║  Price Index: {:>6.3}   │  Inflation: {:>+5.1}%/yr            ║",
+        game.market.price_level,
+        game.market.inflation_rate() * 100.0
+    );
+    for event in &game.market.events {
+        println!(
+            "║  {:<58}  ║",
+            format!("EVENT: {} ({} days left)", event.name, event.days_remaining)
+        );
+    }
     println!("╚══════════════════════════════════════════════════════════════╝");
     println!();
 }
@@ -74,11 +114,14 @@ pub fn display_menu() -> MenuChoice {
     println!("  [7] Manage factories");
     println!("  [8] Manage loans");
     println!("  [9] Manage investments");
+    println!("  [N] Read the news");
+    println!("  [T] Tax summary");
+    println!("  [F] Foreign trade");
     println!("  [0] Quit game");
     println!();
 
     loop {
-        let input = read_input("Enter choice (0-9): ");
+        let input = read_input("Enter choice (0-9, N, T, F): ");
         match input.trim() {
             "1" => return MenuChoice::ViewStore,
             "2" => return MenuChoice::BuyInventory,
@@ -89,10 +132,298 @@ pub fn display_menu() -> MenuChoice {
             "7" => return MenuChoice::ManageFactories,
             "8" => return MenuChoice::ManageLoans,
             "9" => return MenuChoice::ManageInvestments,
+            "n" | "N" => return MenuChoice::ReadNews,
+            "t" | "T" => return MenuChoice::TaxSummary,
+            "f" | "F" => return MenuChoice::ForeignTrade,
             "0" => return MenuChoice::Quit,
-            _ => println!("Invalid choice. Please enter 0-9."),
+            _ => println!("Invalid choice. Please enter 0-9, N, T or F."),
+        }
+    }
+}
+
+/// Displays the news archive, newest first
+pub fn display_news(game: &GameState) {
+    println!("╔══════════════════════════════════════════════════════════════╗");
+    println!("║                        DAILY NEWS                            ║");
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    if game.news.headlines.is_empty() {
+        println!("║  No news yet - advance a day to get the first edition.       ║");
+    } else {
+        let mut last_day = None;
+        for headline in game.news.recent(20) {
+            if last_day != Some(headline.day) {
+                println!("║  Day {}:", headline.day);
+                last_day = Some(headline.day);
+            }
+            println!("║    [{}] {}", headline.category.name(), headline.text);
+        }
+    }
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  Headlines are noisy: rumors can be false alarms and the     ║");
+    println!("║  economy column reads the trend a week ahead, roughly.       ║");
+    println!("╚══════════════════════════════════════════════════════════════╝");
+    wait_for_enter();
+}
+
+/// Displays tax rates, this quarter's running books and past filings,
+/// and lets the player change the rates
+pub fn handle_tax_summary(game: &mut GameState) {
+    let taxes = &game.taxes;
+    let policy = &taxes.policy;
+    let deductions = &taxes.period_deductions;
+    let days_to_filing = TaxLedger::FILING_PERIOD_DAYS - game.day % TaxLedger::FILING_PERIOD_DAYS;
+
+    println!("╔══════════════════════════════════════════════════════════════╗");
+    println!("║                        TAX SUMMARY                           ║");
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!(
+        "║  {:<58}  ║",
+        format!(
+            "Rates: sales {:.1}% │ payroll {:.1}% │ corporate {:.1}%",
+            policy.sales_tax_rate * 100.0,
+            policy.payroll_tax_rate * 100.0,
+            policy.corporate_tax_rate * 100.0
+        )
+    );
+    println!("║  {:<58}  ║", format!("Next corporate filing in {} day(s)", days_to_filing));
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  THIS QUARTER:                                               ║");
+    println!("║  {:<58}  ║", format!("  Revenue (after sales tax)  ${:>12.2}", taxes.period_revenue));
+    for (label, amount) in [
+        ("Cost of goods", deductions.cost_of_goods),
+        ("Rent", deductions.rent),
+        ("Salaries", deductions.salaries),
+        ("Payroll tax", deductions.payroll_tax),
+        ("Loan interest", deductions.interest),
+        ("Depreciation", deductions.depreciation),
+        ("Other expenses", deductions.other),
+    ] {
+        println!("║  {:<58}  ║", format!("  - {:<24} ${:>12.2}", label, amount));
+    }
+    println!(
+        "║  {:<58}  ║",
+        format!("  Taxable profit so far      ${:>12.2}", taxes.period_revenue - deductions.total())
+    );
+    println!("║  {:<58}  ║", format!("  Estimated corporate tax    ${:>12.2}", taxes.estimated_tax()));
+    if taxes.loss_carryforward > 0.0 {
+        println!("║  {:<58}  ║", format!("  Losses carried forward     ${:>12.2}", taxes.loss_carryforward));
+    }
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  TAXES PAID:                                                 ║");
+    println!("║  {:<58}  ║", format!("  Sales tax                  ${:>12.2}", taxes.total_sales_tax));
+    println!("║  {:<58}  ║", format!("  Payroll tax                ${:>12.2}", taxes.total_payroll_tax));
+    println!("║  {:<58}  ║", format!("  Corporate income tax       ${:>12.2}", taxes.total_corporate_tax));
+    if !taxes.filings.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  PAST FILINGS:                                               ║");
+        for filing in taxes.filings.iter().rev().take(8) {
+            println!(
+                "║  {:<58}  ║",
+                format!(
+                    "  Q{} (day {}): profit ${:.2}, tax ${:.2}",
+                    filing.quarter,
+                    filing.day,
+                    filing.profit(),
+                    filing.tax_due
+                )
+            );
+        }
+    }
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  [1] Change tax rates                                        ║");
+    println!("║  [0] Back                                                    ║");
+    println!("╚══════════════════════════════════════════════════════════════╝");
+    println!();
+
+    if read_input("Enter choice: ").trim() != "1" {
+        return;
+    }
+    let current = game.taxes.policy;
+    let mut rates = [current.sales_tax_rate, current.payroll_tax_rate, current.corporate_tax_rate];
+    for (label, rate) in ["Sales", "Payroll", "Corporate"].iter().zip(rates.iter_mut()) {
+        let prompt = format!("{} tax % (Enter to keep {:.1}%): ", label, *rate * 100.0);
+        let input = read_input(&prompt);
+        if input.is_empty() {
+            continue;
+        }
+        match input.parse::<f64>() {
+            Ok(percent) => *rate = percent / 100.0,
+            Err(_) => {
+                println!("Invalid rate.");
+                wait_for_enter();
+                return;
+            }
+        }
+    }
+    match game.set_tax_policy(TaxPolicy::new(rates[0], rates[1], rates[2])) {
+        Ok(()) => println!("Tax rates updated."),
+        Err(e) => println!("Error: {}", e),
+    }
+    wait_for_enter();
+}
+
+/// Handles the foreign trade menu: exchange rates, imports and exports
+pub fn handle_foreign_trade(game: &mut GameState) {
+    loop {
+        clear_screen();
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                      FOREIGN TRADE                           ║");
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        for rate in &game.trade.rates {
+            println!(
+                "║  {:<58}  ║",
+                format!(
+                    "1 {} = ${:.4} ({:+.1}% today)",
+                    rate.currency.code(),
+                    rate.rate,
+                    rate.change() * 100.0
+                )
+            );
+        }
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  IN TRANSIT:                                                 ║");
+        if game.trade.shipments.is_empty() {
+            println!("║    (None)                                                    ║");
+        }
+        for shipment in &game.trade.shipments {
+            let product_name = game.get_product(shipment.product_id).map(|p| p.name.as_str()).unwrap_or("?");
+            let direction = match shipment.kind {
+                ShipmentKind::Import { .. } => "from",
+                ShipmentKind::Export { .. } => "to",
+            };
+            println!(
+                "║    {} x {} {} {} - {} day(s) left",
+                shipment.quantity, product_name, direction, shipment.partner, shipment.days_remaining
+            );
+        }
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  [1] Import goods (retail to store, raw materials to factory)║");
+        println!("║  [2] Export finished goods from current factory              ║");
+        println!("║  [0] Back to main menu                                       ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+        println!();
+
+        match read_input("Enter choice: ").trim() {
+            "0" => return,
+            "1" => handle_import(game),
+            "2" => handle_export(game),
+            _ => println!("Invalid choice."),
+        }
+    }
+}
+
+/// Handles ordering goods from an overseas supplier
+fn handle_import(game: &mut GameState) {
+    println!();
+    println!("Overseas suppliers (landed price per unit incl. tariff):");
+    for supplier in &game.trade.suppliers {
+        println!(
+            "  [{}] {} ({}) - {} days shipping, {:.0}% tariff",
+            supplier.id,
+            supplier.name,
+            supplier.country,
+            supplier.shipping_days,
+            supplier.tariff_rate * 100.0
+        );
+        for (product_id, _) in &supplier.catalog {
+            if let (Some(product), Ok(quote)) = (
+                game.get_product(*product_id),
+                game.quote_import(supplier.id, *product_id, 1),
+            ) {
+                let domestic = game.market.get_wholesale_price(*product_id).unwrap_or(0.0);
+                println!(
+                    "      {:>3} {:22} ${:>8.2}  (home ${:.2})",
+                    product.id, product.name, quote.total(), domestic
+                );
+            }
+        }
+    }
+
+    let supplier_id = match read_number("Supplier (0 to cancel): ") {
+        Some(0) | None => return,
+        Some(id) => id,
+    };
+    let product_id = match read_number("Product ID: ") {
+        Some(id) => id,
+        None => return,
+    };
+    let quantity = match read_number("Quantity: ") {
+        Some(q) if q > 0 => q,
+        _ => return,
+    };
+
+    match game.place_import_order(supplier_id, product_id, quantity) {
+        Ok(quote) => println!(
+            "Ordered! Goods ${:.2} + tariff ${:.2} = ${:.2}, arriving in {} days.",
+            quote.goods_cost,
+            quote.tariff,
+            quote.total(),
+            quote.shipping_days
+        ),
+        Err(e) => println!("ERROR: {}", e),
+    }
+    wait_for_enter();
+}
+
+/// Handles shipping finished goods to an overseas buyer
+fn handle_export(game: &mut GameState) {
+    let factory = match game.current_factory() {
+        Some(f) => f,
+        None => {
+            println!("No factory selected. Buy or select a factory first!");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    println!();
+    println!("Overseas buyers (price per unit at today's rate):");
+    for buyer in &game.trade.buyers {
+        println!(
+            "  [{}] {} ({}) - {} days shipping, pays in {}",
+            buyer.id,
+            buyer.name,
+            buyer.country,
+            buyer.shipping_days,
+            buyer.currency.code()
+        );
+        for (product_id, _) in &buyer.catalog {
+            if let (Some(product), Ok((_, value))) = (
+                game.get_product(*product_id),
+                game.trade.quote_export(buyer.id, *product_id, 1),
+            ) {
+                println!(
+                    "      {:>3} {:22} ${:>8.2}  ({} in stock)",
+                    product.id,
+                    product.name,
+                    value,
+                    factory.get_finished_good(*product_id)
+                );
+            }
         }
     }
+
+    let buyer_id = match read_number("Buyer (0 to cancel): ") {
+        Some(0) | None => return,
+        Some(id) => id,
+    };
+    let product_id = match read_number("Product ID: ") {
+        Some(id) => id,
+        None => return,
+    };
+    let quantity = match read_number("Quantity: ") {
+        Some(q) if q > 0 => q,
+        _ => return,
+    };
+
+    match game.export_goods(buyer_id, product_id, quantity) {
+        Ok(value) => println!(
+            "Shipped! Worth ${:.2} at today's rate - paid in foreign currency on arrival.",
+            value
+        ),
+        Err(e) => println!("ERROR: {}", e),
+    }
+    wait_for_enter();
 }
 
 /// Displays the store inventory
@@ -110,29 +441,59 @@ pub fn display_store(game: &GameState) {
         store.effective_customers(),
         store.daily_rent
     );
+    println!(
+        "║  Reputation: {:>5.1} ({:9})  │  Traffic x{:.2}               ║",
+        store.reputation.score,
+        store.reputation.label(),
+        store.reputation.traffic_multiplier()
+    );
     println!("╠══════════════════════════════════════════════════════════════╣");
 
     if store.inventory.is_empty() {
         println!("║  (No inventory yet - buy some products!)                     ║");
     } else {
         println!(
-            "║  {:20} {:>8} {:>12} {:>12}      ║",
-            "Product", "Qty", "Retail $", "Markup %"
+            "║  {:20} {:>6} {:>10} {:>9} {:>10}  ║",
+            "Product", "Qty", "Retail $", "Markup %", "Could Sell"
         );
-        println!("║  {:─<20} {:─>8} {:─>12} {:─>12}      ║", "", "", "", "");
+        println!("║  {:─<20} {:─>6} {:─>10} {:─>9} {:─>10}  ║", "", "", "", "", "");
 
         for (product_id, item) in &store.inventory {
             if let Some(product) = game.get_product(*product_id) {
-                let wholesale = game.market.get_wholesale_price(*product_id).unwrap_or(0.0);
+                let wholesale = game.store_wholesale_price(*product_id).unwrap_or(0.0);
                 let markup = Market::calculate_markup(wholesale, item.retail_price);
                 println!(
-                    "║  {:20} {:>8} {:>12.2} {:>11.1}%      ║",
-                    product.name, item.quantity, item.retail_price, markup
+                    "║  {:20} {:>6} {:>10.2} {:>8.1}% {:>10}  ║",
+                    product.name,
+                    item.quantity,
+                    item.retail_price,
+                    markup,
+                    store.last_demand(*product_id)
                 );
             }
         }
     }
 
+    // Products we don't carry that customers asked for yesterday
+    let mut missed: Vec<(&str, u32)> = store
+        .demand_log
+        .iter()
+        .filter(|(product_id, record)| {
+            !store.inventory.contains_key(*product_id) && record.demand > 0
+        })
+        .filter_map(|(product_id, record)| {
+            game.get_product(*product_id).map(|p| (p.name.as_str(), record.demand))
+        })
+        .collect();
+    if !missed.is_empty() {
+        missed.sort_by_key(|(_, demand)| std::cmp::Reverse(*demand));
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  NOT STOCKED - you could have sold (yesterday):              ║");
+        for (name, demand) in missed.iter().take(5) {
+            println!("║    {:20} {:>6} units                            ║", name, demand);
+        }
+    }
+
     println!("╠══════════════════════════════════════════════════════════════╣");
     println!(
         "║  Total Items: {:>6}  │  Inventory Value: ${:>10.2}       ║",
@@ -158,7 +519,7 @@ pub fn display_buy_menu(game: &GameState) {
         if !product.product_type.can_sell_retail() {
             continue;
         }
-        let wholesale = game.market.get_wholesale_price(product.id).unwrap_or(product.base_price);
+        let wholesale = game.store_wholesale_price(product.id).unwrap_or(product.base_price);
         println!(
             "║  {:>3} {:20} ${:>10.2} {:>15}        ║",
             product.id,
@@ -272,8 +633,7 @@ pub fn handle_buy_inventory(game: &mut GameState) {
                 };
 
                 let unit_price = game
-                    .market
-                    .get_wholesale_price(product_id)
+                    .store_wholesale_price(product_id)
                     .unwrap_or(product.base_price);
 
                 // Check if product already in cart, if so add to quantity
@@ -381,8 +741,7 @@ pub fn handle_buy_inventory(game: &mut GameState) {
                         };
 
                         let unit_price = game
-                            .market
-                            .get_wholesale_price(product_id)
+                            .store_wholesale_price(product_id)
                             .unwrap_or(product.base_price);
 
                         if let Some(existing) = cart.iter_mut().find(|i| i.product_id == product_id)
@@ -434,7 +793,7 @@ pub fn handle_set_prices(game: &mut GameState) {
 
         for (product_id, item) in &store.inventory {
             if let Some(product) = game.get_product(*product_id) {
-                let wholesale = game.market.get_wholesale_price(*product_id).unwrap_or(0.0);
+                let wholesale = game.store_wholesale_price(*product_id).unwrap_or(0.0);
                 let markup = Market::calculate_markup(wholesale, item.retail_price);
                 println!(
                     "║  {:>3} {:20} ${:>8.2} ${:>8.2} {:>8.1}%   ║",
@@ -454,24 +813,44 @@ pub fn handle_set_prices(game: &mut GameState) {
             }
         };
 
-        let wholesale = game.market.get_wholesale_price(product_id).unwrap_or(0.0);
+        let wholesale = game.store_wholesale_price(product_id).unwrap_or(0.0);
         if wholesale == 0.0 {
             println!("Product not in inventory.");
             continue;
         }
 
         println!("Wholesale price: ${:.2}", wholesale);
+        if let Some(cap) = game.get_product(product_id).and_then(|p| game.price_cap(p)) {
+            println!("Price controls in effect: maximum ${:.2}", cap);
+        }
         println!("Suggested markups: 25%=${:.2}, 50%=${:.2}, 100%=${:.2}",
             Market::suggest_retail_price(wholesale, 25.0),
             Market::suggest_retail_price(wholesale, 50.0),
             Market::suggest_retail_price(wholesale, 100.0)
         );
+        for rel in &game.market.relationships {
+            if let Some(partner) = rel.partner_of(product_id).and_then(|id| game.get_product(id)) {
+                let effect = match rel.kind {
+                    RelationKind::Substitute => "substitute - the cheaper one steals sales",
+                    RelationKind::Complement => "complement - stocking both lifts sales",
+                };
+                println!("  Related: {} ({})", partner.name, effect);
+            }
+        }
 
-        let new_price = match read_float("Enter new retail price: $") {
-            Some(p) if p > 0.0 => p,
-            _ => {
-                println!("Invalid price.");
-                continue;
+        let input = read_input("Enter new retail price, or [S] to suggest the best price: $");
+        let new_price = if input.eq_ignore_ascii_case("s") {
+            match suggest_price(game, product_id) {
+                Some(p) => p,
+                None => continue,
+            }
+        } else {
+            match input.parse::<f64>() {
+                Ok(p) if p > 0.0 => p,
+                _ => {
+                    println!("Invalid price.");
+                    continue;
+                }
             }
         };
 
@@ -491,6 +870,48 @@ pub fn handle_set_prices(game: &mut GameState) {
     }
 }
 
+/// Shows a sales forecast for a product and suggests the most profitable price
+/// Returns the price to apply, or None if the player keeps the current one
+fn suggest_price(game: &GameState, product_id: u32) -> Option<f64> {
+    let days = read_number("Days the current stock should last [7]: ")
+        .filter(|d| *d > 0)
+        .unwrap_or(7);
+
+    let forecasts = match game.forecast_markups(product_id, days) {
+        Ok(f) => f,
+        Err(e) => {
+            println!("ERROR: {}", e);
+            return None;
+        }
+    };
+    let best = match game.suggest_optimal_price(product_id, days) {
+        Ok(b) => b,
+        Err(e) => {
+            println!("ERROR: {}", e);
+            return None;
+        }
+    };
+
+    println!();
+    println!("FORECAST over {} days ({} in stock):", days, game.current_store().get_quantity(product_id));
+    println!("  {:>9} {:>9} {:>8} {:>10} {:>10}", "Price", "Per day", "Units", "Revenue", "Profit");
+    for forecast in forecasts.iter().chain(std::iter::once(&best)) {
+        println!(
+            "  ${:>8.2} {:>9.1} {:>8.0} ${:>9.2} ${:>9.2}",
+            forecast.price, forecast.daily_units, forecast.units, forecast.revenue, forecast.profit
+        );
+    }
+    println!();
+    println!("Suggested price: ${:.2} (expected profit ${:.2})", best.price, best.profit);
+
+    let choice = read_input("Apply suggested price? [Y/n]: ");
+    if choice.eq_ignore_ascii_case("n") {
+        None
+    } else {
+        Some((best.price * 100.0).round() / 100.0)
+    }
+}
+
 /// Displays the results of advancing a day
 pub fn display_day_result(result: &DayResult, new_day: u32, game: &GameState) {
     println!();
@@ -508,9 +929,41 @@ pub fn display_day_result(result: &DayResult, new_day: u32, game: &GameState) {
         (result.economic_state.sales_multiplier() * 100.0) as i32,
         (result.economic_state.price_multiplier() * 100.0) as i32
     );
+    println!(
+        "║  PRICE INDEX: {:.3} (inflation {:+.1}%/yr)                    ║",
+        result.price_level,
+        result.inflation_rate * 100.0
+    );
+    println!("║  DATE: {:50}    ║", result.date);
+    if let Some(ref holiday) = result.holiday {
+        println!("║    *** {} - holiday shoppers are out! ***", holiday);
+    }
     if let Some(ref change) = result.economic_change {
         println!("║    >>> {} <<<                           ║", change);
     }
+    for (name, description, days) in &result.events_started {
+        println!("║    !!! BREAKING: {} ({} days) - {}", name, days, description);
+    }
+    for name in &result.events_ended {
+        println!("║    {} is over", name);
+    }
+    if !result.active_events.is_empty() {
+        let running: Vec<String> = result
+            .active_events
+            .iter()
+            .map(|(name, days)| format!("{} ({}d)", name, days))
+            .collect();
+        println!("║    Events: {}", running.join(", "));
+    }
+
+    // News section
+    if !result.headlines.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  NEWS:                                                       ║");
+        for (section, headline) in &result.headlines {
+            println!("║    [{}] {}", section, headline);
+        }
+    }
 
     // Sales section
     println!("╠══════════════════════════════════════════════════════════════╣");
@@ -530,6 +983,76 @@ pub fn display_day_result(result: &DayResult, new_day: u32, game: &GameState) {
         result.total_revenue
     );
 
+    // Lost sales section (biggest misses first)
+    if !result.lost_sales.is_empty() {
+        let mut lost: Vec<_> = result.lost_sales.iter().collect();
+        lost.sort_by(|a, b| b.3.total_cmp(&a.3));
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  LOST SALES (demand you couldn't meet):                      ║");
+        for (store_name, product_name, qty, revenue) in lost.iter().take(5) {
+            println!(
+                "║  {:<58}  ║",
+                format!("  {}: {} x {} (${:.2})", store_name, qty, product_name, revenue)
+            );
+        }
+        if lost.len() > 5 {
+            println!("║  {:<58}  ║", format!("  ...and {} more", lost.len() - 5));
+        }
+        println!(
+            "║    Revenue missed: ${:>10.2}                               ║",
+            result.total_lost_revenue
+        );
+    }
+
+    // Promotions section
+    if !result.promotion_sales.is_empty() || !result.promotions_ended.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  PROMOTIONS:                                                 ║");
+        for (store_name, promotion, qty, discount) in &result.promotion_sales {
+            println!(
+                "║    {}: {} - {} sold (${:.2} off)",
+                store_name, promotion, qty, discount
+            );
+        }
+        if result.promotion_discounts > 0.01 {
+            println!(
+                "║    Total discounts given: ${:>10.2}                        ║",
+                result.promotion_discounts
+            );
+        }
+        for (store_name, promotion) in &result.promotions_ended {
+            println!("║    Ended at {}: {}", store_name, promotion);
+        }
+    }
+
+    // Store manager section
+    let active_reports: Vec<_> = result.manager_reports.iter().filter(|r| !r.is_empty()).collect();
+    if !active_reports.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  STORE MANAGERS:                                             ║");
+        for report in active_reports {
+            println!("║    {} ({}):", report.store_name, report.manager_name);
+            for name in &report.hired {
+                println!("║      Hired {}", name);
+            }
+            for name in &report.fired {
+                println!("║      Let go {}", name);
+            }
+            for (name, old, new) in &report.price_changes {
+                println!("║      Repriced {} ${:.2} -> ${:.2}", name, old, new);
+            }
+            for (name, qty, cost) in &report.restocked {
+                println!("║      Restocked {} x {} (${:.2})", qty, name, cost);
+            }
+            if !report.skipped.is_empty() {
+                println!("║      Out of budget for: {}", report.skipped.join(", "));
+            }
+            if report.total_spent() > 0.0 {
+                println!("║      Spent on restock: ${:.2}", report.total_spent());
+            }
+        }
+    }
+
     // Production section (if any factories)
     if !result.production_completed.is_empty() {
         println!("╠══════════════════════════════════════════════════════════════╣");
@@ -540,12 +1063,50 @@ pub fn display_day_result(result: &DayResult, new_day: u32, game: &GameState) {
                 .map(|p| p.name.as_str())
                 .unwrap_or("Unknown");
             println!(
-                "║    {} x {} ({})                              ║",
-                prod.quantity, product_name, prod.recipe_name
+                "║    {} x {} {} ({}) @ ${:.2}/unit",
+                prod.quantity,
+                prod.tier().name(),
+                product_name,
+                prod.recipe_name,
+                prod.unit_cost
             );
         }
     }
 
+    if !result.defects.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  DEFECTS:                                                    ║");
+        for (factory, product, qty, sold) in &result.defects {
+            let fate = if *sold { "sold as seconds" } else { "scrapped" };
+            println!("║    {}: {} x {} {}", factory, qty, product, fate);
+        }
+        if result.seconds_revenue > 0.0 {
+            println!("║    Seconds revenue: ${:.2}", result.seconds_revenue);
+        }
+    }
+
+    if !result.procurement_reports.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  PROCUREMENT:                                                ║");
+        for report in &result.procurement_reports {
+            println!("║    {}:", report.factory_name);
+            for (name, qty, cost) in &report.purchased {
+                println!("║      Bought {} x {} (${:.2})", qty, name, cost);
+            }
+            if !report.skipped.is_empty() {
+                println!("║      Out of budget for: {}", report.skipped.join(", "));
+            }
+        }
+    }
+
+    if !result.standing_orders.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  STANDING ORDERS:                                            ║");
+        for (factory_name, entry) in &result.standing_orders {
+            println!("║    {}: {}", factory_name, describe_order_entry(game, entry));
+        }
+    }
+
     // Expenses section
     println!("╠══════════════════════════════════════════════════════════════╣");
     println!("║  EXPENSES:                                                   ║");
@@ -568,6 +1129,13 @@ pub fn display_day_result(result: &DayResult, new_day: u32, game: &GameState) {
         );
     }
 
+    if result.loyalty_rewards > 0.01 {
+        println!(
+            "║    Loyalty rewards: ${:>10.2}                              ║",
+            result.loyalty_rewards
+        );
+    }
+
     println!(
         "║    Total Expenses: ${:>10.2}                               ║",
         result.total_expenses
@@ -631,49 +1199,189 @@ pub fn display_day_result(result: &DayResult, new_day: u32, game: &GameState) {
                 factory, store, qty, product
             );
         }
+        for (factory, product, qty) in &result.distribution_held {
+            println!("║    {} kept {} x {} at the factory", factory, qty, product);
+        }
     }
 
-    // Market & Competitors section
-    println!("╠══════════════════════════════════════════════════════════════╣");
-    println!(
-        "║  MARKET: Your share: {:>5.1}%                                  ║",
-        result.player_market_share * 100.0
-    );
-
-    // Competitor events
-    if !result.competitor_events.is_empty() {
-        println!("║  COMPETITOR NEWS:                                            ║");
-        for event in &result.competitor_events {
-            println!("║    >>> {}                    ║", event);
+    if !result.deliveries_arrived.is_empty() || result.shipping_costs > 0.01 {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  LOGISTICS:                                                  ║");
+        for (factory, store, units) in &result.deliveries_arrived {
+            println!("║    Delivered {} units from {} to {}", units, factory, store);
+        }
+        if result.shipping_costs > 0.01 {
+            println!("║    Shipping and fleet costs: ${:.2}", result.shipping_costs);
         }
     }
 
-    // Stock market section (if player has holdings or significant price moves)
-    let significant_moves: Vec<_> = result.stock_changes.iter()
-        .filter(|(_, old, new)| {
-            let change_pct = ((new - old) / old * 100.0).abs();
-            change_pct > 3.0  // Only show moves > 3%
-        })
+    // Reputation section (only noteworthy moves)
+    let reputation_moves: Vec<_> = result
+        .reputation_changes
+        .iter()
+        .filter(|(_, old, new)| (new - old).abs() >= 1.0)
         .collect();
-
-    if !significant_moves.is_empty() || result.dividends_earned > 0.01 {
+    if !reputation_moves.is_empty() {
         println!("╠══════════════════════════════════════════════════════════════╣");
-        println!("║  STOCK MARKET:                                               ║");
-
-        for (symbol, old, new) in &significant_moves {
-            let change = new - old;
-            let pct = (change / old) * 100.0;
-            let arrow = if change > 0.0 { "▲" } else { "▼" };
+        println!("║  REPUTATION:                                                 ║");
+        for (store_name, old, new) in reputation_moves {
+            let arrow = if new > old { "▲" } else { "▼" };
             println!(
-                "║    {} ${:.2} {} {:.1}%                                      ║",
-                symbol, new, arrow, pct.abs()
+                "║    {}: {:.1} {} {:.1}",
+                store_name, old, arrow, new
             );
         }
+    }
 
-        if result.dividends_earned > 0.01 {
+    // Agent-based shopper section
+    if !result.shopper_stats.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  SHOPPERS:                                                   ║");
+        for (store_name, day) in &result.shopper_stats {
             println!(
-                "║    Dividends earned: ${:.2}                                  ║",
-                result.dividends_earned
+                "║    {}: {} visited, {} bought (avg basket {:.1} items)",
+                store_name,
+                day.shoppers,
+                day.buyers,
+                day.average_basket()
+            );
+            if day.budget_exhausted > 0 || day.lost_to_competitors > 0 {
+                println!(
+                    "║      {} ran out of budget, {} items bought at competitors instead",
+                    day.budget_exhausted, day.lost_to_competitors
+                );
+            }
+        }
+    }
+
+    // Inflation section: lease renewals and wage reviews
+    if !result.lease_renewals.is_empty() || result.wage_review.is_some() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  COST OF LIVING:                                             ║");
+        for (name, old_rent, new_rent) in &result.lease_renewals {
+            println!(
+                "║    Lease renewed at {}: rent ${:.2} -> ${:.2}/day",
+                name, old_rent, new_rent
+            );
+        }
+        if let Some(factor) = result.wage_review {
+            println!(
+                "║    Wage review: all salaries adjusted {:+.1}%",
+                (factor - 1.0) * 100.0
+            );
+        }
+    }
+
+    // Foreign trade section
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    let rates: Vec<String> = result
+        .exchange_rates
+        .iter()
+        .map(|(code, rate, change)| format!("{} ${:.4} ({:+.1}%)", code, rate, change * 100.0))
+        .collect();
+    println!("║  FX: {}", rates.join("  "));
+    for (supplier, product, quantity, destination) in &result.imports_arrived {
+        println!("║    Import arrived: {} x {} from {} -> {}", quantity, product, supplier, destination);
+    }
+    for (supplier, product, quantity) in &result.imports_undeliverable {
+        println!("║    Import from {} undeliverable: {} x {} (no location to receive it)", supplier, quantity, product);
+    }
+    for (buyer, product, quantity, received) in &result.exports_paid {
+        println!("║    Export delivered: {} x {} to {} - paid ${:.2}", quantity, product, buyer, received);
+    }
+
+    // Regulation section
+    if !result.regulation_events.is_empty() || !result.fines.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  GOVERNMENT:                                                 ║");
+        for event in &result.regulation_events {
+            println!("║    {}", event);
+        }
+        for (reason, amount) in &result.fines {
+            println!("║    FINE ${:.2}: {}", amount, reason);
+        }
+    }
+
+    // Taxes section
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!(
+        "║  {:<58}  ║",
+        format!(
+            "TAXES: sales ${:.2} │ payroll ${:.2} │ depreciation ${:.2}",
+            result.sales_tax, result.payroll_tax, result.depreciation
+        )
+    );
+    if let Some(filing) = &result.tax_filing {
+        println!(
+            "║    Q{} corporate tax return filed: profit ${:.2}, tax paid ${:.2}",
+            filing.quarter,
+            filing.profit(),
+            filing.tax_due
+        );
+        if filing.losses_applied > 0.0 {
+            println!("║    Earlier losses used: ${:.2}", filing.losses_applied);
+        }
+    }
+
+    // Marketing section
+    if result.active_campaigns > 0 || !result.campaigns_ended.is_empty() {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  MARKETING: {} campaign(s) running                            ║",
+            result.active_campaigns
+        );
+        for ended in &result.campaigns_ended {
+            println!("║    Campaign ended: {}", ended);
+        }
+    }
+
+    // Market & Competitors section
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!(
+        "║  MARKET: Your share: {:>5.1}%                                  ║",
+        result.player_market_share * 100.0
+    );
+    for (region_name, share, market_size) in &result.regional_shares {
+        println!(
+            "║  {:<58}  ║",
+            format!("  {:<14} {:>5.1}% of {} shoppers", region_name, share * 100.0, market_size)
+        );
+    }
+
+    // Competitor events
+    if !result.competitor_events.is_empty() {
+        println!("║  COMPETITOR NEWS:                                            ║");
+        for event in &result.competitor_events {
+            println!("║    >>> {}                    ║", event);
+        }
+    }
+
+    // Stock market section (if player has holdings or significant price moves)
+    let significant_moves: Vec<_> = result.stock_changes.iter()
+        .filter(|(_, old, new)| {
+            let change_pct = ((new - old) / old * 100.0).abs();
+            change_pct > 3.0  // Only show moves > 3%
+        })
+        .collect();
+
+    if !significant_moves.is_empty() || result.dividends_earned > 0.01 {
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  STOCK MARKET:                                               ║");
+
+        for (symbol, old, new) in &significant_moves {
+            let change = new - old;
+            let pct = (change / old) * 100.0;
+            let arrow = if change > 0.0 { "▲" } else { "▼" };
+            println!(
+                "║    {} ${:.2} {} {:.1}%                                      ║",
+                symbol, new, arrow, pct.abs()
+            );
+        }
+
+        if result.dividends_earned > 0.01 {
+            println!(
+                "║    Dividends earned: ${:.2}                                  ║",
+                result.dividends_earned
             );
         }
     }
@@ -771,7 +1479,14 @@ pub fn handle_manage_stores(game: &mut GameState) {
         println!("╠══════════════════════════════════════════════════════════════╣");
         println!("║  [1] View all stores                                         ║");
         println!("║  [2] Switch active store                                     ║");
-        println!("║  [3] Buy new store ($5,000)                                  ║");
+        println!("║  [3] Buy new store (from $5,000)                             ║");
+        println!("║  [4] Store manager                                           ║");
+        println!("║  [5] Marketing campaigns                                     ║");
+        println!("║  [6] Promotions & loyalty                                    ║");
+        println!(
+            "║  {:<58}  ║",
+            format!("[7] Customer simulation ({})", game.demand_model.name())
+        );
         println!("║  [0] Back to main menu                                       ║");
         println!("╚══════════════════════════════════════════════════════════════╝");
         println!();
@@ -788,11 +1503,67 @@ pub fn handle_manage_stores(game: &mut GameState) {
             "3" => {
                 handle_buy_new_store(game);
             }
+            "4" => {
+                handle_store_manager(game);
+            }
+            "5" => {
+                handle_marketing(game);
+            }
+            "6" => {
+                handle_promotions(game);
+            }
+            "7" => {
+                handle_demand_model(game);
+            }
             _ => println!("Invalid choice."),
         }
     }
 }
 
+/// Handles switching between formula and agent-based customer simulation
+fn handle_demand_model(game: &mut GameState) {
+    clear_screen();
+    println!("╔══════════════════════════════════════════════════════════════╗");
+    println!("║                  CUSTOMER SIMULATION                         ║");
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!(
+        "║  {:<58}  ║",
+        format!("Current mode: {}", game.demand_model.name())
+    );
+    println!("║                                                              ║");
+    println!("║  Formula: smooth expected demand per product.                ║");
+    println!("║  Agent-based: every shopper has a budget, preferences and a  ║");
+    println!("║  shopping list, may buy from a cheaper competitor, and can   ║");
+    println!("║  run out of money. Same seed, same shoppers.                 ║");
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  [1] Formula                                                 ║");
+    println!("║  [2] Agent-based                                             ║");
+    println!("║  [3] Change simulation seed                                  ║");
+    println!("║  [0] Back                                                    ║");
+    println!("╚══════════════════════════════════════════════════════════════╝");
+    println!();
+
+    match read_input("Enter choice: ").trim() {
+        "1" => game.set_demand_model(DemandModel::Formula),
+        "2" => game.set_demand_model(DemandModel::Agents),
+        "3" => match read_number("Enter new seed: ") {
+            Some(seed) => game.simulation_seed = seed as u64,
+            None => {
+                println!("Invalid seed.");
+                wait_for_enter();
+                return;
+            }
+        },
+        _ => return,
+    }
+    println!(
+        "Customer simulation: {} (seed {})",
+        game.demand_model.name(),
+        game.simulation_seed
+    );
+    wait_for_enter();
+}
+
 /// Displays detailed info about all stores
 fn display_all_stores(game: &GameState) {
     clear_screen();
@@ -806,9 +1577,10 @@ fn display_all_stores(game: &GameState) {
         } else {
             ""
         };
+        let region_name = game.region(store.region_id).map(|r| r.name.as_str()).unwrap_or("?");
         println!(
-            "║  Store #{}: {} {}",
-            store.id, store.name, current_marker
+            "║  Store #{}: {} ({}) {}",
+            store.id, store.name, region_name, current_marker
         );
         println!(
             "║    Inventory: {} items (${:.2} value)",
@@ -820,15 +1592,61 @@ fn display_all_stores(game: &GameState) {
             store.employees.len(),
             store.effective_customers()
         );
+        println!(
+            "║    Reputation: {:.1} ({})",
+            store.reputation.score,
+            store.reputation.label()
+        );
         println!(
             "║    Daily Expenses: ${:.2} (Rent: ${:.0}, Salaries: ${:.0})",
             store.daily_expenses(),
             store.daily_rent,
-            store.employees.iter().map(|e| e.salary).sum::<f64>()
+            store.total_salaries()
         );
+        println!("║    Lease renews in {} days", store.lease_days_remaining);
+        match &store.manager {
+            Some(manager) => println!(
+                "║    Manager: {} (margin {:.0}%, budget ${:.0}/day, staff {})",
+                manager.name,
+                manager.mandate.target_margin,
+                manager.mandate.restock_budget,
+                manager.mandate.staffing_level
+            ),
+            None => println!("║    Manager: (none)"),
+        }
         println!("║  ──────────────────────────────────────────────────────────  ║");
     }
 
+    println!("║  REGIONS:                                                    ║");
+    for region in &game.regions {
+        let store_count = game.player.stores.iter().filter(|s| s.region_id == region.id).count();
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "{:<12} shoppers {:>4} │ {} store(s) │ share {:>4.1}% │ rivals {}",
+                region.name,
+                region.market.total_market_size,
+                store_count,
+                region.market.player_market_share * 100.0,
+                region.market.competitors.len()
+            )
+        );
+    }
+    println!(
+        "║  {:<58}  ║",
+        format!(
+            "GOVERNMENT: minimum wage ${:.2}/day │ food price controls: {}",
+            game.regulations.minimum_wage,
+            if game.regulations.price_controls { "ON" } else { "off" }
+        )
+    );
+    for region in &game.regions {
+        let status = match game.regulations.licence_days(region.id) {
+            Some(days) => format!("licensed, renews in {} days", days),
+            None => "no licence".to_string(),
+        };
+        println!("║  {:<58}  ║", format!("  {:<12} {}", region.name, status));
+    }
     println!("╚══════════════════════════════════════════════════════════════╝");
     wait_for_enter();
 }
@@ -866,16 +1684,62 @@ fn handle_switch_store(game: &mut GameState) {
     wait_for_enter();
 }
 
+/// Lists the regions with the local cost of a property and asks the player to pick one
+/// Returns the chosen region ID, or None if cancelled
+fn select_region(game: &GameState, base_cost: f64) -> Option<u32> {
+    println!("Regions:");
+    for (idx, region) in game.regions.iter().enumerate() {
+        println!(
+            "  [{}] {:<12} Shoppers {:>4}  Cost ${:>9.2}  Prices {:>3.0}%  Rivals {}",
+            idx + 1,
+            region.name,
+            region.market.total_market_size,
+            base_cost * region.rent_multiplier,
+            region.price_multiplier * 100.0,
+            region.market.competitors.len()
+        );
+    }
+
+    match read_number("Choose region (0 to cancel): ") {
+        Some(n) if n > 0 && (n as usize) <= game.regions.len() => Some(game.regions[n as usize - 1].id),
+        Some(0) => None,
+        _ => {
+            println!("Invalid region.");
+            None
+        }
+    }
+}
+
 /// Handles buying a new store
 fn handle_buy_new_store(game: &mut GameState) {
-    println!("Buy a new store for $5,000");
+    println!("Buy a new store from ${:.0}", GameState::NEW_STORE_COST);
     println!("Your cash: ${:.2}", game.player.cash);
     println!();
 
-    if game.player.cash < 5000.0 {
-        println!("Not enough cash! You need $5,000.");
-        wait_for_enter();
-        return;
+    let region_id = match select_region(game, GameState::NEW_STORE_COST) {
+        Some(id) => id,
+        None => {
+            wait_for_enter();
+            return;
+        }
+    };
+
+    // Stores need a business licence for the region
+    if !game.regulations.has_licence(region_id) {
+        let fee = game.licence_fee(region_id).unwrap_or(0.0);
+        println!("Opening a store here requires a business licence (${:.2}).", fee);
+        let choice = read_input("Buy a licence? [y/N]: ");
+        if !choice.eq_ignore_ascii_case("y") {
+            return;
+        }
+        match game.buy_business_licence(region_id) {
+            Ok(fee) => println!("Licence granted for ${:.2}.", fee),
+            Err(e) => {
+                println!("ERROR: {}", e);
+                wait_for_enter();
+                return;
+            }
+        }
     }
 
     let name = read_input("Enter name for new store (or 0 to cancel): ");
@@ -883,7 +1747,7 @@ fn handle_buy_new_store(game: &mut GameState) {
         return;
     }
 
-    match game.buy_new_store(&name) {
+    match game.buy_new_store(&name, region_id) {
         Ok(reactions) => {
             println!();
             println!("SUCCESS! Purchased new store: {}", name);
@@ -904,48 +1768,53 @@ fn handle_buy_new_store(game: &mut GameState) {
     wait_for_enter();
 }
 
-/// Handles staff management submenu
-pub fn handle_manage_staff(game: &mut GameState) {
+/// Handles the store manager submenu for the current store
+fn handle_store_manager(game: &mut GameState) {
     loop {
         clear_screen();
         let store = game.current_store();
         println!("╔══════════════════════════════════════════════════════════════╗");
-        println!("║                    MANAGE STAFF                              ║");
+        println!("║                    STORE MANAGER                             ║");
         println!("╠══════════════════════════════════════════════════════════════╣");
-        println!(
-            "║  Store: {:20}  │  Cash: ${:>10.2}   ║",
-            store.name, game.player.cash
-        );
+        println!("║  Store: {:40}             ║", store.name);
         println!("╠══════════════════════════════════════════════════════════════╣");
 
-        if store.employees.is_empty() {
-            println!("║  No employees yet.                                           ║");
-        } else {
-            println!("║  Current Employees:                                          ║");
-            for (idx, emp) in store.employees.iter().enumerate() {
+        match &store.manager {
+            Some(manager) => {
                 println!(
-                    "║    [{}] {:30} ${:.0}/day          ║",
-                    idx + 1,
-                    emp.name,
-                    emp.salary
+                    "║  Manager: {:30} ${:.0}/day          ║",
+                    manager.name, manager.salary
+                );
+                println!(
+                    "║    Target margin:  {:>6.1}%                                  ║",
+                    manager.mandate.target_margin
+                );
+                println!(
+                    "║    Restock budget: ${:>8.2}/day                            ║",
+                    manager.mandate.restock_budget
+                );
+                println!(
+                    "║    Staffing level: {} employee(s)                             ║",
+                    manager.mandate.staffing_level
+                );
+            }
+            None => {
+                println!("║  No manager. A manager restocks, prices and staffs the       ║");
+                println!(
+                    "║  {:<58}  ║",
+                    format!("store automatically each day (${:.0}/day).", game.going_wage(StoreManager::BASE_SALARY))
                 );
             }
         }
 
         println!("╠══════════════════════════════════════════════════════════════╣");
         println!(
-            "║  Daily Customers: {:>3} (base: 50, +20% per employee)        ║",
-            store.effective_customers()
-        );
-        println!(
-            "║  Total Daily Salaries: ${:>6.0}                               ║",
-            store.employees.iter().map(|e| e.salary).sum::<f64>()
+            "║  {:<58}  ║",
+            format!("[1] Hire manager (${:.0}/day)", game.going_wage(StoreManager::BASE_SALARY))
         );
-        println!("╠══════════════════════════════════════════════════════════════╣");
-        println!("║  [1] View employees                                          ║");
-        println!("║  [2] Hire employee ($50/day)                                 ║");
-        println!("║  [3] Fire employee                                           ║");
-        println!("║  [0] Back to main menu                                       ║");
+        println!("║  [2] Change mandate                                          ║");
+        println!("║  [3] Fire manager                                            ║");
+        println!("║  [0] Back                                                    ║");
         println!("╚══════════════════════════════════════════════════════════════╝");
         println!();
 
@@ -953,64 +1822,470 @@ pub fn handle_manage_staff(game: &mut GameState) {
         match input.trim() {
             "0" => return,
             "1" => {
-                display_employees(game);
+                let name = read_input("Enter manager name (or 0 to cancel): ");
+                if name == "0" || name.is_empty() {
+                    continue;
+                }
+                let mandate = match read_mandate() {
+                    Some(m) => m,
+                    None => continue,
+                };
+                match game.hire_store_manager(&name, mandate) {
+                    Ok(Some(replaced)) => println!("Hired {} (replacing {}).", name, replaced),
+                    Ok(None) => println!("Hired {} to run {}.", name, game.current_store().name),
+                    Err(e) => println!("ERROR: {}", e),
+                }
+                wait_for_enter();
             }
             "2" => {
-                handle_hire_employee(game);
+                if !game.current_store().has_manager() {
+                    println!("This store has no manager. Hire one first!");
+                    wait_for_enter();
+                    continue;
+                }
+                let mandate = match read_mandate() {
+                    Some(m) => m,
+                    None => continue,
+                };
+                match game.set_manager_mandate(mandate) {
+                    Ok(()) => println!("Mandate updated."),
+                    Err(e) => println!("ERROR: {}", e),
+                }
+                wait_for_enter();
             }
             "3" => {
-                handle_fire_employee(game);
+                match game.fire_store_manager() {
+                    Ok(fired) => println!("Fired manager: {}", fired.name),
+                    Err(e) => println!("ERROR: {}", e),
+                }
+                wait_for_enter();
             }
             _ => println!("Invalid choice."),
         }
     }
 }
 
-/// Displays employees of the current store
-fn display_employees(game: &GameState) {
-    clear_screen();
-    let store = game.current_store();
-
-    println!("╔══════════════════════════════════════════════════════════════╗");
-    println!("║                 {} - EMPLOYEES                 ║", store.name);
-    println!("╠══════════════════════════════════════════════════════════════╣");
-
-    if store.employees.is_empty() {
-        println!("║  No employees hired yet.                                     ║");
-        println!("║  Hire employees to increase customer traffic!                ║");
-    } else {
-        for (idx, emp) in store.employees.iter().enumerate() {
-            println!(
-                "║  {}. {:40} ${:.0}/day    ║",
-                idx + 1,
-                emp.name,
-                emp.salary
-            );
+/// Prompts for a manager mandate (margin, budget, staffing level)
+fn read_mandate() -> Option<ManagerMandate> {
+    let target_margin = match read_float("Target markup % (e.g. 50): ") {
+        Some(m) if m >= 0.0 => m,
+        _ => {
+            println!("Invalid markup.");
+            wait_for_enter();
+            return None;
+        }
+    };
+    let restock_budget = match read_float("Daily restock budget: $") {
+        Some(b) if b >= 0.0 => b,
+        _ => {
+            println!("Invalid budget.");
+            wait_for_enter();
+            return None;
+        }
+    };
+    let staffing_level = match read_number("Staffing level (0-3 employees): ") {
+        Some(n) if n <= 3 => n as usize,
+        _ => {
+            println!("Invalid staffing level.");
+            wait_for_enter();
+            return None;
         }
+    };
+    Some(ManagerMandate::new(target_margin, restock_budget, staffing_level))
+}
+
+/// Handles the marketing campaigns submenu
+fn handle_marketing(game: &mut GameState) {
+    loop {
+        clear_screen();
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                  MARKETING CAMPAIGNS                         ║");
         println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  Your cash: ${:>10.2}                                      ║", game.player.cash);
         println!(
-            "║  Customer Bonus: +{}% ({} → {} customers/day)             ║",
-            store.employees.len() * 20,
-            store.daily_customers,
-            store.effective_customers()
+            "║  Brand awareness: +{:>4.1}% market power                      ║",
+            (game.player.brand_marketing_multiplier() - 1.0) * 100.0
         );
-    }
+        println!("╠══════════════════════════════════════════════════════════════╣");
 
-    println!("╚══════════════════════════════════════════════════════════════╝");
-    wait_for_enter();
-}
+        if game.player.campaigns.is_empty() {
+            println!("║  No active campaigns.                                        ║");
+        } else {
+            println!("║  Active Campaigns:                                           ║");
+            for campaign in &game.player.campaigns {
+                println!(
+                    "║    #{} {:35} {:>2} day(s) left   ║",
+                    campaign.id,
+                    game.describe_campaign(campaign),
+                    campaign.days_remaining
+                );
+            }
+        }
 
-/// Handles hiring a new employee
-fn handle_hire_employee(game: &mut GameState) {
-    let store = game.current_store();
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  [1] Launch campaign                                         ║");
+        println!("║  [0] Back                                                    ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+        println!();
 
-    if store.employees.len() >= 3 {
+        let input = read_input("Enter choice: ");
+        match input.trim() {
+            "0" => return,
+            "1" => handle_launch_campaign(game),
+            _ => println!("Invalid choice."),
+        }
+    }
+}
+
+/// Handles launching a new advertising campaign
+fn handle_launch_campaign(game: &mut GameState) {
+    println!();
+    println!("Channels (cost per day for a single store):");
+    let channels = CampaignChannel::all();
+    for (idx, channel) in channels.iter().enumerate() {
+        println!(
+            "  [{}] {:8} ${:>6.0}/day  (reach {:.2})",
+            idx + 1,
+            channel.name(),
+            channel.daily_cost(),
+            channel.reach()
+        );
+    }
+    let channel = match read_number("Choose channel (0 to cancel): ") {
+        Some(0) => return,
+        Some(n) if n > 0 && (n as usize) <= channels.len() => channels[n as usize - 1],
+        _ => {
+            println!("Invalid channel.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    println!();
+    println!("Target:");
+    println!("  [1] Current store ({})      x1.0 cost", game.current_store().name);
+    println!("  [2] A product category       x1.5 cost");
+    println!("  [3] Whole brand              x2.5 cost");
+    let target = match read_number("Choose target (0 to cancel): ") {
+        Some(0) => return,
+        Some(1) => CampaignTarget::Store(game.current_store().id),
+        Some(2) => {
+            let categories = Category::retail_categories();
+            for (idx, category) in categories.iter().enumerate() {
+                println!("  [{}] {}", idx + 1, category.name());
+            }
+            match read_number("Choose category: ") {
+                Some(n) if n > 0 && (n as usize) <= categories.len() => {
+                    CampaignTarget::Category(categories[n as usize - 1])
+                }
+                _ => {
+                    println!("Invalid category.");
+                    wait_for_enter();
+                    return;
+                }
+            }
+        }
+        Some(3) => CampaignTarget::Brand,
+        _ => {
+            println!("Invalid target.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    let prompt = format!(
+        "Duration in days ({}-{}): ",
+        MarketingCampaign::MIN_DAYS,
+        MarketingCampaign::MAX_DAYS
+    );
+    let days = match read_number(&prompt) {
+        Some(d) => d,
+        None => {
+            println!("Invalid duration.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    let cost = MarketingCampaign::cost(channel, target, days);
+    let confirm = read_input(&format!("Launch for ${:.2}? [Y/n]: ", cost));
+    if confirm.to_lowercase() == "n" {
+        return;
+    }
+
+    match game.launch_campaign(channel, target, days) {
+        Ok(id) => println!("Campaign #{} launched! Remaining cash: ${:.2}", id, game.player.cash),
+        Err(e) => println!("ERROR: {}", e),
+    }
+    wait_for_enter();
+}
+
+/// Handles promotions and the loyalty program for the current store
+fn handle_promotions(game: &mut GameState) {
+    loop {
+        clear_screen();
+        let store = game.current_store();
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                 PROMOTIONS & LOYALTY                         ║");
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  Store: {:20}  │  Cash: ${:>10.2}   ║",
+            store.name, game.player.cash
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+
+        if store.promotions.is_empty() {
+            println!("║  No promotions running.                                      ║");
+        } else {
+            println!("║  Running Promotions:                                         ║");
+            for (idx, promotion) in store.promotions.iter().enumerate() {
+                println!(
+                    "║    [{}] {:38} {:>2} day(s) left ║",
+                    idx + 1,
+                    game.describe_promotion(promotion),
+                    promotion.days_remaining
+                );
+            }
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        match &store.loyalty_program {
+            Some(program) => println!(
+                "║  Loyalty program: {} day(s) old, +{:.1}% repeat visits          ║",
+                program.days_active,
+                (program.visit_multiplier() - 1.0) * 100.0
+            ),
+            None => println!("║  Loyalty program: not enrolled                               ║"),
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  [1] Category discount                                       ║");
+        println!("║  [2] Buy-one-get-one                                         ║");
+        println!("║  [3] Bundle (e.g. Bread + Milk)                              ║");
+        println!("║  [4] Cancel promotion                                        ║");
+        println!(
+            "║  [5] Enroll in loyalty program (${:.0})                      ║",
+            LoyaltyProgram::ENROLLMENT_COST
+        );
+        println!("║  [0] Back                                                    ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+        println!();
+
+        let input = read_input("Enter choice: ");
+        let kind = match input.trim() {
+            "0" => return,
+            "1" => {
+                let categories = Category::retail_categories();
+                for (idx, category) in categories.iter().enumerate() {
+                    println!("  [{}] {}", idx + 1, category.name());
+                }
+                let category = match read_number("Choose category: ") {
+                    Some(n) if n > 0 && (n as usize) <= categories.len() => categories[n as usize - 1],
+                    _ => {
+                        println!("Invalid category.");
+                        wait_for_enter();
+                        continue;
+                    }
+                };
+                let percent = match read_float("Discount %: ") {
+                    Some(p) => p,
+                    None => {
+                        println!("Invalid discount.");
+                        wait_for_enter();
+                        continue;
+                    }
+                };
+                PromotionKind::CategoryDiscount { category, percent }
+            }
+            "2" => {
+                let product_id = match read_number("Product ID: ") {
+                    Some(id) => id,
+                    None => {
+                        println!("Invalid product ID.");
+                        wait_for_enter();
+                        continue;
+                    }
+                };
+                PromotionKind::BuyOneGetOne { product_id }
+            }
+            "3" => {
+                let input = read_input("Product IDs in bundle (comma separated, e.g. 1,2): ");
+                let product_ids: Vec<u32> = input
+                    .split(',')
+                    .filter_map(|id| id.trim().parse().ok())
+                    .collect();
+                let percent = match read_float("Bundle discount %: ") {
+                    Some(p) => p,
+                    None => {
+                        println!("Invalid discount.");
+                        wait_for_enter();
+                        continue;
+                    }
+                };
+                PromotionKind::Bundle { product_ids, percent }
+            }
+            "4" => {
+                let count = game.current_store().promotions.len();
+                match read_number("Promotion number to cancel (0 to cancel): ") {
+                    Some(n) if n > 0 && (n as usize) <= count => {
+                        match game.current_store_mut().cancel_promotion(n as usize - 1) {
+                            Ok(promotion) => {
+                                println!("Cancelled: {}", game.describe_promotion(&promotion))
+                            }
+                            Err(e) => println!("ERROR: {}", e),
+                        }
+                    }
+                    Some(0) => continue,
+                    _ => println!("Invalid promotion number."),
+                }
+                wait_for_enter();
+                continue;
+            }
+            "5" => {
+                match game.enroll_loyalty_program() {
+                    Ok(cost) => println!("Loyalty program launched for ${:.2}!", cost),
+                    Err(e) => println!("ERROR: {}", e),
+                }
+                wait_for_enter();
+                continue;
+            }
+            _ => {
+                println!("Invalid choice.");
+                continue;
+            }
+        };
+
+        let days = match read_number("Duration in days: ") {
+            Some(d) => d,
+            None => {
+                println!("Invalid duration.");
+                wait_for_enter();
+                continue;
+            }
+        };
+
+        match game.start_promotion(kind, days) {
+            Ok(()) => println!("Promotion started!"),
+            Err(e) => println!("ERROR: {}", e),
+        }
+        wait_for_enter();
+    }
+}
+
+/// Handles staff management submenu
+pub fn handle_manage_staff(game: &mut GameState) {
+    loop {
+        clear_screen();
+        let store = game.current_store();
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                    MANAGE STAFF                              ║");
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  Store: {:20}  │  Cash: ${:>10.2}   ║",
+            store.name, game.player.cash
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+
+        if store.employees.is_empty() {
+            println!("║  No employees yet.                                           ║");
+        } else {
+            println!("║  Current Employees:                                          ║");
+            for (idx, emp) in store.employees.iter().enumerate() {
+                println!(
+                    "║    [{}] {:30} ${:.0}/day          ║",
+                    idx + 1,
+                    emp.name,
+                    emp.salary
+                );
+            }
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  Daily Customers: {:>3} (base: {:.0}, +20% per employee)        ║",
+            store.effective_customers(),
+            store.base_traffic()
+        );
+        println!(
+            "║  Total Daily Salaries: ${:>6.0}                               ║",
+            store.employees.iter().map(|e| e.salary).sum::<f64>()
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  [1] View employees                                          ║");
+        println!(
+            "║  {:<58}  ║",
+            format!("[2] Hire employee (${:.0}/day)", game.going_wage(Employee::BASE_SALARY))
+        );
+        println!("║  [3] Fire employee                                           ║");
+        println!("║  [0] Back to main menu                                       ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+        println!();
+
+        let input = read_input("Enter choice: ");
+        match input.trim() {
+            "0" => return,
+            "1" => {
+                display_employees(game);
+            }
+            "2" => {
+                handle_hire_employee(game);
+            }
+            "3" => {
+                handle_fire_employee(game);
+            }
+            _ => println!("Invalid choice."),
+        }
+    }
+}
+
+/// Displays employees of the current store
+fn display_employees(game: &GameState) {
+    clear_screen();
+    let store = game.current_store();
+
+    println!("╔══════════════════════════════════════════════════════════════╗");
+    println!("║                 {} - EMPLOYEES                 ║", store.name);
+    println!("╠══════════════════════════════════════════════════════════════╣");
+
+    if store.employees.is_empty() {
+        println!("║  No employees hired yet.                                     ║");
+        println!("║  Hire employees to increase customer traffic!                ║");
+    } else {
+        for (idx, emp) in store.employees.iter().enumerate() {
+            println!(
+                "║  {}. {:40} ${:.0}/day    ║",
+                idx + 1,
+                emp.name,
+                emp.salary
+            );
+        }
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  Customer Bonus: +{}% ({:.0} → {} customers/day)             ║",
+            store.employees.len() * 20,
+            store.base_traffic(),
+            store.effective_customers()
+        );
+    }
+
+    println!("╚══════════════════════════════════════════════════════════════╝");
+    wait_for_enter();
+}
+
+/// Handles hiring a new employee
+fn handle_hire_employee(game: &mut GameState) {
+    let store = game.current_store();
+
+    if store.employees.len() >= 3 {
         println!("Maximum of 3 employees per store reached!");
         wait_for_enter();
         return;
     }
 
-    println!("Hire a new employee ($50/day salary)");
+    println!(
+        "Hire a new employee (${:.0}/day salary)",
+        game.going_wage(Employee::BASE_SALARY)
+    );
     println!(
         "Current employees: {}/3",
         store.employees.len()
@@ -1022,7 +2297,8 @@ fn handle_hire_employee(game: &mut GameState) {
         return;
     }
 
-    match game.current_store_mut().hire_employee(&name) {
+    let salary = game.going_wage(Employee::BASE_SALARY);
+    match game.current_store_mut().hire_employee(&name, salary) {
         Ok(()) => {
             println!();
             println!("SUCCESS! Hired: {}", name);
@@ -1125,7 +2401,14 @@ pub fn handle_manage_factories(game: &mut GameState) {
         println!("║  [5] Manage factory workers                                  ║");
         println!("║  [6] Manage supply chain                                     ║");
         println!("║  [7] Switch factory                                          ║");
-        println!("║  [8] Buy new factory ($10,000)                               ║");
+        println!("║  [8] Buy new factory (from $10,000)                          ║");
+        println!("║  [9] Transfer components to another factory                  ║");
+        println!("║  [O] Standing production orders                              ║");
+        println!("║  [P] Raw material procurement                                ║");
+        println!("║  [M] Plan production (material requirements)                 ║");
+        println!("║  [I] Internal transfers and transfer pricing                 ║");
+        println!("║  [L] Logistics (trucks and deliveries)                       ║");
+        println!("║  [Q] Quality (equipment, materials, defects)                 ║");
         println!("║  [0] Back to main menu                                       ║");
         println!("╚══════════════════════════════════════════════════════════════╝");
         println!();
@@ -1141,6 +2424,13 @@ pub fn handle_manage_factories(game: &mut GameState) {
             "6" => handle_supply_chain(game),
             "7" => handle_switch_factory(game),
             "8" => handle_buy_new_factory(game),
+            "9" => handle_transfer_components(game),
+            "O" | "o" => handle_standing_orders(game),
+            "P" | "p" => handle_procurement(game),
+            "M" | "m" => handle_production_planning(game),
+            "I" | "i" => handle_internal_transfers(game),
+            "L" | "l" => handle_logistics(game),
+            "Q" | "q" => handle_quality(game),
             _ => println!("Invalid choice."),
         }
     }
@@ -1164,6 +2454,12 @@ fn display_factory_status(game: &GameState) {
         format!("{} - Status", factory.name)
     );
     println!("╠══════════════════════════════════════════════════════════════╣");
+    if let Some(region) = game.region(factory.region_id) {
+        println!(
+            "║  {:<58}  ║",
+            format!("Region: {} (raw material prices {:.0}%)", region.name, region.price_multiplier * 100.0)
+        );
+    }
     println!(
         "║  Workers: {}/3  │  Production Slots: {}/{}                   ║",
         factory.workers.len(),
@@ -1176,6 +2472,25 @@ fn display_factory_status(game: &GameState) {
         factory.daily_rent,
         factory.workers.iter().map(|w| w.salary).sum::<f64>()
     );
+    let quality = factory.quality_score();
+    println!(
+        "║  {:<58}  ║",
+        format!(
+            "Quality: {:.0}% ({})  │  Defect rate: {:.1}%",
+            quality * 100.0,
+            QualityTier::from_score(quality).name(),
+            quality::defect_rate(quality) * 100.0
+        )
+    );
+    println!(
+        "║  {:<58}  ║",
+        format!(
+            "Equipment: level {}/{}  │  Materials: {}",
+            factory.equipment_level,
+            quality::MAX_EQUIPMENT_LEVEL,
+            factory.material_grade.name()
+        )
+    );
     println!("╠══════════════════════════════════════════════════════════════╣");
 
     // Raw materials
@@ -1212,13 +2527,13 @@ fn display_factory_status(game: &GameState) {
         }
     }
 
-    // Finished goods
+    // Components
     println!("╠══════════════════════════════════════════════════════════════╣");
-    println!("║  FINISHED GOODS (ready to transfer):                        ║");
-    if factory.finished_goods.is_empty() || factory.total_finished_goods() == 0 {
+    println!("║  COMPONENTS (for later production stages):                   ║");
+    if factory.total_components() == 0 {
         println!("║    (None)                                                    ║");
     } else {
-        for (product_id, quantity) in &factory.finished_goods {
+        for (product_id, quantity) in &factory.components {
             if *quantity > 0 {
                 let name = game
                     .get_product(*product_id)
@@ -1229,13 +2544,39 @@ fn display_factory_status(game: &GameState) {
         }
     }
 
-    // Supply chain
+    // Finished goods
     println!("╠══════════════════════════════════════════════════════════════╣");
-    println!(
-        "║  SUPPLY CHAIN: Auto-transfer {}                              ║",
-        if factory.auto_transfer { "ON " } else { "OFF" }
-    );
-    if factory.connected_stores.is_empty() {
+    println!("║  FINISHED GOODS (ready to transfer):                        ║");
+    if factory.finished_goods.is_empty() || factory.total_finished_goods() == 0 {
+        println!("║    (None)                                                    ║");
+    } else {
+        for (product_id, quantity) in &factory.finished_goods {
+            if *quantity > 0 {
+                let name = game
+                    .get_product(*product_id)
+                    .map(|p| p.name.as_str())
+                    .unwrap_or("Unknown");
+                println!(
+                    "║  {:<58}  ║",
+                    format!(
+                        "  {:24} x {:>5} @ ${:>8.2}  {}",
+                        name,
+                        quantity,
+                        factory.unit_cost(*product_id),
+                        QualityTier::from_score(factory.quality_of(*product_id)).name()
+                    )
+                );
+            }
+        }
+    }
+
+    // Supply chain
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!(
+        "║  SUPPLY CHAIN: Auto-transfer {}                              ║",
+        if factory.auto_transfer { "ON " } else { "OFF" }
+    );
+    if factory.connected_stores.is_empty() {
         println!("║    (Not connected to any stores)                             ║");
     } else {
         for store_id in &factory.connected_stores {
@@ -1276,8 +2617,7 @@ fn handle_buy_raw_materials(game: &mut GameState) {
 
         for product in Product::raw_materials() {
             let wholesale = game
-                .market
-                .get_wholesale_price(product.id)
+                .factory_wholesale_price(product.id)
                 .unwrap_or(product.base_price);
             println!(
                 "║  {:>3} {:25} ${:>10.2}                   ║",
@@ -1334,8 +2674,7 @@ fn handle_buy_raw_materials(game: &mut GameState) {
                 };
 
                 let unit_price = game
-                    .market
-                    .get_wholesale_price(product_id)
+                    .factory_wholesale_price(product_id)
                     .unwrap_or(product.base_price);
 
                 if let Some(existing) = cart.iter_mut().find(|i| i.product_id == product_id) {
@@ -1447,8 +2786,7 @@ fn handle_buy_raw_materials(game: &mut GameState) {
                             };
 
                             let unit_price = game
-                                .market
-                                .get_wholesale_price(product_id)
+                                .factory_wholesale_price(product_id)
                                 .unwrap_or(product.base_price);
 
                             if let Some(existing) =
@@ -1515,8 +2853,8 @@ fn handle_start_production(game: &mut GameState) {
     println!("║  {:─<2} {:─<20} {:─>5} {:─>8} {:─>6}              ║", "", "", "", "", "");
 
     for recipe in &game.recipes {
-        let material_cost = recipe.material_cost(|id| {
-            game.market.get_wholesale_price(id).unwrap_or(0.0)
+        let material_cost = recipe.rolled_up_cost(&game.recipes, &|id| {
+            game.factory_wholesale_price(id).unwrap_or(0.0)
         });
         let max_producible = factory.max_producible(recipe);
 
@@ -1546,6 +2884,15 @@ fn handle_start_production(game: &mut GameState) {
     if !has_materials {
         println!("  (None - buy raw materials first!)");
     }
+    for (product_id, quantity) in &factory.components {
+        if *quantity > 0 {
+            let name = game
+                .get_product(*product_id)
+                .map(|p| p.name.as_str())
+                .unwrap_or("Unknown");
+            println!("  {} x {} (component)", quantity, name);
+        }
+    }
     println!();
 
     let recipe_id = match read_number("Enter recipe ID to produce (0 to cancel): ") {
@@ -1578,8 +2925,8 @@ fn handle_start_production(game: &mut GameState) {
             .get_product(ing.product_id)
             .map(|p| p.name.as_str())
             .unwrap_or("Unknown");
-        let have = game.current_factory().unwrap().get_raw_material(ing.product_id);
-        let batches = if ing.quantity > 0 { have / ing.quantity } else { 0 };
+        let have = game.current_factory().unwrap().get_ingredient(ing.product_id);
+        let batches = have.checked_div(ing.quantity).unwrap_or(0);
         println!("  {} x {} (have: {}, enough for {} batches)", ing.quantity, name, have, batches);
     }
     println!("Production time: {} day(s) per batch", recipe.production_days);
@@ -1721,49 +3068,742 @@ fn handle_transfer_goods(game: &mut GameState) {
         return;
     }
 
-    println!("Available: {}", available);
-    let quantity = match read_number("Enter quantity to transfer: ") {
-        Some(0) => return,
-        Some(q) => q,
-        None => {
-            println!("Invalid quantity.");
-            wait_for_enter();
-            return;
-        }
+    println!("Available: {}", available);
+    let quantity = match read_number("Enter quantity to transfer: ") {
+        Some(0) => return,
+        Some(q) => q,
+        None => {
+            println!("Invalid quantity.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    let store_num = match read_number("Enter store number to transfer to: ") {
+        Some(0) => return,
+        Some(n) if n > 0 && (n as usize) <= game.player.stores.len() => n as usize - 1,
+        _ => {
+            println!("Invalid store number.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    match game.transfer_to_store(product_id, quantity, store_num) {
+        Ok(actual) => {
+            let product_name = game
+                .get_product(product_id)
+                .map(|p| p.name.as_str())
+                .unwrap_or("Unknown");
+            let store_name = &game.player.stores[store_num].name;
+            let factory_idx = game.current_factory.unwrap();
+            println!();
+            println!(
+                "Shipped {} x {} to {} (arrives in {} day(s))",
+                actual,
+                product_name,
+                store_name,
+                game.transit_days(factory_idx, store_num)
+            );
+        }
+        Err(e) => {
+            println!("ERROR: {}", e);
+        }
+    }
+    wait_for_enter();
+}
+
+/// Handles factory worker management
+fn handle_factory_workers(game: &mut GameState) {
+    if game.current_factory.is_none() {
+        println!("No factory selected. Buy or select a factory first!");
+        wait_for_enter();
+        return;
+    }
+
+    loop {
+        clear_screen();
+        let factory = game.current_factory().unwrap();
+
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                  FACTORY WORKERS                             ║");
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  Factory: {:20}  │  Cash: ${:>10.2}   ║",
+            factory.name, game.player.cash
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+
+        if factory.workers.is_empty() {
+            println!("║  No workers yet.                                             ║");
+        } else {
+            println!("║  Current Workers:                                            ║");
+            for (idx, worker) in factory.workers.iter().enumerate() {
+                println!(
+                    "║  {:<58}  ║",
+                    format!(
+                        "  [{}] {:24} ${:.0}/day  skill {:.0}%",
+                        idx + 1,
+                        worker.name,
+                        worker.salary,
+                        worker.skill * 100.0
+                    )
+                );
+            }
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  Production slots: {} (base 2 + {} workers)                  ║",
+            factory.production_slots(),
+            factory.workers.len()
+        );
+        println!(
+            "║  Total daily salaries: ${:>6.0}                               ║",
+            factory.workers.iter().map(|w| w.salary).sum::<f64>()
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  {:<58}  ║",
+            format!("[1] Hire worker (${:.0}/day)", game.going_wage(FactoryWorker::BASE_SALARY))
+        );
+        println!("║  [2] Fire worker                                             ║");
+        println!("║  [0] Back                                                    ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+        println!();
+
+        let input = read_input("Enter choice: ");
+        match input.trim() {
+            "0" => return,
+            "1" => {
+                let factory = game.current_factory().unwrap();
+                if factory.workers.len() >= 3 {
+                    println!("Maximum of 3 workers per factory reached!");
+                    wait_for_enter();
+                    continue;
+                }
+
+                let name = read_input("Enter worker name (0 to cancel): ");
+                if name == "0" || name.is_empty() {
+                    continue;
+                }
+
+                let salary = game.going_wage(FactoryWorker::BASE_SALARY);
+                match game.current_factory_mut().unwrap().hire_worker(&name, salary) {
+                    Ok(()) => {
+                        println!();
+                        println!("Hired: {}", name);
+                        println!(
+                            "New production slots: {}",
+                            game.current_factory().unwrap().production_slots()
+                        );
+                    }
+                    Err(e) => {
+                        println!("ERROR: {}", e);
+                    }
+                }
+                wait_for_enter();
+            }
+            "2" => {
+                let factory = game.current_factory().unwrap();
+                if factory.workers.is_empty() {
+                    println!("No workers to fire!");
+                    wait_for_enter();
+                    continue;
+                }
+
+                let worker_num =
+                    match read_number("Enter worker number to fire (0 to cancel): ") {
+                        Some(0) => continue,
+                        Some(n) if n > 0 && (n as usize) <= factory.workers.len() => {
+                            n as usize - 1
+                        }
+                        _ => {
+                            println!("Invalid worker number.");
+                            wait_for_enter();
+                            continue;
+                        }
+                    };
+
+                match game.current_factory_mut().unwrap().fire_worker(worker_num) {
+                    Ok(fired) => {
+                        println!();
+                        println!("Fired: {}", fired.name);
+                        println!(
+                            "New production slots: {}",
+                            game.current_factory().unwrap().production_slots()
+                        );
+                    }
+                    Err(e) => {
+                        println!("ERROR: {}", e);
+                    }
+                }
+                wait_for_enter();
+            }
+            _ => println!("Invalid choice."),
+        }
+    }
+}
+
+/// Describes what a standing order did on a day
+fn describe_order_entry(game: &GameState, entry: &OrderLogEntry) -> String {
+    let blocked = match &entry.blocked {
+        None => String::new(),
+        Some(BlockReason::NoSlots) => " - blocked: no free slots".to_string(),
+        Some(BlockReason::UnknownRecipe) => " - blocked: recipe not found".to_string(),
+        Some(BlockReason::MissingIngredients(missing)) => {
+            let names: Vec<String> = missing
+                .iter()
+                .map(|(id, qty)| {
+                    let name = game.get_product(*id).map(|p| p.name.as_str()).unwrap_or("Unknown");
+                    format!("{} x {}", qty, name)
+                })
+                .collect();
+            format!(" - blocked: short {}", names.join(", "))
+        }
+    };
+    format!("started {} x {}{}", entry.started, entry.recipe_name, blocked)
+}
+
+/// Handles standing production orders for the current factory
+fn handle_standing_orders(game: &mut GameState) {
+    if game.current_factory.is_none() {
+        println!("No factory selected. Buy or select a factory first!");
+        wait_for_enter();
+        return;
+    }
+
+    loop {
+        clear_screen();
+        let factory = game.current_factory().unwrap();
+
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║  {:^58}  ║", format!("{} - Standing Orders", factory.name));
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        if factory.standing_orders.is_empty() {
+            println!("║    (None)                                                    ║");
+        }
+        for (idx, order) in factory.standing_orders.iter().enumerate() {
+            let recipe_name = game
+                .get_recipe(order.recipe_id)
+                .map(|r| r.name.as_str())
+                .unwrap_or("Unknown");
+            let target = match order.target {
+                OrderTarget::StockLevel(level) => format!("keep {} in stock", level),
+                OrderTarget::DailyCount(count) => format!("start {} per day", count),
+            };
+            println!("║  {:<58}  ║", format!("[{}] {} - {}", idx + 1, recipe_name, target));
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  RECENT ACTIVITY:                                            ║");
+        if factory.order_log.is_empty() {
+            println!("║    (None)                                                    ║");
+        }
+        for entry in factory.order_log.iter().rev().take(10) {
+            println!("║    Day {}: {}", entry.day, describe_order_entry(game, entry));
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  [1] Add standing order                                      ║");
+        println!("║  [2] Cancel standing order                                   ║");
+        println!("║  [0] Back                                                    ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+
+        match read_input("Enter choice: ").trim() {
+            "0" => return,
+            "1" => {
+                println!("Recipes:");
+                for recipe in &game.recipes {
+                    println!("  [{}] {}", recipe.id, recipe.name);
+                }
+                let recipe_id = match read_number("Enter recipe ID (0 to cancel): ") {
+                    Some(0) | None => continue,
+                    Some(id) => id,
+                };
+                println!("  [1] Keep a stock level");
+                println!("  [2] Start a fixed number of jobs each day");
+                let kind = read_input("Order type: ");
+                let amount = match read_number("Amount: ") {
+                    Some(n) => n,
+                    None => {
+                        println!("Invalid amount.");
+                        wait_for_enter();
+                        continue;
+                    }
+                };
+                let target = match kind.trim() {
+                    "1" => OrderTarget::StockLevel(amount),
+                    "2" => OrderTarget::DailyCount(amount),
+                    _ => {
+                        println!("Invalid order type.");
+                        wait_for_enter();
+                        continue;
+                    }
+                };
+                match game.add_standing_order(recipe_id, target) {
+                    Ok(()) => println!("Standing order saved."),
+                    Err(e) => println!("Error: {}", e),
+                }
+                wait_for_enter();
+            }
+            "2" => {
+                let index = match read_number("Order number to cancel (0 to cancel): ") {
+                    Some(0) | None => continue,
+                    Some(n) => n as usize - 1,
+                };
+                match game.cancel_standing_order(index) {
+                    Ok(_) => println!("Standing order cancelled."),
+                    Err(e) => println!("Error: {}", e),
+                }
+                wait_for_enter();
+            }
+            _ => println!("Invalid choice."),
+        }
+    }
+}
+
+/// Handles the truck fleet and shows goods on the road
+/// Handles a factory's quality settings: equipment, material grade and defects
+fn handle_quality(game: &mut GameState) {
+    loop {
+        clear_screen();
+        let factory = match game.current_factory() {
+            Some(f) => f,
+            None => {
+                println!("No factory selected. Buy or select a factory first!");
+                wait_for_enter();
+                return;
+            }
+        };
+        let quality = factory.quality_score();
+        let skill = factory.average_skill();
+        let handling = match factory.defect_handling {
+            DefectHandling::Scrap => "scrapped".to_string(),
+            DefectHandling::SellAsSeconds => format!(
+                "sold as seconds ({:.0}% of base price)",
+                DefectHandling::SECONDS_PRICE * 100.0
+            ),
+        };
+
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║  {:^58}  ║", format!("{} - Quality", factory.name));
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "Quality: {:.0}% ({})  │  Defect rate: {:.1}%",
+                quality * 100.0,
+                QualityTier::from_score(quality).name(),
+                quality::defect_rate(quality) * 100.0
+            )
+        );
+        println!("║  {:<58}  ║", format!("Worker skill: {:.0}% (grows with experience)", skill * 100.0));
+        println!(
+            "║  {:<58}  ║",
+            format!("Equipment: level {}/{}", factory.equipment_level, quality::MAX_EQUIPMENT_LEVEL)
+        );
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "Materials: {} ({:.0}% of standard price)",
+                factory.material_grade.name(),
+                factory.material_grade.price_multiplier() * 100.0
+            )
+        );
+        println!("║  {:<58}  ║", format!("Defective units are {}", handling));
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "Premium goods sell for {:.0}% more, economy for {:.0}% less.",
+                (QualityTier::Premium.price_multiplier() - 1.0) * 100.0,
+                (1.0 - QualityTier::Economy.price_multiplier()) * 100.0
+            )
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        if factory.equipment_level < quality::MAX_EQUIPMENT_LEVEL {
+            println!(
+                "║  {:<58}  ║",
+                format!(
+                    "[1] Upgrade equipment (${:.0})",
+                    quality::EQUIPMENT_UPGRADE_COST * (factory.equipment_level + 1) as f64
+                )
+            );
+        } else {
+            println!("║  [1] Upgrade equipment (fully upgraded)                      ║");
+        }
+        println!("║  [2] Switch material grade                                   ║");
+        println!("║  [3] Switch defect handling (scrap / sell as seconds)        ║");
+        println!("║  [0] Back                                                    ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+
+        let grade = factory.material_grade;
+        let defect_handling = factory.defect_handling;
+        match read_input("Enter choice: ").trim() {
+            "0" => return,
+            "1" => match game.upgrade_factory_equipment() {
+                Ok(level) => println!("Equipment upgraded to level {}.", level),
+                Err(e) => println!("Error: {}", e),
+            },
+            "2" => {
+                let grade = match grade {
+                    MaterialGrade::Standard => MaterialGrade::Premium,
+                    MaterialGrade::Premium => MaterialGrade::Standard,
+                };
+                match game.set_material_grade(grade) {
+                    Ok(()) => println!("Now buying {} materials.", grade.name().to_lowercase()),
+                    Err(e) => println!("Error: {}", e),
+                }
+            }
+            "3" => {
+                let handling = match defect_handling {
+                    DefectHandling::Scrap => DefectHandling::SellAsSeconds,
+                    DefectHandling::SellAsSeconds => DefectHandling::Scrap,
+                };
+                match game.set_defect_handling(handling) {
+                    Ok(()) if handling == DefectHandling::Scrap => println!("Defective units will be scrapped."),
+                    Ok(()) => println!("Defective units will be sold as seconds."),
+                    Err(e) => println!("Error: {}", e),
+                }
+            }
+            _ => {
+                println!("Invalid choice.");
+                continue;
+            }
+        }
+        wait_for_enter();
+    }
+}
+
+fn handle_logistics(game: &mut GameState) {
+    loop {
+        clear_screen();
+        println!("╔══════════════════════════════════════════════════════════════╗");
+        println!("║                       LOGISTICS                              ║");
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "Trucks carry {} units; trips cost ${:.0}/day on the road.",
+                Truck::CAPACITY,
+                Truck::RUNNING_COST
+            )
+        );
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "With no free truck, goods go by courier (${:.2}/unit/day).",
+                Logistics::COURIER_RATE
+            )
+        );
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  FLEET:                                                      ║");
+        if game.logistics.trucks.is_empty() {
+            println!("║    (No trucks)                                               ║");
+        }
+        for truck in &game.logistics.trucks {
+            let ownership = match truck.ownership {
+                TruckOwnership::Owned => "owned".to_string(),
+                TruckOwnership::Leased => format!("leased ${:.0}/day", truck.fixed_cost()),
+            };
+            let status = if truck.is_available() {
+                "available".to_string()
+            } else {
+                format!("back in {} day(s)", truck.busy_days)
+            };
+            println!(
+                "║  {:<58}  ║",
+                format!("  [{}] {} ({}) - {}", truck.id, truck.name, ownership, status)
+            );
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!("║  ON THE ROAD:                                                ║");
+        if game.logistics.deliveries.is_empty() {
+            println!("║    (Nothing in transit)                                      ║");
+        }
+        for delivery in &game.logistics.deliveries {
+            let factory = game
+                .player
+                .factories
+                .iter()
+                .find(|f| f.id == delivery.factory_id)
+                .map(|f| f.name.as_str())
+                .unwrap_or("Unknown");
+            let store = game.get_store_name_by_id(delivery.store_id).unwrap_or("Unknown");
+            let carrier = if delivery.truck_id.is_some() { "truck" } else { "courier" };
+            println!(
+                "║  {:<58}  ║",
+                format!(
+                    "  {} -> {}: {} units by {}, {} day(s) left",
+                    factory,
+                    store,
+                    delivery.units(),
+                    carrier,
+                    delivery.days_remaining
+                )
+            );
+        }
+
+        println!("╠══════════════════════════════════════════════════════════════╣");
+        println!(
+            "║  {:<58}  ║",
+            format!("[1] Buy a truck (${:.0})", Truck::PURCHASE_PRICE)
+        );
+        println!(
+            "║  {:<58}  ║",
+            format!("[2] Lease a truck (${:.0}/day)", Truck::LEASE_DAILY)
+        );
+        println!("║  [3] Sell or return a truck                                  ║");
+        println!("║  [0] Back                                                    ║");
+        println!("╚══════════════════════════════════════════════════════════════╝");
+
+        match read_input("Enter choice: ").trim() {
+            "0" => return,
+            "1" => match game.buy_truck() {
+                Ok(id) => println!("Bought Truck #{}.", id),
+                Err(e) => println!("Error: {}", e),
+            },
+            "2" => {
+                let id = game.lease_truck();
+                println!("Leased Truck #{}.", id);
+            }
+            "3" => {
+                let truck_id = match read_number("Truck ID (0 to cancel): ") {
+                    Some(0) | None => continue,
+                    Some(id) => id,
+                };
+                match game.retire_truck(truck_id) {
+                    Ok(proceeds) if proceeds > 0.0 => println!("Sold for ${:.2}.", proceeds),
+                    Ok(_) => println!("Lease returned."),
+                    Err(e) => println!("Error: {}", e),
+                }
+            }
+            _ => {
+                println!("Invalid choice.");
+                continue;
+            }
+        }
+        wait_for_enter();
+    }
+}
+
+/// Shows factory-to-store shipments at production cost and sets the retail markup
+fn handle_internal_transfers(game: &mut GameState) {
+    clear_screen();
+    let ledger = &game.transfers;
+
+    println!("╔══════════════════════════════════════════════════════════════╗");
+    println!("║                  INTERNAL TRANSFERS                          ║");
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  Goods move at production cost; new store lines are priced   ║");
+    println!(
+        "║  {:<58}  ║",
+        format!("at a {:.0}% markup over it.", ledger.retail_markup)
+    );
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  FACTORIES (shipped at cost):                                ║");
+    for factory in &game.player.factories {
+        let totals = ledger.factory_totals(factory.id);
+        println!(
+            "║  {:<58}  ║",
+            format!("  {:24} {:>6} units  ${:>12.2}", factory.name, totals.units, totals.value)
+        );
+    }
+    println!("║  STORES (cost of goods received):                            ║");
+    for store in &game.player.stores {
+        let totals = ledger.store_totals(store.id);
+        if totals.units > 0 {
+            println!(
+                "║  {:<58}  ║",
+                format!("  {:24} {:>6} units  ${:>12.2}", store.name, totals.units, totals.value)
+            );
+        }
+    }
+
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  RECENT SHIPMENTS:                                           ║");
+    if ledger.transfers.is_empty() {
+        println!("║    (None)                                                    ║");
+    }
+    for transfer in ledger.transfers.iter().rev().take(10) {
+        let product = game
+            .get_product(transfer.product_id)
+            .map(|p| p.name.as_str())
+            .unwrap_or("Unknown");
+        let store = game.get_store_name_by_id(transfer.store_id).unwrap_or("Unknown");
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "  Day {}: {} x {} -> {} @ ${:.2}",
+                transfer.day, transfer.quantity, product, store, transfer.transfer_price
+            )
+        );
+    }
+    println!("╚══════════════════════════════════════════════════════════════╝");
+
+    let input = read_input("New retail markup % (Enter to keep): ");
+    if input.trim().is_empty() {
+        return;
+    }
+    match input.trim().parse::<f64>() {
+        Ok(markup) => match game.set_transfer_markup(markup) {
+            Ok(()) => println!("Retail markup set to {:.0}%.", markup),
+            Err(e) => println!("Error: {}", e),
+        },
+        Err(_) => println!("Invalid markup."),
+    }
+    wait_for_enter();
+}
+
+/// Handles planning materials and factory time for a set of production targets
+fn handle_production_planning(game: &mut GameState) {
+    if game.player.factories.is_empty() {
+        println!("You have no factories. Buy one first!");
+        wait_for_enter();
+        return;
+    }
+
+    let product_name = |game: &GameState, id: u32| {
+        game.get_product(id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string())
     };
 
-    let store_num = match read_number("Enter store number to transfer to: ") {
-        Some(0) => return,
-        Some(n) if n > 0 && (n as usize) <= game.player.stores.len() => n as usize - 1,
+    // Collect targets
+    let mut targets: Vec<ProductionTarget> = Vec::new();
+    println!("What do you want to make?");
+    for recipe in game.recipes.iter().filter(|r| !r.intermediate) {
+        println!("  [{}] {}", recipe.output_product_id, product_name(game, recipe.output_product_id));
+    }
+    loop {
+        let product_id = match read_number("Product ID (0 when done): ") {
+            Some(0) => break,
+            Some(id) => id,
+            None => {
+                println!("Invalid product ID.");
+                continue;
+            }
+        };
+        match read_number("Quantity: ") {
+            Some(quantity) if quantity > 0 => targets.push(ProductionTarget::new(product_id, quantity)),
+            _ => println!("Invalid quantity."),
+        }
+    }
+    if targets.is_empty() {
+        return;
+    }
+    let deadline = match read_number("Deadline (days from today): ") {
+        Some(days) if days > 0 => days,
         _ => {
-            println!("Invalid store number.");
+            println!("Invalid deadline.");
             wait_for_enter();
             return;
         }
     };
 
-    match game.transfer_to_store(product_id, quantity, store_num) {
-        Ok(actual) => {
-            let product_name = game
-                .get_product(product_id)
-                .map(|p| p.name.as_str())
-                .unwrap_or("Unknown");
-            let store_name = &game.player.stores[store_num].name;
-            println!();
-            println!(
-                "Transferred {} x {} to {}",
-                actual, product_name, store_name
-            );
+    let plan = game.plan_production(&targets, deadline);
+
+    clear_screen();
+    println!("╔══════════════════════════════════════════════════════════════╗");
+    println!("║  {:^58}  ║", format!("PRODUCTION PLAN - {} day deadline", deadline));
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    for target in &targets {
+        println!(
+            "║  {:<58}  ║",
+            format!("Target: {} x {}", target.quantity, product_name(game, target.product_id))
+        );
+    }
+
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  MATERIALS:                                                  ║");
+    if plan.requirements.is_empty() {
+        println!("║    (None)                                                    ║");
+    }
+    for req in &plan.requirements {
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "  {}: {} x {} (have {}, buy {} = ${:.2})",
+                game.player.factories[req.factory_idx].name,
+                req.required,
+                product_name(game, req.product_id),
+                req.on_hand,
+                req.to_buy,
+                req.cost()
+            )
+        );
+    }
+
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    println!("║  SCHEDULE:                                                   ║");
+    // Group jobs by factory and recipe: (jobs, first start, last finish)
+    let mut schedule = std::collections::BTreeMap::new();
+    for job in &plan.jobs {
+        let (count, start, finish) = schedule
+            .entry((job.factory_idx, job.recipe_id))
+            .or_insert((0, job.start_day, job.finish_day));
+        *count += 1;
+        *start = (*start).min(job.start_day);
+        *finish = (*finish).max(job.finish_day);
+    }
+    for ((factory_idx, recipe_id), (count, start, finish)) in &schedule {
+        let recipe_name = game.get_recipe(*recipe_id).map(|r| r.name.as_str()).unwrap_or("Unknown");
+        println!(
+            "║  {:<58}  ║",
+            format!(
+                "  {}: {} x {} (days {}-{})",
+                game.player.factories[*factory_idx].name, count, recipe_name, start, finish
+            )
+        );
+    }
+    println!(
+        "║  {:<58}  ║",
+        format!(
+            "Slot-days: {}  │  Done by day {}  │  Materials: ${:.2}",
+            plan.slot_days(),
+            plan.finish_day(),
+            plan.purchase_cost()
+        )
+    );
+
+    println!("╠══════════════════════════════════════════════════════════════╣");
+    if plan.is_feasible() {
+        println!("║  The plan can be met on time.                                ║");
+    } else {
+        println!("║  SHORTFALLS:                                                 ║");
+        for shortfall in &plan.shortfalls {
+            let text = match shortfall {
+                Shortfall::NoRecipe { product_id, quantity } => {
+                    format!("{} x {}: no recipe makes it", quantity, product_name(game, *product_id))
+                }
+                Shortfall::Capacity { product_id, quantity } => format!(
+                    "{} x {}: not enough factory time",
+                    quantity,
+                    product_name(game, *product_id)
+                ),
+                Shortfall::Cash(amount) => format!("${:.2} short to buy materials", amount),
+            };
+            println!("║  {:<58}  ║", format!("  {}", text));
         }
-        Err(e) => {
-            println!("ERROR: {}", e);
+    }
+    println!("╚══════════════════════════════════════════════════════════════╝");
+
+    if plan.purchase_cost() > 0.0 {
+        let confirm = read_input(&format!("Buy materials for ${:.2}? [y/N]: ", plan.purchase_cost()));
+        if confirm.eq_ignore_ascii_case("y") {
+            match game.buy_planned_materials(&plan) {
+                Ok(cost) => println!("Bought materials for ${:.2}. Start the jobs from the factory menu.", cost),
+                Err(e) => println!("Error: {}", e),
+            }
         }
     }
     wait_for_enter();
 }
 
-/// Handles factory worker management
-fn handle_factory_workers(game: &mut GameState) {
+/// Handles the current factory's automatic raw material buying
+fn handle_procurement(game: &mut GameState) {
     if game.current_factory.is_none() {
         println!("No factory selected. Buy or select a factory first!");
         wait_for_enter();
@@ -1773,117 +3813,166 @@ fn handle_factory_workers(game: &mut GameState) {
     loop {
         clear_screen();
         let factory = game.current_factory().unwrap();
+        let policy = &factory.procurement;
 
         println!("╔══════════════════════════════════════════════════════════════╗");
-        println!("║                  FACTORY WORKERS                             ║");
+        println!("║  {:^58}  ║", format!("{} - Procurement", factory.name));
         println!("╠══════════════════════════════════════════════════════════════╣");
-        println!(
-            "║  Factory: {:20}  │  Cash: ${:>10.2}   ║",
-            factory.name, game.player.cash
-        );
+        println!("║  {:<58}  ║", format!("Daily spending limit: ${:.0}", policy.daily_budget));
         println!("╠══════════════════════════════════════════════════════════════╣");
-
-        if factory.workers.is_empty() {
-            println!("║  No workers yet.                                             ║");
-        } else {
-            println!("║  Current Workers:                                            ║");
-            for (idx, worker) in factory.workers.iter().enumerate() {
-                println!(
-                    "║    [{}] {:30} ${:.0}/day          ║",
-                    idx + 1,
-                    worker.name,
-                    worker.salary
-                );
-            }
+        if policy.rules.is_empty() {
+            println!("║    (No rules - nothing is bought automatically)              ║");
+        }
+        for (idx, rule) in policy.rules.iter().enumerate() {
+            let text = match rule {
+                ProcurementRule::KeepStocked { product_id, quantity } => {
+                    let name = game
+                        .get_product(*product_id)
+                        .map(|p| p.name.as_str())
+                        .unwrap_or("Unknown");
+                    format!("Keep {} x {} on hand", quantity, name)
+                }
+                ProcurementRule::CoverOrders { days } => {
+                    format!("Cover standing orders for {} day(s)", days)
+                }
+            };
+            println!("║  {:<58}  ║", format!("[{}] {}", idx + 1, text));
         }
 
         println!("╠══════════════════════════════════════════════════════════════╣");
-        println!(
-            "║  Production slots: {} (base 2 + {} workers)                  ║",
-            factory.production_slots(),
-            factory.workers.len()
-        );
-        println!(
-            "║  Total daily salaries: ${:>6.0}                               ║",
-            factory.workers.iter().map(|w| w.salary).sum::<f64>()
-        );
-        println!("╠══════════════════════════════════════════════════════════════╣");
-        println!("║  [1] Hire worker ($75/day)                                   ║");
-        println!("║  [2] Fire worker                                             ║");
+        println!("║  [1] Keep a raw material stocked                             ║");
+        println!("║  [2] Cover standing orders                                   ║");
+        println!("║  [3] Remove rule                                             ║");
+        println!("║  [4] Set daily spending limit                                ║");
         println!("║  [0] Back                                                    ║");
         println!("╚══════════════════════════════════════════════════════════════╝");
-        println!();
 
-        let input = read_input("Enter choice: ");
-        match input.trim() {
+        let result = match read_input("Enter choice: ").trim() {
             "0" => return,
             "1" => {
-                let factory = game.current_factory().unwrap();
-                if factory.workers.len() >= 3 {
-                    println!("Maximum of 3 workers per factory reached!");
-                    wait_for_enter();
-                    continue;
-                }
-
-                let name = read_input("Enter worker name (0 to cancel): ");
-                if name == "0" || name.is_empty() {
-                    continue;
+                println!("Raw materials:");
+                for product in game.products.iter().filter(|p| p.product_type.is_raw_material()) {
+                    println!("  [{}] {}", product.id, product.name);
                 }
-
-                match game.current_factory_mut().unwrap().hire_worker(&name) {
-                    Ok(()) => {
-                        println!();
-                        println!("Hired: {}", name);
-                        println!(
-                            "New production slots: {}",
-                            game.current_factory().unwrap().production_slots()
-                        );
-                    }
-                    Err(e) => {
-                        println!("ERROR: {}", e);
-                    }
+                let product_id = match read_number("Enter product ID (0 to cancel): ") {
+                    Some(0) | None => continue,
+                    Some(id) => id,
+                };
+                match read_number("Units to keep on hand: ") {
+                    Some(quantity) => game.add_procurement_rule(ProcurementRule::KeepStocked {
+                        product_id,
+                        quantity,
+                    }),
+                    None => Err("Invalid quantity".to_string()),
                 }
-                wait_for_enter();
             }
-            "2" => {
-                let factory = game.current_factory().unwrap();
-                if factory.workers.is_empty() {
-                    println!("No workers to fire!");
-                    wait_for_enter();
-                    continue;
-                }
+            "2" => match read_number("Days of standing orders to cover: ") {
+                Some(days) => game.add_procurement_rule(ProcurementRule::CoverOrders { days }),
+                None => Err("Invalid number of days".to_string()),
+            },
+            "3" => match read_number("Rule number to remove (0 to cancel): ") {
+                Some(0) | None => continue,
+                Some(n) => game.remove_procurement_rule(n as usize - 1).map(|_| ()),
+            },
+            "4" => match read_float("Daily spending limit: $") {
+                Some(budget) => game.set_procurement_budget(budget),
+                None => Err("Invalid amount".to_string()),
+            },
+            _ => {
+                println!("Invalid choice.");
+                continue;
+            }
+        };
 
-                let worker_num =
-                    match read_number("Enter worker number to fire (0 to cancel): ") {
-                        Some(0) => continue,
-                        Some(n) if n > 0 && (n as usize) <= factory.workers.len() => {
-                            n as usize - 1
-                        }
-                        _ => {
-                            println!("Invalid worker number.");
-                            wait_for_enter();
-                            continue;
-                        }
-                    };
+        match result {
+            Ok(()) => println!("Procurement updated."),
+            Err(e) => println!("Error: {}", e),
+        }
+        wait_for_enter();
+    }
+}
 
-                match game.current_factory_mut().unwrap().fire_worker(worker_num) {
-                    Ok(fired) => {
-                        println!();
-                        println!("Fired: {}", fired.name);
-                        println!(
-                            "New production slots: {}",
-                            game.current_factory().unwrap().production_slots()
-                        );
-                    }
-                    Err(e) => {
-                        println!("ERROR: {}", e);
-                    }
-                }
-                wait_for_enter();
-            }
-            _ => println!("Invalid choice."),
+/// Handles moving components from the current factory to another one
+fn handle_transfer_components(game: &mut GameState) {
+    let factory = match game.current_factory() {
+        Some(f) => f,
+        None => {
+            println!("No factory selected. Buy or select a factory first!");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    if game.player.factories.len() < 2 {
+        println!("You need a second factory to transfer components to.");
+        wait_for_enter();
+        return;
+    }
+
+    let components: Vec<(u32, u32)> = factory
+        .components
+        .iter()
+        .filter(|(_, qty)| **qty > 0)
+        .map(|(id, qty)| (*id, *qty))
+        .collect();
+    if components.is_empty() {
+        println!("No components to transfer. Produce some with an intermediate recipe first!");
+        wait_for_enter();
+        return;
+    }
+
+    println!("Components at {}:", factory.name);
+    for (product_id, quantity) in &components {
+        let name = game
+            .get_product(*product_id)
+            .map(|p| p.name.as_str())
+            .unwrap_or("Unknown");
+        println!("  [{}] {} x {}", product_id, name, quantity);
+    }
+
+    let product_id = match read_number("Enter component ID (0 to cancel): ") {
+        Some(0) => return,
+        Some(id) if components.iter().any(|(c, _)| *c == id) => id,
+        _ => {
+            println!("Invalid component ID.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    println!("Send to:");
+    for (idx, other) in game.player.factories.iter().enumerate() {
+        if Some(idx) != game.current_factory {
+            println!("  [{}] {}", idx + 1, other.name);
+        }
+    }
+    let to_idx = match read_number("Enter factory number (0 to cancel): ") {
+        Some(0) => return,
+        Some(n) if n > 0 && (n as usize) <= game.player.factories.len() => n as usize - 1,
+        _ => {
+            println!("Invalid factory number.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    let quantity = match read_number("Quantity to transfer: ") {
+        Some(q) if q > 0 => q,
+        _ => {
+            println!("Invalid quantity.");
+            wait_for_enter();
+            return;
         }
+    };
+
+    match game.transfer_components(product_id, quantity, to_idx) {
+        Ok(moved) => println!(
+            "Transferred {} units to {}.",
+            moved, game.player.factories[to_idx].name
+        ),
+        Err(e) => println!("Error: {}", e),
     }
+    wait_for_enter();
 }
 
 /// Handles switching between factories
@@ -1928,22 +4017,24 @@ fn handle_switch_factory(game: &mut GameState) {
 
 /// Handles buying a new factory
 fn handle_buy_new_factory(game: &mut GameState) {
-    println!("Buy a new factory for $10,000");
+    println!("Buy a new factory from ${:.0}", GameState::NEW_FACTORY_COST);
     println!("Your cash: ${:.2}", game.player.cash);
     println!();
 
-    if game.player.cash < 10000.0 {
-        println!("Not enough cash! You need $10,000.");
-        wait_for_enter();
-        return;
-    }
+    let region_id = match select_region(game, GameState::NEW_FACTORY_COST) {
+        Some(id) => id,
+        None => {
+            wait_for_enter();
+            return;
+        }
+    };
 
     let name = read_input("Enter name for new factory (0 to cancel): ");
     if name == "0" || name.is_empty() {
         return;
     }
 
-    match game.buy_new_factory(&name) {
+    match game.buy_new_factory(&name, region_id) {
         Ok(()) => {
             println!();
             println!("SUCCESS! Purchased new factory: {}", name);
@@ -1981,6 +4072,7 @@ fn handle_supply_chain(game: &mut GameState) {
             "║  Auto-transfer: {:6}                                        ║",
             if factory.auto_transfer { "ON" } else { "OFF" }
         );
+        println!("║  {:<58}  ║", format!("Distribution: {}", factory.distribution.name()));
         println!("╠══════════════════════════════════════════════════════════════╣");
 
         // Show connected stores
@@ -1991,7 +4083,18 @@ fn handle_supply_chain(game: &mut GameState) {
             for store_id in &factory.connected_stores {
                 if let Some(store_name) = game.get_store_name_by_id(*store_id) {
                     let is_primary = factory.primary_store() == Some(*store_id);
-                    let marker = if is_primary { " [PRIMARY]" } else { "" };
+                    let marker = match &factory.distribution {
+                        DistributionPolicy::PrimaryOnly if is_primary => " [PRIMARY]".to_string(),
+                        DistributionPolicy::Manual(shares) => {
+                            let pct = shares
+                                .iter()
+                                .find(|(id, _)| id == store_id)
+                                .map(|(_, pct)| *pct)
+                                .unwrap_or(0.0);
+                            format!(" [{:.0}%]", pct)
+                        }
+                        _ => String::new(),
+                    };
                     println!("║    - {}{}                                     ║", store_name, marker);
                 }
             }
@@ -2016,13 +4119,14 @@ fn handle_supply_chain(game: &mut GameState) {
         println!("║  [1] Connect store                                           ║");
         println!("║  [2] Disconnect store                                        ║");
         println!("║  [3] Toggle auto-transfer                                    ║");
+        println!("║  [4] Set distribution policy                                 ║");
         println!("║  [0] Back                                                    ║");
         println!("╚══════════════════════════════════════════════════════════════╝");
         println!();
 
         if factory.auto_transfer && !factory.connected_stores.is_empty() {
             println!("Auto-transfer is ON: Finished goods will automatically ship");
-            println!("to the primary connected store each day.");
+            println!("to connected stores each day ({}).", factory.distribution.name());
             println!();
         }
 
@@ -2095,7 +4199,7 @@ fn handle_supply_chain(game: &mut GameState) {
                     Ok(enabled) => {
                         if enabled {
                             println!("Auto-transfer ENABLED!");
-                            println!("Finished goods will automatically ship to the primary store.");
+                            println!("Finished goods will automatically ship to connected stores.");
                         } else {
                             println!("Auto-transfer DISABLED.");
                         }
@@ -2106,11 +4210,70 @@ fn handle_supply_chain(game: &mut GameState) {
                 }
                 wait_for_enter();
             }
+            "4" => handle_distribution_policy(game),
             _ => println!("Invalid choice."),
         }
     }
 }
 
+/// Handles choosing how the current factory splits goods between connected stores
+fn handle_distribution_policy(game: &mut GameState) {
+    let connected: Vec<u32> = game.current_factory().unwrap().connected_stores.clone();
+    if connected.is_empty() {
+        println!("Connect at least one store first!");
+        wait_for_enter();
+        return;
+    }
+
+    println!("Distribution policies:");
+    println!("  [1] Primary store only");
+    println!("  [2] Round-robin (deal units out evenly)");
+    println!("  [3] By sales rate (stores with more demand get more)");
+    println!("  [4] Fill each store to a target");
+    println!("  [5] Manual percentages");
+
+    let policy = match read_input("Choose policy (0 to cancel): ").trim() {
+        "0" => return,
+        "1" => DistributionPolicy::PrimaryOnly,
+        "2" => DistributionPolicy::RoundRobin,
+        "3" => DistributionPolicy::SalesRate,
+        "4" => match read_number("Units of each product per store: ") {
+            Some(target) => DistributionPolicy::FillToTarget(target),
+            None => {
+                println!("Invalid target.");
+                wait_for_enter();
+                return;
+            }
+        },
+        "5" => {
+            let mut shares = Vec::new();
+            for store_id in &connected {
+                let name = game.get_store_name_by_id(*store_id).unwrap_or_default();
+                match read_float(&format!("Percent for {}: ", name)) {
+                    Some(pct) => shares.push((*store_id, pct)),
+                    None => {
+                        println!("Invalid percentage.");
+                        wait_for_enter();
+                        return;
+                    }
+                }
+            }
+            DistributionPolicy::Manual(shares)
+        }
+        _ => {
+            println!("Invalid choice.");
+            wait_for_enter();
+            return;
+        }
+    };
+
+    match game.set_distribution_policy(policy) {
+        Ok(()) => println!("Distribution policy updated."),
+        Err(e) => println!("ERROR: {}", e),
+    }
+    wait_for_enter();
+}
+
 // ==================== LOAN MANAGEMENT ====================
 
 /// Handles loan management submenu
@@ -2118,7 +4281,7 @@ pub fn handle_manage_loans(game: &mut GameState) {
     loop {
         clear_screen();
         let economic_state = &game.market.economic_state;
-        let base_rate = economic_state.interest_rate();
+        let base_rate = game.market.base_interest_rate();
 
         println!("╔══════════════════════════════════════════════════════════════╗");
         println!("║                      MANAGE LOANS                            ║");
@@ -2243,7 +4406,7 @@ fn handle_take_loan(game: &mut GameState) {
     println!(
         "║  Economy: {:12}  │  Base rate: {:>5.1}%                  ║",
         economic_state.name(),
-        economic_state.interest_rate() * 100.0
+        game.market.base_interest_rate() * 100.0
     );
     println!(
         "║  Max borrowable: ${:>10.2}                                 ║",